@@ -1,3 +1,44 @@
+use std::env;
+use std::path::PathBuf;
+
+/** Name of the directory under openvr/lib that holds the import library for the target being built. */
+fn platform_dir(os: &str, pointer_width: &str) -> Option<&'static str> {
+  match (os, pointer_width) {
+    ("linux", "64") => Some("linux64"),
+    ("linux", "32") => Some("linux32"),
+    ("windows", "64") => Some("win64"),
+    ("windows", "32") => Some("win32"),
+    ("macos", _) => Some("osx32"),
+    _ => None,
+  }
+}
+
 fn main() {
-  println!("cargo:rustc-link-search=native=./openvr/lib/win64");
-}
\ No newline at end of file
+  println!("cargo:rerun-if-env-changed=OPENVR_LIB_DIR");
+  println!("cargo:rerun-if-changed=build.rs");
+
+  // With the dynamic feature the library is opened at runtime, and with the mock feature it is not used at all.
+  if env::var_os("CARGO_FEATURE_DYNAMIC").is_some() || env::var_os("CARGO_FEATURE_MOCK").is_some() {
+    return;
+  }
+
+  if let Ok(dir) = env::var("OPENVR_LIB_DIR") {
+    println!("cargo:rustc-link-search=native={}", dir);
+    return;
+  }
+
+  let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+  let pointer_width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+
+  match platform_dir(&os, &pointer_width) {
+    Some(platform) => {
+      let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+      let dir = manifest_dir.join("openvr").join("lib").join(platform);
+      println!("cargo:rustc-link-search=native={}", dir.display());
+    }
+    None => println!(
+      "cargo:warning=OpenVR ships no library for target os {} ({}-bit); set OPENVR_LIB_DIR to link against one",
+      os, pointer_width
+    ),
+  }
+}
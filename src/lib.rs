@@ -1,9 +1,22 @@
+// Doc comments are copied from the OpenVR headers, which indent with tabs, and the bindings keep the
+// spelling they were written with.
+#![allow(
+    clippy::tabs_in_doc_comments,
+    clippy::redundant_static_lifetimes,
+    clippy::needless_return,
+    clippy::match_like_matches_macro
+)]
+
+// Function tables are declared `extern "system"`: the runtime's OPENVR_FNTABLE_CALLTYPE is __stdcall
+// on 32-bit Windows and the plain C convention everywhere else, which is the ABI "system" selects per target.
 pub mod vr {
+    #[cfg(not(any(feature = "dynamic", feature = "mock")))]
     use std::ffi::c_void;
 
     pub mod version;
     pub mod public_vrtypes;
     pub mod vrtypes;
+    #[cfg(not(any(feature = "dynamic", feature = "mock")))]
     use vrtypes::*;
 
     pub mod ivrsystem;
@@ -12,7 +25,65 @@ pub mod vr {
     pub mod ivrchaperone;
     pub mod ivrchaperonesetup;
     pub mod ivrcompositor;
+    pub mod ivrdebug;
+    pub mod ivrspatialanchors;
+
+    pub mod context;
+    pub mod system;
+    pub mod compositor;
+    pub mod distortion;
+    pub mod explicit_timing;
+    pub mod frame_timing;
+    #[cfg(feature = "serde")]
+    pub mod timing_log;
+    pub mod mirror;
+    pub mod texture;
+    pub mod transition;
+    pub mod vulkan;
+    pub mod projection;
+    pub mod property;
+    pub mod event;
+    pub mod math;
+
+    /** Conversions between the OpenVR math types and glam, nalgebra and mint, each behind the cargo feature of
+    * the same name. OpenVR matrices are row-major arrays, m[row][column]; glam and nalgebra store columns, so
+    * the conversions transpose, and an element keeps its row and column on both sides.
+    *
+    * The struct types (HmdQuaternionf, HmdQuaternion, HmdColor, HmdRect2 and VRBoneTransform) implement From and
+    * Into. TrackedDevicePose only converts into its device to absolute tracking transform: a transform has no
+    * velocities or tracking state to convert back from. The matrix and vector types are plain arrays, and the
+    * orphan rule rules out From between an array and another crate's type, so they convert with FromMath and
+    * IntoMath instead. */
+    #[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+    pub mod interop;
+
+    mod util;
+
+    /** Loads libopenvr_api at runtime instead of linking it. The VR_* functions below are then
+    * forwarded through the loaded library, and report that no runtime is installed until one is loaded with
+    * loader::load, or by Context::new, which loads the default library if nothing is loaded yet. */
+    #[cfg(feature = "dynamic")]
+    pub mod loader;
+    #[cfg(all(feature = "dynamic", not(feature = "mock")))]
+    pub use loader::{
+        VR_GetGenericInterface, VR_GetInitToken, VR_GetRuntimePath, VR_GetVRInitErrorAsEnglishDescription,
+        VR_GetVRInitErrorAsSymbol, VR_InitInternal2, VR_IsHmdPresent, VR_IsInterfaceVersionValid,
+        VR_IsRuntimeInstalled, VR_ShutdownInternal,
+    };
+
+    /** An in-process runtime that implements IVRSystem, IVRCompositor, IVRChaperone, IVRSettings and
+    * IVRApplications in Rust, so code using this crate can be tested without SteamVR or a headset.
+    * The VR_* functions below are the mock's, and take precedence over the dynamic feature. */
+    #[cfg(feature = "mock")]
+    pub mod mock;
+    #[cfg(feature = "mock")]
+    pub use mock::{
+        VR_GetGenericInterface, VR_GetInitToken, VR_GetRuntimePath, VR_GetVRInitErrorAsEnglishDescription,
+        VR_GetVRInitErrorAsSymbol, VR_InitInternal2, VR_IsHmdPresent, VR_IsInterfaceVersionValid,
+        VR_IsRuntimeInstalled, VR_ShutdownInternal,
+    };
 
+    #[cfg(not(any(feature = "dynamic", feature = "mock")))]
     #[link(name = "openvr_api")]
     extern "C" {
         pub fn VR_IsHmdPresent() -> bool;
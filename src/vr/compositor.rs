@@ -0,0 +1,466 @@
+use std::ffi::{c_void, CString};
+use std::marker::PhantomData;
+use std::mem::{size_of, MaybeUninit};
+use std::ptr::{addr_of_mut, null};
+
+use super::explicit_timing::ExplicitTiming;
+use super::ivrcompositor::*;
+use super::mirror::{D3D11MirrorTexture, MirrorTexture};
+use super::public_vrtypes::{HmdColor, HmdMatrix34};
+use super::texture::SubmitTexture;
+use super::transition::SceneTransition;
+use super::util::read_string;
+use super::vulkan::extension_list;
+use super::vrtypes::*;
+
+/** One pose per tracked device slot, indexed by TrackedDeviceIndex. */
+pub type TrackedDevicePoses = [TrackedDevicePose; k_unMaxTrackedDeviceCount as usize];
+
+/** The poses returned by WaitGetPoses. Render poses are predicted to when the frame will be displayed;
+* game poses are predicted one frame further out, for gameplay. */
+#[derive(Clone, Copy, Debug)]
+pub struct FramePoses {
+    pub render: TrackedDevicePoses,
+    pub game: TrackedDevicePoses,
+}
+
+fn result(error: EVRCompositorError) -> Result<(), EVRCompositorError> {
+    match error {
+        EVRCompositorError::VRCompositorError_None => Ok(()),
+        error => Err(error),
+    }
+}
+
+/** Safe access to IVRCompositor. Obtained from Context::compositor and valid until the Context is dropped. */
+#[derive(Clone, Copy)]
+pub struct Compositor<'a> {
+    table: &'a VR_IVRCompositor_FnTable,
+}
+
+impl<'a> Compositor<'a> {
+    pub(crate) fn new(table: &'a VR_IVRCompositor_FnTable) -> Compositor<'a> {
+        Compositor { table }
+    }
+
+    /** The underlying function table, for entry points this wrapper does not cover. */
+    pub fn fn_table(&self) -> &'a VR_IVRCompositor_FnTable {
+        self.table
+    }
+
+    /** Sets the tracking space the poses from wait_get_poses are reported in. */
+    pub fn set_tracking_space(&self, origin: ETrackingUniverseOrigin) {
+        unsafe { (self.table.SetTrackingSpace)(origin) }
+    }
+
+    /** The tracking space the poses from wait_get_poses are reported in. */
+    pub fn tracking_space(&self) -> ETrackingUniverseOrigin {
+        unsafe { (self.table.GetTrackingSpace)() }
+    }
+
+    /** Blocks until "running start" before the next frame and returns the poses to render it with.
+    * Prefer begin_frame, which also makes sure both eyes are submitted in order. */
+    pub fn wait_get_poses(&self) -> Result<FramePoses, EVRCompositorError> {
+        let mut render = MaybeUninit::<TrackedDevicePoses>::uninit();
+        let mut game = MaybeUninit::<TrackedDevicePoses>::uninit();
+        unsafe {
+            result((self.table.WaitGetPoses)(
+                render.as_mut_ptr() as *mut TrackedDevicePose,
+                k_unMaxTrackedDeviceCount,
+                game.as_mut_ptr() as *mut TrackedDevicePose,
+                k_unMaxTrackedDeviceCount,
+            ))?;
+            Ok(FramePoses { render: render.assume_init(), game: game.assume_init() })
+        }
+    }
+
+    /** The poses returned by the last call to wait_get_poses. */
+    pub fn last_poses(&self) -> Result<FramePoses, EVRCompositorError> {
+        let mut render = MaybeUninit::<TrackedDevicePoses>::uninit();
+        let mut game = MaybeUninit::<TrackedDevicePoses>::uninit();
+        unsafe {
+            result((self.table.GetLastPoses)(
+                render.as_mut_ptr() as *mut TrackedDevicePose,
+                k_unMaxTrackedDeviceCount,
+                game.as_mut_ptr() as *mut TrackedDevicePose,
+                k_unMaxTrackedDeviceCount,
+            ))?;
+            Ok(FramePoses { render: render.assume_init(), game: game.assume_init() })
+        }
+    }
+
+    /** The (render, game) pose of one device from the last call to wait_get_poses. */
+    pub fn last_pose_for_tracked_device_index(
+        &self,
+        device: TrackedDeviceIndex,
+    ) -> Result<(TrackedDevicePose, TrackedDevicePose), EVRCompositorError> {
+        let mut render = MaybeUninit::<TrackedDevicePose>::uninit();
+        let mut game = MaybeUninit::<TrackedDevicePose>::uninit();
+        unsafe {
+            result((self.table.GetLastPoseForTrackedDeviceIndex)(device, render.as_mut_ptr(), game.as_mut_ptr()))?;
+            Ok((render.assume_init(), game.assume_init()))
+        }
+    }
+
+    /** Submits the scene texture for one eye. With no bounds the whole texture is used.
+    * The flags the texture's handle needs (such as Submit_GlRenderBuffer) are added to `flags`.
+    *
+    * # Safety
+    * The texture must be a live object of the kind it was built as, created on the device the runtime
+    * expects. A raw Texture whose flags say it carries a pose or depth must be the leading part of a
+    * VRTextureWithPose or VRTextureWithPoseAndDepth to match. */
+    pub unsafe fn submit(
+        &self,
+        eye: EVREye,
+        texture: &SubmitTexture,
+        bounds: Option<VRTextureBounds>,
+        flags: EVRSubmitFlags,
+    ) -> Result<(), EVRCompositorError> {
+        let bounds = bounds.as_ref().map_or(null(), |bounds| bounds as *const VRTextureBounds);
+        result((self.table.Submit)(eye, texture.texture(), bounds, flags | texture.flags()))
+    }
+
+    /** Timing data for up to the last `frames` frames, oldest first. The runtime keeps about a second's worth,
+    * so fewer entries than asked for may come back. */
+    pub fn frame_timings(&self, frames: u32) -> Vec<Compositor_FrameTiming> {
+        let mut timings = Vec::<Compositor_FrameTiming>::with_capacity(frames as usize);
+        if frames == 0 {
+            return timings;
+        }
+
+        unsafe {
+            // Only the first entry's size needs to be set; the runtime infers the rest from it.
+            addr_of_mut!((*timings.as_mut_ptr()).size).write_unaligned(size_of::<Compositor_FrameTiming>() as u32);
+            let filled = (self.table.GetFrameTimings)(timings.as_mut_ptr(), frames);
+            timings.set_len(filled.min(frames) as usize);
+        }
+        timings
+    }
+
+    /** Stats the runtime accumulated for the last connected scene application. */
+    pub fn cumulative_stats(&self) -> Compositor_CumulativeStats {
+        let mut stats = Compositor_CumulativeStats::default();
+        unsafe { (self.table.GetCumulativeStats)(&mut stats, size_of::<Compositor_CumulativeStats>() as u32) };
+        stats
+    }
+
+    /** Fades the view to a color over `seconds`, on top of the scene by the color's alpha. The background fade
+    * is drawn behind the scene instead. A color with alpha 0 removes the fade. */
+    pub fn fade_to_color(&self, seconds: f32, color: HmdColor, background: bool) {
+        unsafe { (self.table.FadeToColor)(seconds, color.r, color.g, color.b, color.a, background) }
+    }
+
+    /** The fade color as it currently is, partway through a fade. */
+    pub fn current_fade_color(&self, background: bool) -> HmdColor {
+        unsafe { (self.table.GetCurrentFadeColor)(background) }
+    }
+
+    /** Fades the compositor grid in or out over `seconds`. */
+    pub fn fade_grid(&self, seconds: f32, fade_in: bool) {
+        unsafe { (self.table.FadeGrid)(seconds, fade_in) }
+    }
+
+    /** The grid's current alpha, 0 when it is hidden. */
+    pub fn current_grid_alpha(&self) -> f32 {
+        unsafe { (self.table.GetCurrentGridAlpha)() }
+    }
+
+    /** Replaces the compositor's skybox, for example while a level loads. One texture is a lat-long image,
+    * two are a lat-long stereo pair, and six are cube faces in the order front, back, left, right, top,
+    * bottom. Other counts, null handles and mixed texture types are rejected with InvalidTexture.
+    *
+    * # Safety
+    * The textures must be live objects of their texture type, on the device the runtime expects. */
+    pub unsafe fn set_skybox_override(&self, textures: &[Texture]) -> Result<(), EVRCompositorError> {
+        let valid = matches!(textures.len(), 1 | 2 | 6)
+            && textures.iter().all(|texture| !texture.handle.is_null() && texture.texture_type == textures[0].texture_type);
+        if !valid {
+            return Err(EVRCompositorError::VRCompositorError_InvalidTexture);
+        }
+        result((self.table.SetSkyboxOverride)(textures.as_ptr(), textures.len() as u32))
+    }
+
+    /** Goes back to the default skybox. */
+    pub fn clear_skybox_override(&self) {
+        unsafe { (self.table.ClearSkyboxOverride)() }
+    }
+
+    /** Replaces the grid with the OBJ model at `render_model_path`, a full path. The model loads in the
+    * background and VREvent_Compositor_StageOverrideReady is sent once it can be shown; fade_grid reveals it.
+    * A path containing a null byte is rejected with RequestFailed. */
+    pub fn set_stage_override(
+        &self,
+        render_model_path: &str,
+        transform: &HmdMatrix34,
+        settings: &Compositor_StageRenderSettings,
+    ) -> Result<(), EVRCompositorError> {
+        let path = CString::new(render_model_path).map_err(|_| EVRCompositorError::VRCompositorError_RequestFailed)?;
+        result(unsafe {
+            (self.table.SetStageOverride_Async)(
+                path.as_ptr(),
+                transform,
+                settings,
+                size_of::<Compositor_StageRenderSettings>() as u32,
+            )
+        })
+    }
+
+    /** Frees the stage override and goes back to the user's stage. */
+    pub fn clear_stage_override(&self) {
+        unsafe { (self.table.ClearStageOverride)() }
+    }
+
+    /** Opens the compositor's undistorted image for one eye as an OpenGL texture, released when the
+    * MirrorTexture is dropped.
+    *
+    * # Safety
+    * An OpenGL context must be current on this thread, and on the thread the MirrorTexture is locked and dropped. */
+    pub unsafe fn mirror_texture_gl(&self, eye: EVREye) -> Result<MirrorTexture<'a>, EVRCompositorError> {
+        MirrorTexture::new(*self, eye)
+    }
+
+    /** Opens the compositor's undistorted image for one eye as a D3D11 shader resource view, released when
+    * the D3D11MirrorTexture is dropped.
+    *
+    * # Safety
+    * `device` must be a live ID3D11Device, or an ID3D11Resource on one. */
+    pub unsafe fn mirror_texture_d3d11(
+        &self,
+        eye: EVREye,
+        device: *mut c_void,
+    ) -> Result<D3D11MirrorTexture<'a>, EVRCompositorError> {
+        D3D11MirrorTexture::new(*self, eye, device)
+    }
+
+    /** Fades to `color` over `seconds`, for example at the start of a level load. The fade is undone when the
+    * SceneTransition is finished or dropped. */
+    pub fn scene_transition<'t>(&self, color: HmdColor, seconds: f32) -> SceneTransition<'a, 't> {
+        SceneTransition::new(*self, color, seconds)
+    }
+
+    /** Clears the frame sent with the last submit, so the compositor shows the grid until the next one. */
+    pub fn clear_last_submitted_frame(&self) {
+        unsafe { (self.table.ClearLastSubmittedFrame)() }
+    }
+
+    /** Tells the compositor that rendering for this frame is done, for apps that cannot call
+    * wait_get_poses right after presenting. Call it on the render thread after submitting both eyes. */
+    pub fn post_present_handoff(&self) {
+        unsafe { (self.table.PostPresentHandoff)() }
+    }
+
+    /** Seconds left in the current frame. May roll over to the next frame before reaching 0. */
+    pub fn frame_time_remaining(&self) -> f32 {
+        unsafe { (self.table.GetFrameTimeRemaining)() }
+    }
+
+    /** Whether this process may render the scene right now. */
+    pub fn can_render_scene(&self) -> bool {
+        unsafe { (self.table.CanRenderScene)() }
+    }
+
+    /** Whether the compositor is fullscreen. */
+    pub fn is_fullscreen(&self) -> bool {
+        unsafe { (self.table.IsFullscreen)() }
+    }
+
+    /** The instance extensions the compositor needs, to enable in vkCreateInstance. */
+    pub fn vulkan_instance_extensions_required(&self) -> Vec<CString> {
+        extension_list(&read_string(|value, size| unsafe { (self.table.GetVulkanInstanceExtensionsRequired)(value, size) }))
+    }
+
+    /** The device extensions the compositor needs on a physical device, to enable in vkCreateDevice.
+    *
+    * # Safety
+    * The physical device must be a live VkPhysicalDevice. */
+    pub unsafe fn vulkan_device_extensions_required(&self, physical_device: VkPhysicalDevice) -> Vec<CString> {
+        extension_list(&read_string(|value, size| (self.table.GetVulkanDeviceExtensionsRequired)(physical_device, value, size)))
+    }
+
+    /** Sets how the app and runtime coordinate frame timing. Prefer explicit_timing, which also checks the call order. */
+    pub fn set_explicit_timing_mode(&self, mode: EVRCompositorTimingMode) {
+        unsafe { (self.table.SetExplicitTimingMode)(mode) }
+    }
+
+    /** Inserts a GPU timestamp for the frame. In an explicit timing mode this must be called right before
+    * the frame's first vkQueueSubmit or ExecuteCommandLists, and not while another thread uses the queue. */
+    pub fn submit_explicit_timing_data(&self) -> Result<(), EVRCompositorError> {
+        result(unsafe { (self.table.SubmitExplicitTimingData)() })
+    }
+
+    /** Switches to VRCompositorTimingMode_Explicit_ApplicationPerformsPostPresentHandoff and returns the
+    * frame loop for that mode. The implicit mode is restored when it is dropped. */
+    pub fn explicit_timing(&self) -> ExplicitTiming<'a> {
+        ExplicitTiming::new(*self)
+    }
+
+    /** Waits for the next frame and returns a Frame that only lets the left eye, then the right eye, be submitted. */
+    pub fn begin_frame(&self) -> Result<Frame<'a, SubmitLeft>, EVRCompositorError> {
+        let poses = self.wait_get_poses()?;
+        Ok(Frame { compositor: *self, poses, state: PhantomData })
+    }
+}
+
+/** A zeroed timing with `size` filled in, as GetFrameTiming expects it, and an invalid hmd_pose. */
+impl Default for Compositor_FrameTiming {
+    fn default() -> Compositor_FrameTiming {
+        Compositor_FrameTiming {
+            size: size_of::<Compositor_FrameTiming>() as u32,
+            frame_index: 0,
+            num_frame_presents: 0,
+            num_mis_presented: 0,
+            num_dropped_frames: 0,
+            reprojection_flags: VRCompositorReprojectionFlags::empty(),
+            system_time_in_seconds: 0.0,
+            pre_submit_gpu_ms: 0.0,
+            post_submit_gpu_ms: 0.0,
+            total_render_gpu_ms: 0.0,
+            compositor_render_gpu_ms: 0.0,
+            compositor_render_cpu_ms: 0.0,
+            compositor_idle_cpu_ms: 0.0,
+            client_frame_interval_ms: 0.0,
+            present_call_cpu_ms: 0.0,
+            wait_for_present_cpu_ms: 0.0,
+            submit_frame_ms: 0.0,
+            wait_get_poses_called_ms: 0.0,
+            new_poses_ready_ms: 0.0,
+            new_frame_ready_ms: 0.0,
+            compositor_update_start_ms: 0.0,
+            compositor_update_end_ms: 0.0,
+            compositor_render_start_ms: 0.0,
+            hmd_pose: TrackedDevicePose {
+                device_to_absolute_tracking: [[0.0; 4]; 3],
+                velocity: [0.0; 3],
+                angular_velocity: [0.0; 3],
+                tracking_result: ETrackingResult::TrackingResult_Uninitialized,
+                pose_is_valid: false,
+                device_is_connected: false,
+            },
+            num_vsyncs_ready_for_use: 0,
+            num_vsyncs_to_first_view: 0,
+        }
+    }
+}
+
+impl Compositor_FrameTiming {
+    /** How many frames beyond the next one the runtime predicted poses for, because the app is running late. Normally 0. */
+    pub fn additional_predicted_frames(&self) -> u32 {
+        (self.reprojection_flags & VRCompositorReprojectionFlags::VRCompositor_PredictionMask).bits() >> 4
+    }
+
+    /** How many frames the compositor is throttling the application by. */
+    pub fn throttled_frames(&self) -> u32 {
+        (self.reprojection_flags & VRCompositorReprojectionFlags::VRCompositor_ThrottleMask).bits() >> 8
+    }
+
+    /** Why the frame was reprojected: ReprojectionReason_Cpu, ReprojectionReason_Gpu, both, or empty if it was not. */
+    pub fn reprojection_reason(&self) -> VRCompositorReprojectionFlags {
+        self.reprojection_flags
+            & (VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Cpu
+                | VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Gpu)
+    }
+
+    /** Whether the scene texture was reused instead of a new frame being shown. */
+    pub fn was_reprojected(&self) -> bool {
+        !self.reprojection_reason().is_empty()
+    }
+
+    /** Whether motion smoothing was triggered for this frame, as opposed to merely being enabled. */
+    pub fn motion_smoothing_active(&self) -> bool {
+        self.reprojection_flags.contains(VRCompositorReprojectionFlags::VRCompositor_ReprojectionMotion)
+    }
+
+    /** CPU time the app spent on the frame, from the new poses being ready to its second Submit. */
+    pub fn application_cpu_ms(&self) -> f32 {
+        self.new_frame_ready_ms - self.new_poses_ready_ms
+    }
+
+    /** GPU time the app spent on the frame, before and after its second Submit. */
+    pub fn application_gpu_ms(&self) -> f32 {
+        self.pre_submit_gpu_ms + self.post_submit_gpu_ms
+    }
+
+    /** CPU time of the app and the compositor for the frame. */
+    pub fn total_cpu_ms(&self) -> f32 {
+        self.application_cpu_ms() + self.compositor_render_cpu_ms
+    }
+
+    /** GPU time of the app and the compositor for the frame. */
+    pub fn total_gpu_ms(&self) -> f32 {
+        self.total_render_gpu_ms
+    }
+}
+
+/** Frame state: waiting for the left eye. */
+pub enum SubmitLeft {}
+
+/** Frame state: the left eye is submitted, waiting for the right eye. */
+pub enum SubmitRight {}
+
+/** Frame state: both eyes are submitted. */
+pub enum Submitted {}
+
+/** A frame between WaitGetPoses and the submission of both eyes.
+* Each submit consumes the frame and returns it in the next state, so the calls can only be made in
+* the order WaitGetPoses, Submit(left), Submit(right). A failed submit ends the frame; start the next
+* one with Compositor::begin_frame. */
+pub struct Frame<'a, S> {
+    compositor: Compositor<'a>,
+    poses: FramePoses,
+    state: PhantomData<S>,
+}
+
+impl<'a, S> Frame<'a, S> {
+    /** The poses this frame was started with. */
+    pub fn poses(&self) -> &FramePoses {
+        &self.poses
+    }
+
+    /** The render pose of a device, for building view matrices. */
+    pub fn render_pose(&self, device: TrackedDeviceIndex) -> Option<&TrackedDevicePose> {
+        self.poses.render.get(device as usize)
+    }
+
+    fn next<T>(self) -> Frame<'a, T> {
+        Frame { compositor: self.compositor, poses: self.poses, state: PhantomData }
+    }
+}
+
+impl<'a> Frame<'a, SubmitLeft> {
+    /** Submits the left eye.
+    *
+    * # Safety
+    * Same contract as Compositor::submit. */
+    pub unsafe fn submit_left(
+        self,
+        texture: &SubmitTexture,
+        bounds: Option<VRTextureBounds>,
+        flags: EVRSubmitFlags,
+    ) -> Result<Frame<'a, SubmitRight>, EVRCompositorError> {
+        self.compositor.submit(EVREye::Eye_Left, texture, bounds, flags)?;
+        Ok(self.next())
+    }
+}
+
+impl<'a> Frame<'a, SubmitRight> {
+    /** Submits the right eye.
+    *
+    * # Safety
+    * Same contract as Compositor::submit. */
+    pub unsafe fn submit_right(
+        self,
+        texture: &SubmitTexture,
+        bounds: Option<VRTextureBounds>,
+        flags: EVRSubmitFlags,
+    ) -> Result<Frame<'a, Submitted>, EVRCompositorError> {
+        self.compositor.submit(EVREye::Eye_Right, texture, bounds, flags)?;
+        Ok(self.next())
+    }
+}
+
+impl<'a> Frame<'a, Submitted> {
+    /** Ends the frame with PostPresentHandoff. Only needed by apps that do not call WaitGetPoses right
+    * after presenting; otherwise the frame can just be dropped. */
+    pub fn post_present_handoff(self) {
+        self.compositor.post_present_handoff();
+    }
+}
@@ -0,0 +1,172 @@
+use std::error::Error;
+use std::ffi::CString;
+use std::fmt;
+use std::ptr::null;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::compositor::Compositor;
+#[cfg(feature = "dynamic")]
+use super::loader::LoadError;
+use super::ivrcompositor::VR_IVRCompositor_FnTable;
+use super::ivrsystem::VR_IVRSystem_FnTable;
+use super::system::System;
+use super::vrtypes::*;
+use super::{VR_GetGenericInterface, VR_InitInternal2, VR_IsInterfaceVersionValid, VR_ShutdownInternal};
+
+/** Set while a Context is alive. OpenVR keeps its state per process, so only one may exist at a time. */
+static CONTEXT_ALIVE: AtomicBool = AtomicBool::new(false);
+
+/** Implemented by each VR_IVR*_FnTable so it can be loaded with Context::interface.
+*
+* # Safety
+* VERSION must name the interface whose function table has exactly the layout of Self. */
+pub unsafe trait Interface {
+    /** Interface version string without the "FnTable:" prefix, e.g. "IVRSystem_022". */
+    const VERSION: &'static str;
+}
+
+/** Errors that can occur when loading an interface from a Context */
+#[derive(PartialEq, Debug)]
+pub enum InterfaceError {
+    /** The installed runtime does not provide this interface version. */
+    VersionNotValid(&'static str),
+    /** VR_GetGenericInterface failed for this interface version. */
+    Init(&'static str, EVRInitError),
+}
+
+impl fmt::Display for InterfaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterfaceError::VersionNotValid(version) => {
+                write!(f, "interface version {} is not supported by the runtime", version)
+            }
+            InterfaceError::Init(version, error) => {
+                write!(f, "failed to load interface {}: {:?}", version, error)
+            }
+        }
+    }
+}
+
+impl Error for InterfaceError {}
+
+/** Errors that can occur when creating a Context */
+#[derive(Debug)]
+pub enum ContextError {
+    /** VR_InitInternal2 failed, or another Context is still alive. */
+    Init(EVRInitError),
+    /** The runtime library could not be loaded. */
+    #[cfg(feature = "dynamic")]
+    Load(LoadError),
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextError::Init(error) => write!(f, "failed to initialize OpenVR: {:?}", error),
+            #[cfg(feature = "dynamic")]
+            ContextError::Load(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ContextError::Init(_) => None,
+            #[cfg(feature = "dynamic")]
+            ContextError::Load(error) => Some(error),
+        }
+    }
+}
+
+impl From<EVRInitError> for ContextError {
+    fn from(error: EVRInitError) -> ContextError {
+        ContextError::Init(error)
+    }
+}
+
+/** An initialized OpenVR runtime.
+* Construction runs VR_InitInternal2 and dropping runs VR_ShutdownInternal. Interfaces handed out by
+* a Context borrow from it, so they cannot outlive the shutdown. */
+pub struct Context {
+    init_token: u32,
+}
+
+impl Context {
+    /** Initializes OpenVR as the given application type.
+    * Returns VRInitError_Init_AlreadyRunning if another Context is still alive in this process. With the
+    * dynamic feature, the default runtime library is loaded first if the app has not loaded one. */
+    pub fn new(application_type: EVRApplicationType) -> Result<Context, ContextError> {
+        #[cfg(all(feature = "dynamic", not(feature = "mock")))]
+        super::loader::ensure_loaded().map_err(ContextError::Load)?;
+
+        if CONTEXT_ALIVE
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(ContextError::Init(EVRInitError::VRInitError_Init_AlreadyRunning));
+        }
+
+        let mut error = EVRInitError::VRInitError_None;
+        let init_token = unsafe { VR_InitInternal2(&mut error, application_type, null()) };
+
+        if error != EVRInitError::VRInitError_None {
+            CONTEXT_ALIVE.store(false, Ordering::Release);
+            return Err(ContextError::Init(error));
+        }
+
+        Ok(Context { init_token })
+    }
+
+    /** The token returned by VR_InitInternal2 for this initialization. */
+    pub fn init_token(&self) -> u32 {
+        self.init_token
+    }
+
+    /** Looks up the function table for an interface version string such as IVRSYSTEM_VERSION.
+    * The "FnTable:" prefix is added here.
+    *
+    * # Safety
+    * T must be the function table type that matches `version`. */
+    pub unsafe fn fn_table<T>(&self, version: &str) -> Result<&T, EVRInitError> {
+        let name = CString::new(format!("FnTable:{}", version))
+            .map_err(|_| EVRInitError::VRInitError_Init_InvalidInterface)?;
+
+        let mut error = EVRInitError::VRInitError_None;
+        let table = VR_GetGenericInterface(name.as_ptr(), &mut error) as *const T;
+
+        if error != EVRInitError::VRInitError_None {
+            return Err(error);
+        }
+
+        table.as_ref().ok_or(EVRInitError::VRInitError_Init_InterfaceNotFound)
+    }
+
+    /** Loads the function table for T after checking that the runtime supports T::VERSION. */
+    pub fn interface<T: Interface>(&self) -> Result<&T, InterfaceError> {
+        let version = CString::new(T::VERSION).map_err(|_| InterfaceError::VersionNotValid(T::VERSION))?;
+
+        if !unsafe { VR_IsInterfaceVersionValid(version.as_ptr()) } {
+            return Err(InterfaceError::VersionNotValid(T::VERSION));
+        }
+
+        unsafe { self.fn_table::<T>(T::VERSION) }.map_err(|error| InterfaceError::Init(T::VERSION, error))
+    }
+
+    /** Safe access to IVRSystem. */
+    pub fn system(&self) -> Result<System<'_>, InterfaceError> {
+        self.interface::<VR_IVRSystem_FnTable>().map(System::new)
+    }
+
+    /** Safe access to IVRCompositor. */
+    pub fn compositor(&self) -> Result<Compositor<'_>, InterfaceError> {
+        self.interface::<VR_IVRCompositor_FnTable>().map(Compositor::new)
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe { VR_ShutdownInternal() };
+        CONTEXT_ALIVE.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,295 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use super::public_vrtypes::HmdVector2;
+use super::system::System;
+use super::vrtypes::*;
+
+/** Where a DistortionMesh gets its samples. System samples ComputeDistortion; any
+* `Fn(EVREye, u, v) -> Option<DistortionCoordinates>` works too, for synthetic lenses in tests. */
+pub trait DistortionSource {
+    /** The texture coordinates to sample for each channel at (u, v) in the eye's output, or None if the sample
+    * failed. (0, 0) is the top left of the eye's viewport and (1, 1) the bottom right. */
+    fn compute_distortion(&self, eye: EVREye, u: f32, v: f32) -> Option<DistortionCoordinates>;
+}
+
+impl DistortionSource for System<'_> {
+    fn compute_distortion(&self, eye: EVREye, u: f32, v: f32) -> Option<DistortionCoordinates> {
+        System::compute_distortion(self, eye, u, v)
+    }
+}
+
+impl<F> DistortionSource for F
+where
+    F: Fn(EVREye, f32, f32) -> Option<DistortionCoordinates>,
+{
+    fn compute_distortion(&self, eye: EVREye, u: f32, v: f32) -> Option<DistortionCoordinates> {
+        self(eye, u, v)
+    }
+}
+
+/** A vertex of a distortion mesh, laid out for upload as eight floats. */
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct DistortionVertex {
+    /** Where the vertex is in the eye's output, from (0, 0) at the top left to (1, 1) at the bottom right. */
+    pub position: HmdVector2,
+    /** Where to sample the rendered eye texture for each channel, in the same convention. */
+    pub red: HmdVector2,
+    pub green: HmdVector2,
+    pub blue: HmdVector2,
+}
+
+/** A grid of distortion samples for one eye, for apps that draw the lens distortion themselves and submit
+* with Submit_LensDistortionAlreadyApplied. Vertices are stored row by row from the top, and each grid cell is
+* two triangles: top left, top right, bottom right and top left, bottom right, bottom left.
+*
+* A failed sample keeps its undistorted coordinates, and the triangles that use it are left out of the
+* indices, so that part of the output stays empty. */
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistortionMesh {
+    pub eye: EVREye,
+    /** Vertices per row. */
+    pub columns: u32,
+    /** Vertices per column. */
+    pub rows: u32,
+    pub vertices: Vec<DistortionVertex>,
+    pub indices: Vec<u32>,
+    /** The number of samples the source could not compute. */
+    pub failed_samples: u32,
+}
+
+const MAGIC: &[u8; 8] = b"ovrdist1";
+
+/** The most vertices a mesh has per row or column, which keeps vertex and index counts well within u32. */
+pub const MAX_GRID_SIZE: u32 = 4096;
+
+/** The grid size a mesh is actually generated with for a requested one. */
+fn grid_size(columns: u32, rows: u32) -> (u32, u32) {
+    (columns.clamp(2, MAX_GRID_SIZE), rows.clamp(2, MAX_GRID_SIZE))
+}
+
+impl DistortionMesh {
+    /** Samples `source` on a grid of `columns` by `rows` vertices, each clamped to 2..=MAX_GRID_SIZE, spread
+    * evenly over the eye. */
+    pub fn generate<S>(source: &S, eye: EVREye, columns: u32, rows: u32) -> DistortionMesh
+    where
+        S: DistortionSource + ?Sized,
+    {
+        let (columns, rows) = grid_size(columns, rows);
+        let mut vertices = Vec::with_capacity(columns as usize * rows as usize);
+        let mut failed = Vec::with_capacity(vertices.capacity());
+        for row in 0..rows {
+            for column in 0..columns {
+                let position = [column as f32 / (columns - 1) as f32, row as f32 / (rows - 1) as f32];
+                let sample = source.compute_distortion(eye, position[0], position[1]);
+                failed.push(sample.is_none());
+                vertices.push(match sample {
+                    Some(DistortionCoordinates { red, green, blue }) => DistortionVertex { position, red, green, blue },
+                    None => DistortionVertex { position, red: position, green: position, blue: position },
+                });
+            }
+        }
+
+        let mut indices = Vec::with_capacity((columns as usize - 1) * (rows as usize - 1) * 6);
+        for row in 0..rows - 1 {
+            for column in 0..columns - 1 {
+                let top_left = row * columns + column;
+                let (top_right, bottom_left) = (top_left + 1, top_left + columns);
+                let bottom_right = bottom_left + 1;
+                for triangle in [[top_left, top_right, bottom_right], [top_left, bottom_right, bottom_left]] {
+                    if triangle.iter().all(|&index| !failed[index as usize]) {
+                        indices.extend_from_slice(&triangle);
+                    }
+                }
+            }
+        }
+
+        let failed_samples = failed.iter().filter(|&&failed| failed).count() as u32;
+        DistortionMesh { eye, columns, rows, vertices, indices, failed_samples }
+    }
+
+    /** The meshes for the left and right eye. */
+    pub fn generate_stereo<S>(source: &S, columns: u32, rows: u32) -> [DistortionMesh; 2]
+    where
+        S: DistortionSource + ?Sized,
+    {
+        [EVREye::Eye_Left, EVREye::Eye_Right].map(|eye| DistortionMesh::generate(source, eye, columns, rows))
+    }
+
+    /** Writes the mesh in the little-endian format read_from reads. */
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        let header = [
+            self.eye as u32,
+            self.columns,
+            self.rows,
+            self.failed_samples,
+            self.vertices.len() as u32,
+            self.indices.len() as u32,
+        ];
+        for value in header {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        for vertex in &self.vertices {
+            for value in [vertex.position, vertex.red, vertex.green, vertex.blue].as_flattened() {
+                writer.write_all(&value.to_le_bytes())?;
+            }
+        }
+        for index in &self.indices {
+            writer.write_all(&index.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /** Reads a mesh written by write_to. Data that is not a valid mesh is an InvalidData error. */
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<DistortionMesh> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("not a distortion mesh"));
+        }
+        let mut header = [0; 6];
+        for value in &mut header {
+            *value = read_u32(&mut reader)?;
+        }
+        let [eye, columns, rows, failed_samples, vertex_count, index_count] = header;
+
+        let eye = match eye {
+            0 => EVREye::Eye_Left,
+            1 => EVREye::Eye_Right,
+            _ => return Err(invalid("bad eye")),
+        };
+        if columns < 2 || rows < 2 || columns.checked_mul(rows) != Some(vertex_count) {
+            return Err(invalid("vertex count does not match the grid"));
+        }
+
+        // The counts are only trusted as far as the data backs them up.
+        let reserve = |count: u32| (count as usize).min(1 << 16);
+        let mut vertices = Vec::with_capacity(reserve(vertex_count));
+        for _ in 0..vertex_count {
+            let mut values = [0.0; 8];
+            for value in &mut values {
+                *value = f32::from_bits(read_u32(&mut reader)?);
+            }
+            let [x, y, red_u, red_v, green_u, green_v, blue_u, blue_v] = values;
+            vertices.push(DistortionVertex {
+                position: [x, y],
+                red: [red_u, red_v],
+                green: [green_u, green_v],
+                blue: [blue_u, blue_v],
+            });
+        }
+        let mut indices = Vec::with_capacity(reserve(index_count));
+        for _ in 0..index_count {
+            let index = read_u32(&mut reader)?;
+            if index >= vertex_count {
+                return Err(invalid("index out of range"));
+            }
+            indices.push(index);
+        }
+
+        Ok(DistortionMesh { eye, columns, rows, vertices, indices, failed_samples })
+    }
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/** Distortion meshes saved in a directory, one file per HMD serial number and grid size, so they are only
+* sampled the first time a headset is used. */
+#[derive(Clone, Debug)]
+pub struct DistortionCache {
+    directory: PathBuf,
+}
+
+impl DistortionCache {
+    /** The directory is created when the first meshes are stored. */
+    pub fn new<P: Into<PathBuf>>(directory: P) -> DistortionCache {
+        DistortionCache { directory: directory.into() }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /** The file for an HMD and grid size. Bytes of the serial number other than ASCII letters, digits, '-' and
+    * '.' are written as %XX in hex, so different serial numbers never share a file. */
+    pub fn path(&self, serial: &str, columns: u32, rows: u32) -> PathBuf {
+        let mut name = String::with_capacity(serial.len());
+        for byte in serial.bytes() {
+            if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'.' {
+                name.push(byte as char);
+            } else {
+                name.push_str(&format!("%{:02X}", byte));
+            }
+        }
+        let (columns, rows) = grid_size(columns, rows);
+        self.directory.join(format!("{}_{}x{}.distortion", name, columns, rows))
+    }
+
+    /** The cached meshes for both eyes, or None if there are none or the file is unreadable. */
+    pub fn load(&self, serial: &str, columns: u32, rows: u32) -> Option<[DistortionMesh; 2]> {
+        let mut reader = BufReader::new(File::open(self.path(serial, columns, rows)).ok()?);
+        let left = DistortionMesh::read_from(&mut reader).ok()?;
+        let right = DistortionMesh::read_from(&mut reader).ok()?;
+        let (columns, rows) = grid_size(columns, rows);
+        let matches = |mesh: &DistortionMesh, eye| mesh.eye == eye && mesh.columns == columns && mesh.rows == rows;
+        (matches(&left, EVREye::Eye_Left) && matches(&right, EVREye::Eye_Right)).then_some([left, right])
+    }
+
+    /** Saves the meshes for both eyes, replacing what was cached for the HMD and grid size. The file is
+    * written next to its final path and renamed into place, so a crash cannot leave half a file. */
+    pub fn store(&self, serial: &str, meshes: &[DistortionMesh; 2]) -> io::Result<()> {
+        fs::create_dir_all(&self.directory)?;
+        let path = self.path(serial, meshes[0].columns, meshes[0].rows);
+        let partial = path.with_extension("partial");
+        let mut writer = BufWriter::new(File::create(&partial)?);
+        for mesh in meshes {
+            mesh.write_to(&mut writer)?;
+        }
+        writer.into_inner().map_err(|error| error.into_error())?.sync_all()?;
+        fs::rename(partial, path)
+    }
+
+    /** The cached meshes, or new ones sampled from `source` and stored. Only storing can fail. */
+    pub fn load_or_generate<S>(
+        &self,
+        serial: &str,
+        source: &S,
+        columns: u32,
+        rows: u32,
+    ) -> io::Result<[DistortionMesh; 2]>
+    where
+        S: DistortionSource + ?Sized,
+    {
+        if let Some(meshes) = self.load(serial, columns, rows) {
+            return Ok(meshes);
+        }
+        let meshes = DistortionMesh::generate_stereo(source, columns, rows);
+        self.store(serial, &meshes)?;
+        Ok(meshes)
+    }
+
+    /** load_or_generate for the HMD, keyed by its Prop_SerialNumber_String. */
+    pub fn load_or_generate_for_system(
+        &self,
+        system: &System,
+        columns: u32,
+        rows: u32,
+    ) -> io::Result<[DistortionMesh; 2]> {
+        let prop = ETrackedDeviceProperty::Prop_SerialNumber_String;
+        let serial = system
+            .string_tracked_device_property(k_unTrackedDeviceIndex_Hmd, prop)
+            .map_err(|error| io::Error::other(format!("could not read the HMD serial number: {:?}", error)))?;
+        self.load_or_generate(&serial, system, columns, rows)
+    }
+}
@@ -0,0 +1,367 @@
+use std::ptr;
+
+use super::system::System;
+use super::util::enum_try_from_u32;
+use super::vrtypes::*;
+use super::vrtypes::EVREventType::*;
+use super::vrtypes::VREvent_TouchPadMove;
+
+/** A VREvent decoded into its event type and the matching member of the VREvent_Data union.
+* Events whose type this crate does not know, or whose payload holds an unknown enum value, come back as Raw. */
+#[derive(Clone, Copy, Debug)]
+pub enum Event {
+    TrackedDeviceActivated { device: TrackedDeviceIndex },
+    TrackedDeviceDeactivated { device: TrackedDeviceIndex },
+    TrackedDeviceUpdated { device: TrackedDeviceIndex },
+    TrackedDeviceUserInteractionStarted { device: TrackedDeviceIndex },
+    TrackedDeviceUserInteractionEnded { device: TrackedDeviceIndex },
+    TrackedDeviceRoleChanged { device: TrackedDeviceIndex },
+    IpdChanged { ipd_meters: f32 },
+    PropertyChanged { container: PropertyContainerHandle, prop: ETrackedDeviceProperty },
+
+    ButtonPress { device: TrackedDeviceIndex, button: EVRButtonId },
+    ButtonUnpress { device: TrackedDeviceIndex, button: EVRButtonId },
+    ButtonTouch { device: TrackedDeviceIndex, button: EVRButtonId },
+    ButtonUntouch { device: TrackedDeviceIndex, button: EVRButtonId },
+
+    MouseMove { device: TrackedDeviceIndex, mouse: VREvent_Mouse },
+    MouseButtonDown { device: TrackedDeviceIndex, mouse: VREvent_Mouse },
+    MouseButtonUp { device: TrackedDeviceIndex, mouse: VREvent_Mouse },
+    ScrollDiscrete { device: TrackedDeviceIndex, scroll: VREvent_Scroll },
+    ScrollSmooth { device: TrackedDeviceIndex, scroll: VREvent_Scroll },
+    TouchPadMove { device: TrackedDeviceIndex, touch_pad_move: VREvent_TouchPadMove },
+    FocusEnter { overlay: VREvent_Overlay },
+    FocusLeave { overlay: VREvent_Overlay },
+    OverlayFocusChanged { overlay: VREvent_Overlay },
+
+    InputFocusCaptured { process: VREvent_Process },
+    InputFocusReleased { process: VREvent_Process },
+    SceneApplicationChanged { process: VREvent_Process },
+    SceneFocusChanged { process: VREvent_Process },
+    InputFocusChanged { process: VREvent_Process },
+    SceneApplicationUsingWrongGraphicsAdapter { process: VREvent_Process },
+    ActionBindingReloaded { process: VREvent_Process },
+    Quit { process: VREvent_Process },
+    ProcessQuit { process: VREvent_Process },
+    QuitAcknowledged { process: VREvent_Process },
+    MonitorShowHeadsetView { process: VREvent_Process },
+    MonitorHideHeadsetView { process: VREvent_Process },
+
+    ScreenshotTaken { screenshot: VREvent_Screenshot },
+    ScreenshotFailed { screenshot: VREvent_Screenshot },
+    ScreenshotProgressToDashboard { progress: f32 },
+    ShowUI { ui_type: EShowUIType },
+    ShowDevTools { browser_identifier: i32 },
+
+    NotificationShown { notification: VREvent_Notification },
+    NotificationHidden { notification: VREvent_Notification },
+    NotificationBeginInteraction { notification: VREvent_Notification },
+    NotificationDestroyed { notification: VREvent_Notification },
+
+    ChaperoneDataChanged { previous_universe: u64, current_universe: u64 },
+    ChaperoneUniverseChanged { previous_universe: u64, current_universe: u64 },
+    ChaperoneTempDataChanged { previous_universe: u64, current_universe: u64 },
+    SeatedZeroPoseReset { reset_by_system_menu: bool },
+
+    StatusUpdate { status_state: u32 },
+
+    KeyboardClosed { keyboard: VREvent_Keyboard },
+    KeyboardCharInput { keyboard: VREvent_Keyboard },
+    KeyboardDone { keyboard: VREvent_Keyboard },
+
+    ApplicationMimeTypeLoad { application_launch: VREvent_ApplicationLaunch },
+    HDCPError { code: EHDCPError },
+    TrackedCameraEditingSurface { camera_surface: VREvent_EditingCameraSurface },
+    PerformanceTestFidelityLevel { fidelity_level: u32 },
+    MessageOverlayClosed { message_overlay: VREvent_MessageOverlay },
+
+    InputHapticVibration { haptic_vibration: VREvent_HapticVibration },
+    InputBindingLoadFailed { input_binding: VREvent_InputBindingLoad },
+    InputBindingLoadSuccessful { input_binding: VREvent_InputBindingLoad },
+    InputActionManifestLoadFailed { action_manifest: VREvent_InputActionManifestLoad },
+    InputProgressUpdate { progress_update: VREvent_ProgressUpdate },
+
+    SpatialAnchorsPoseUpdated { spatial_anchor: VREvent_SpatialAnchor },
+    SpatialAnchorsDescriptorUpdated { spatial_anchor: VREvent_SpatialAnchor },
+    SpatialAnchorsRequestPoseUpdate { spatial_anchor: VREvent_SpatialAnchor },
+    SpatialAnchorsRequestDescriptorUpdate { spatial_anchor: VREvent_SpatialAnchor },
+
+    /** A known event type that carries no payload, or one this enum does not break out. */
+    Other { event_type: EVREventType, device: TrackedDeviceIndex },
+    /** An event that could not be decoded safely. */
+    Raw { event_type: u32, device: TrackedDeviceIndex, data: VREvent_Data },
+}
+
+/** VREvent_Property with the property read as its raw value, so an unknown property cannot be materialized.
+* Public only so tests/layout.rs can check it against VREvent_Property. */
+#[doc(hidden)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
+pub struct RawPropertyData {
+    pub container: PropertyContainerHandle,
+    pub prop: u32,
+}
+
+impl From<&VREvent> for Event {
+    fn from(event: &VREvent) -> Event {
+        decode(event).unwrap_or(Event::Raw {
+            event_type: event.event_type,
+            device: event.tracked_device_index,
+            data: event.data,
+        })
+    }
+}
+
+/** Iterator that drains the event queue. Returned by System::poll_events. */
+pub struct Events<'a> {
+    system: System<'a>,
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        self.system.poll_next_event().map(|event| Event::from(&event))
+    }
+}
+
+/** Iterator that drains the event queue along with the pose of each event's device.
+* Returned by System::poll_events_with_pose. */
+pub struct EventsWithPose<'a> {
+    system: System<'a>,
+    origin: ETrackingUniverseOrigin,
+}
+
+impl<'a> Iterator for EventsWithPose<'a> {
+    type Item = (Event, TrackedDevicePose);
+
+    fn next(&mut self) -> Option<(Event, TrackedDevicePose)> {
+        self.system
+            .poll_next_event_with_pose(self.origin)
+            .map(|(event, pose)| (Event::from(&event), pose))
+    }
+}
+
+impl<'a> System<'a> {
+    /** Drains the event queue as typed events. Stops once the queue is empty. */
+    pub fn poll_events(&self) -> Events<'a> {
+        Events { system: *self }
+    }
+
+    /** Drains the event queue as typed events paired with the pose of the event's device when it happened.
+    * These poses are always older than the call and should not be used for rendering. */
+    pub fn poll_events_with_pose(&self, origin: ETrackingUniverseOrigin) -> EventsWithPose<'a> {
+        EventsWithPose { system: *self, origin }
+    }
+}
+
+/** Reads the leading u32 of the payload, for members whose first field is a C enum. */
+fn leading_u32(data: &VREvent_Data) -> u32 {
+    unsafe { ptr::read(data as *const VREvent_Data as *const u32) }
+}
+
+fn decode(event: &VREvent) -> Option<Event> {
+    let event_type = EVREventType::try_from(event.event_type).ok()?;
+    let device = event.tracked_device_index;
+    let data = &event.data;
+
+    // Every union read below is paired with the member the header documents for that event type,
+    // and every member is plain old data.
+    let decoded = unsafe {
+        match event_type {
+            VREvent_TrackedDeviceActivated => Event::TrackedDeviceActivated { device },
+            VREvent_TrackedDeviceDeactivated => Event::TrackedDeviceDeactivated { device },
+            VREvent_TrackedDeviceUpdated => Event::TrackedDeviceUpdated { device },
+            VREvent_TrackedDeviceUserInteractionStarted => Event::TrackedDeviceUserInteractionStarted { device },
+            VREvent_TrackedDeviceUserInteractionEnded => Event::TrackedDeviceUserInteractionEnded { device },
+            VREvent_TrackedDeviceRoleChanged => Event::TrackedDeviceRoleChanged { device },
+            VREvent_IpdChanged => Event::IpdChanged { ipd_meters: data.ipd.ipd_meters },
+            VREvent_PropertyChanged => {
+                let property = ptr::read_unaligned(data as *const VREvent_Data as *const RawPropertyData);
+                Event::PropertyChanged {
+                    container: property.container,
+                    prop: ETrackedDeviceProperty::try_from(property.prop).ok()?,
+                }
+            }
+
+            VREvent_ButtonPress | VREvent_ButtonUnpress | VREvent_ButtonTouch | VREvent_ButtonUntouch => {
+                let button = EVRButtonId::try_from(data.controller.button).ok()?;
+                match event_type {
+                    VREvent_ButtonPress => Event::ButtonPress { device, button },
+                    VREvent_ButtonUnpress => Event::ButtonUnpress { device, button },
+                    VREvent_ButtonTouch => Event::ButtonTouch { device, button },
+                    _ => Event::ButtonUntouch { device, button },
+                }
+            }
+
+            VREvent_MouseMove => Event::MouseMove { device, mouse: data.mouse },
+            VREvent_MouseButtonDown => Event::MouseButtonDown { device, mouse: data.mouse },
+            VREvent_MouseButtonUp => Event::MouseButtonUp { device, mouse: data.mouse },
+            VREvent_ScrollDiscrete => Event::ScrollDiscrete { device, scroll: data.scroll },
+            VREvent_ScrollSmooth => Event::ScrollSmooth { device, scroll: data.scroll },
+            VREvent_TouchPadMove => Event::TouchPadMove { device, touch_pad_move: data.touch_pad_move },
+            VREvent_FocusEnter => Event::FocusEnter { overlay: data.overlay },
+            VREvent_FocusLeave => Event::FocusLeave { overlay: data.overlay },
+            VREvent_OverlayFocusChanged => Event::OverlayFocusChanged { overlay: data.overlay },
+
+            VREvent_InputFocusCaptured => Event::InputFocusCaptured { process: data.process },
+            VREvent_InputFocusReleased => Event::InputFocusReleased { process: data.process },
+            VREvent_SceneApplicationChanged => Event::SceneApplicationChanged { process: data.process },
+            VREvent_SceneFocusChanged => Event::SceneFocusChanged { process: data.process },
+            VREvent_InputFocusChanged => Event::InputFocusChanged { process: data.process },
+            VREvent_SceneApplicationUsingWrongGraphicsAdapter => {
+                Event::SceneApplicationUsingWrongGraphicsAdapter { process: data.process }
+            }
+            VREvent_ActionBindingReloaded => Event::ActionBindingReloaded { process: data.process },
+            VREvent_Quit => Event::Quit { process: data.process },
+            VREvent_ProcessQuit => Event::ProcessQuit { process: data.process },
+            VREvent_QuitAcknowledged => Event::QuitAcknowledged { process: data.process },
+            VREvent_Monitor_ShowHeadsetView => Event::MonitorShowHeadsetView { process: data.process },
+            VREvent_Monitor_HideHeadsetView => Event::MonitorHideHeadsetView { process: data.process },
+
+            VREvent_ScreenshotTaken => Event::ScreenshotTaken { screenshot: data.screenshot },
+            VREvent_ScreenshotFailed => Event::ScreenshotFailed { screenshot: data.screenshot },
+            VREvent_ScreenshotProgressToDashboard => {
+                Event::ScreenshotProgressToDashboard { progress: data.screenshot_progress.progress }
+            }
+            VREvent_ShowUI => Event::ShowUI { ui_type: EShowUIType::try_from(leading_u32(data)).ok()? },
+            VREvent_ShowDevTools => Event::ShowDevTools { browser_identifier: data.show_dev_tools.browser_identifier },
+
+            VREvent_Notification_Shown => Event::NotificationShown { notification: data.notification },
+            VREvent_Notification_Hidden => Event::NotificationHidden { notification: data.notification },
+            VREvent_Notification_BeginInteraction => Event::NotificationBeginInteraction { notification: data.notification },
+            VREvent_Notification_Destroyed => Event::NotificationDestroyed { notification: data.notification },
+
+            VREvent_ChaperoneDataHasChanged => Event::ChaperoneDataChanged {
+                previous_universe: data.chaperone.previous_universe,
+                current_universe: data.chaperone.current_universe,
+            },
+            VREvent_ChaperoneUniverseHasChanged => Event::ChaperoneUniverseChanged {
+                previous_universe: data.chaperone.previous_universe,
+                current_universe: data.chaperone.current_universe,
+            },
+            VREvent_ChaperoneTempDataHasChanged => Event::ChaperoneTempDataChanged {
+                previous_universe: data.chaperone.previous_universe,
+                current_universe: data.chaperone.current_universe,
+            },
+            VREvent_SeatedZeroPoseReset => Event::SeatedZeroPoseReset {
+                reset_by_system_menu: data.seated_zero_pose_reset.reset_by_system_menu,
+            },
+
+            VREvent_StatusUpdate => Event::StatusUpdate { status_state: data.status.status_state },
+
+            VREvent_KeyboardClosed => Event::KeyboardClosed { keyboard: data.keyboard },
+            VREvent_KeyboardCharInput => Event::KeyboardCharInput { keyboard: data.keyboard },
+            VREvent_KeyboardDone => Event::KeyboardDone { keyboard: data.keyboard },
+
+            VREvent_ApplicationMimeTypeLoad => Event::ApplicationMimeTypeLoad { application_launch: data.application_launch },
+            VREvent_Compositor_HDCPError => Event::HDCPError { code: EHDCPError::try_from(leading_u32(data)).ok()? },
+            VREvent_TrackedCamera_EditingSurface => Event::TrackedCameraEditingSurface { camera_surface: data.camera_surface },
+            VREvent_PerformanceTest_FidelityLevel => {
+                Event::PerformanceTestFidelityLevel { fidelity_level: data.performance_test.fidelity_level }
+            }
+            VREvent_MessageOverlay_Closed => Event::MessageOverlayClosed { message_overlay: data.message_overlay },
+
+            VREvent_Input_HapticVibration => Event::InputHapticVibration { haptic_vibration: data.haptic_vibration },
+            VREvent_Input_BindingLoadFailed => Event::InputBindingLoadFailed { input_binding: data.input_binding },
+            VREvent_Input_BindingLoadSuccessful => Event::InputBindingLoadSuccessful { input_binding: data.input_binding },
+            VREvent_Input_ActionManifestLoadFailed => {
+                Event::InputActionManifestLoadFailed { action_manifest: data.action_manifest }
+            }
+            VREvent_Input_ProgressUpdate => Event::InputProgressUpdate { progress_update: data.progress_update },
+
+            VREvent_SpatialAnchors_PoseUpdated => Event::SpatialAnchorsPoseUpdated { spatial_anchor: data.spatial_anchor },
+            VREvent_SpatialAnchors_DescriptorUpdated => {
+                Event::SpatialAnchorsDescriptorUpdated { spatial_anchor: data.spatial_anchor }
+            }
+            VREvent_SpatialAnchors_RequestPoseUpdate => {
+                Event::SpatialAnchorsRequestPoseUpdate { spatial_anchor: data.spatial_anchor }
+            }
+            VREvent_SpatialAnchors_RequestDescriptorUpdate => {
+                Event::SpatialAnchorsRequestDescriptorUpdate { spatial_anchor: data.spatial_anchor }
+            }
+
+            event_type => Event::Other { event_type, device },
+        }
+    };
+
+    Some(decoded)
+}
+
+enum_try_from_u32!(EVREventType {
+    VREvent_None, VREvent_TrackedDeviceActivated, VREvent_TrackedDeviceDeactivated,
+    VREvent_TrackedDeviceUpdated, VREvent_TrackedDeviceUserInteractionStarted,
+    VREvent_TrackedDeviceUserInteractionEnded, VREvent_IpdChanged, VREvent_EnterStandbyMode,
+    VREvent_LeaveStandbyMode, VREvent_TrackedDeviceRoleChanged, VREvent_WatchdogWakeUpRequested,
+    VREvent_LensDistortionChanged, VREvent_PropertyChanged, VREvent_WirelessDisconnect,
+    VREvent_WirelessReconnect, VREvent_ButtonPress, VREvent_ButtonUnpress, VREvent_ButtonTouch,
+    VREvent_ButtonUntouch, VREvent_Modal_Cancel, VREvent_MouseMove, VREvent_MouseButtonDown,
+    VREvent_MouseButtonUp, VREvent_FocusEnter, VREvent_FocusLeave, VREvent_ScrollDiscrete,
+    VREvent_TouchPadMove, VREvent_OverlayFocusChanged, VREvent_ReloadOverlays, VREvent_ScrollSmooth,
+    VREvent_LockMousePosition, VREvent_UnlockMousePosition, VREvent_InputFocusCaptured,
+    VREvent_InputFocusReleased, VREvent_SceneApplicationChanged, VREvent_SceneFocusChanged,
+    VREvent_InputFocusChanged, VREvent_SceneApplicationUsingWrongGraphicsAdapter,
+    VREvent_ActionBindingReloaded, VREvent_HideRenderModels, VREvent_ShowRenderModels,
+    VREvent_SceneApplicationStateChanged, VREvent_ConsoleOpened, VREvent_ConsoleClosed, VREvent_OverlayShown,
+    VREvent_OverlayHidden, VREvent_DashboardActivated, VREvent_DashboardDeactivated,
+    VREvent_DashboardRequested, VREvent_ResetDashboard, VREvent_ImageLoaded, VREvent_ShowKeyboard,
+    VREvent_HideKeyboard, VREvent_OverlayGamepadFocusGained, VREvent_OverlayGamepadFocusLost,
+    VREvent_OverlaySharedTextureChanged, VREvent_ScreenshotTriggered, VREvent_ImageFailed,
+    VREvent_DashboardOverlayCreated, VREvent_SwitchGamepadFocus, VREvent_RequestScreenshot,
+    VREvent_ScreenshotTaken, VREvent_ScreenshotFailed, VREvent_SubmitScreenshotToDashboard,
+    VREvent_ScreenshotProgressToDashboard, VREvent_PrimaryDashboardDeviceChanged, VREvent_RoomViewShown,
+    VREvent_RoomViewHidden, VREvent_ShowUI, VREvent_ShowDevTools, VREvent_DesktopViewUpdating,
+    VREvent_DesktopViewReady, VREvent_Notification_Shown, VREvent_Notification_Hidden,
+    VREvent_Notification_BeginInteraction, VREvent_Notification_Destroyed, VREvent_Quit, VREvent_ProcessQuit,
+    VREvent_QuitAcknowledged, VREvent_DriverRequestedQuit, VREvent_RestartRequested,
+    VREvent_ChaperoneDataHasChanged, VREvent_ChaperoneUniverseHasChanged, VREvent_ChaperoneTempDataHasChanged,
+    VREvent_ChaperoneSettingsHaveChanged, VREvent_SeatedZeroPoseReset, VREvent_ChaperoneFlushCache,
+    VREvent_ChaperoneRoomSetupStarting, VREvent_ChaperoneRoomSetupFinished, VREvent_StandingZeroPoseReset,
+    VREvent_AudioSettingsHaveChanged, VREvent_BackgroundSettingHasChanged, VREvent_CameraSettingsHaveChanged,
+    VREvent_ReprojectionSettingHasChanged, VREvent_ModelSkinSettingsHaveChanged,
+    VREvent_EnvironmentSettingsHaveChanged, VREvent_PowerSettingsHaveChanged,
+    VREvent_EnableHomeAppSettingsHaveChanged, VREvent_SteamVRSectionSettingChanged,
+    VREvent_LighthouseSectionSettingChanged, VREvent_NullSectionSettingChanged,
+    VREvent_UserInterfaceSectionSettingChanged, VREvent_NotificationsSectionSettingChanged,
+    VREvent_KeyboardSectionSettingChanged, VREvent_PerfSectionSettingChanged,
+    VREvent_DashboardSectionSettingChanged, VREvent_WebInterfaceSectionSettingChanged,
+    VREvent_TrackersSectionSettingChanged, VREvent_LastKnownSectionSettingChanged,
+    VREvent_DismissedWarningsSectionSettingChanged, VREvent_GpuSpeedSectionSettingChanged,
+    VREvent_WindowsMRSectionSettingChanged, VREvent_OtherSectionSettingChanged, VREvent_StatusUpdate,
+    VREvent_WebInterface_InstallDriverCompleted, VREvent_MCImageUpdated, VREvent_FirmwareUpdateStarted,
+    VREvent_FirmwareUpdateFinished, VREvent_KeyboardClosed, VREvent_KeyboardCharInput, VREvent_KeyboardDone,
+    VREvent_ApplicationListUpdated, VREvent_ApplicationMimeTypeLoad, VREvent_ProcessConnected,
+    VREvent_ProcessDisconnected, VREvent_Compositor_ChaperoneBoundsShown,
+    VREvent_Compositor_ChaperoneBoundsHidden, VREvent_Compositor_DisplayDisconnected,
+    VREvent_Compositor_DisplayReconnected, VREvent_Compositor_HDCPError,
+    VREvent_Compositor_ApplicationNotResponding, VREvent_Compositor_ApplicationResumed,
+    VREvent_Compositor_OutOfVideoMemory, VREvent_Compositor_DisplayModeNotSupported,
+    VREvent_Compositor_StageOverrideReady, VREvent_TrackedCamera_StartVideoStream,
+    VREvent_TrackedCamera_StopVideoStream, VREvent_TrackedCamera_PauseVideoStream,
+    VREvent_TrackedCamera_ResumeVideoStream, VREvent_TrackedCamera_EditingSurface,
+    VREvent_PerformanceTest_EnableCapture, VREvent_PerformanceTest_DisableCapture,
+    VREvent_PerformanceTest_FidelityLevel, VREvent_MessageOverlay_Closed,
+    VREvent_MessageOverlayCloseRequested, VREvent_Input_HapticVibration, VREvent_Input_BindingLoadFailed,
+    VREvent_Input_BindingLoadSuccessful, VREvent_Input_ActionManifestReloaded,
+    VREvent_Input_ActionManifestLoadFailed, VREvent_Input_ProgressUpdate, VREvent_Input_TrackerActivated,
+    VREvent_Input_BindingsUpdated, VREvent_Input_BindingSubscriptionChanged,
+    VREvent_SpatialAnchors_PoseUpdated, VREvent_SpatialAnchors_DescriptorUpdated,
+    VREvent_SpatialAnchors_RequestPoseUpdate, VREvent_SpatialAnchors_RequestDescriptorUpdate,
+    VREvent_SystemReport_Started, VREvent_Monitor_ShowHeadsetView, VREvent_Monitor_HideHeadsetView,
+    VREvent_VendorSpecific_Reserved_Start, VREvent_VendorSpecific_Reserved_End,
+});
+
+enum_try_from_u32!(EVRButtonId {
+    k_EButton_System, k_EButton_ApplicationMenu, k_EButton_Grip, k_EButton_DPad_Left, k_EButton_DPad_Up,
+    k_EButton_DPad_Right, k_EButton_DPad_Down, k_EButton_A, k_EButton_ProximitySensor, k_EButton_Axis0,
+    k_EButton_Axis1, k_EButton_Axis2, k_EButton_Axis3, k_EButton_Axis4, k_EButton_Max,
+});
+
+enum_try_from_u32!(EShowUIType {
+    ShowUI_ControllerBinding, ShowUI_ManageTrackers, ShowUI_Pairing, ShowUI_Settings, ShowUI_DebugCommands,
+    ShowUI_FullControllerBinding, ShowUI_ManageDrivers,
+});
+
+enum_try_from_u32!(EHDCPError {
+    HDCPError_None, HDCPError_LinkLost, HDCPError_Tampered, HDCPError_DeviceRevoked, HDCPError_Unknown,
+});
@@ -0,0 +1,157 @@
+use std::error::Error;
+use std::fmt;
+
+use super::compositor::{Compositor, FramePoses};
+use super::ivrcompositor::*;
+use super::texture::SubmitTexture;
+use super::vrtypes::*;
+
+/** Where an ExplicitTiming frame loop is within the current frame. */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExplicitFrameState {
+    /** Waiting for wait_get_poses. This is the state after post_present_handoff. */
+    WaitingForPoses,
+    /** Poses are in; SubmitExplicitTimingData has to come before the first queue submission. */
+    WaitingForTimingData,
+    /** Timing data is in; the app may submit GPU work and then the eye textures. */
+    Rendering,
+    /** One eye has been submitted. */
+    EyeSubmitted(EVREye),
+    /** Both eyes have been submitted; waiting for post_present_handoff. */
+    WaitingForHandoff,
+}
+
+/** Errors from the explicit timing frame loop */
+#[derive(Debug, PartialEq)]
+pub enum ExplicitTimingError {
+    /** The named call is not allowed in the state the frame is in. Nothing was passed to the runtime. */
+    OutOfOrder(&'static str, ExplicitFrameState),
+    /** The runtime rejected the call. The frame stays in the state it was in; abandon_frame gives up on it. */
+    Compositor(EVRCompositorError),
+}
+
+impl fmt::Display for ExplicitTimingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExplicitTimingError::OutOfOrder(call, state) => write!(f, "{} called out of order, in state {:?}", call, state),
+            ExplicitTimingError::Compositor(error) => write!(f, "compositor error: {:?}", error),
+        }
+    }
+}
+
+impl Error for ExplicitTimingError {}
+
+impl From<EVRCompositorError> for ExplicitTimingError {
+    fn from(error: EVRCompositorError) -> ExplicitTimingError {
+        ExplicitTimingError::Compositor(error)
+    }
+}
+
+/** The frame loop for VRCompositorTimingMode_Explicit_ApplicationPerformsPostPresentHandoff, which Vulkan
+* and D3D12 apps use to control when the compositor starts on a frame. Each frame has to go
+*
+*   wait_get_poses, submit_queue (or submit_timing_data before the first queue submission),
+*   submit for both eyes, post_present_handoff
+*
+* and calls made out of that order are rejected with ExplicitTimingError::OutOfOrder. Obtained from
+* Compositor::explicit_timing; dropping it switches the compositor back to implicit timing. */
+pub struct ExplicitTiming<'a> {
+    compositor: Compositor<'a>,
+    state: ExplicitFrameState,
+}
+
+impl<'a> ExplicitTiming<'a> {
+    pub(crate) fn new(compositor: Compositor<'a>) -> ExplicitTiming<'a> {
+        compositor.set_explicit_timing_mode(
+            EVRCompositorTimingMode::VRCompositorTimingMode_Explicit_ApplicationPerformsPostPresentHandoff,
+        );
+        ExplicitTiming { compositor, state: ExplicitFrameState::WaitingForPoses }
+    }
+
+    /** The calls allowed next follow from this. */
+    pub fn state(&self) -> ExplicitFrameState {
+        self.state
+    }
+
+    fn expect(&self, call: &'static str, allowed: bool) -> Result<(), ExplicitTimingError> {
+        if allowed {
+            Ok(())
+        } else {
+            Err(ExplicitTimingError::OutOfOrder(call, self.state))
+        }
+    }
+
+    /** Starts a frame. Only allowed once the previous frame has been handed off. */
+    pub fn wait_get_poses(&mut self) -> Result<FramePoses, ExplicitTimingError> {
+        self.expect("WaitGetPoses", self.state == ExplicitFrameState::WaitingForPoses)?;
+        let poses = self.compositor.wait_get_poses()?;
+        self.state = ExplicitFrameState::WaitingForTimingData;
+        Ok(poses)
+    }
+
+    /** Calls SubmitExplicitTimingData. It has to be followed immediately by the frame's first queue
+    * submission, on the same thread; submit_queue does both. */
+    pub fn submit_timing_data(&mut self) -> Result<(), ExplicitTimingError> {
+        self.expect("SubmitExplicitTimingData", self.state == ExplicitFrameState::WaitingForTimingData)?;
+        self.compositor.submit_explicit_timing_data()?;
+        self.state = ExplicitFrameState::Rendering;
+        Ok(())
+    }
+
+    /** Runs `submit`, which should make a vkQueueSubmit or ExecuteCommandLists call. For the frame's first
+    * queue submission SubmitExplicitTimingData is called right before it; later ones, up to the second
+    * eye's Submit, just run `submit`. */
+    pub fn submit_queue<F, R>(&mut self, submit: F) -> Result<R, ExplicitTimingError>
+    where
+        F: FnOnce() -> R,
+    {
+        match self.state {
+            ExplicitFrameState::WaitingForTimingData => self.submit_timing_data()?,
+            ExplicitFrameState::Rendering | ExplicitFrameState::EyeSubmitted(_) => {}
+            _ => return Err(ExplicitTimingError::OutOfOrder("queue submission", self.state)),
+        }
+        Ok(submit())
+    }
+
+    /** Submits the texture for one eye. Each eye is submitted once per frame, in either order.
+    *
+    * # Safety
+    * Same contract as Compositor::submit. */
+    pub unsafe fn submit(
+        &mut self,
+        eye: EVREye,
+        texture: &SubmitTexture,
+        bounds: Option<VRTextureBounds>,
+        flags: EVRSubmitFlags,
+    ) -> Result<(), ExplicitTimingError> {
+        let next = match self.state {
+            ExplicitFrameState::Rendering => ExplicitFrameState::EyeSubmitted(eye),
+            ExplicitFrameState::EyeSubmitted(submitted) if submitted != eye => ExplicitFrameState::WaitingForHandoff,
+            _ => return Err(ExplicitTimingError::OutOfOrder("Submit", self.state)),
+        };
+        self.compositor.submit(eye, texture, bounds, flags)?;
+        self.state = next;
+        Ok(())
+    }
+
+    /** Ends the frame once both eyes are submitted, letting the compositor start on it. */
+    pub fn post_present_handoff(&mut self) -> Result<(), ExplicitTimingError> {
+        self.expect("PostPresentHandoff", self.state == ExplicitFrameState::WaitingForHandoff)?;
+        self.compositor.post_present_handoff();
+        self.state = ExplicitFrameState::WaitingForPoses;
+        Ok(())
+    }
+
+    /** Gives up on the current frame without handing it off, so the next wait_get_poses starts a new one.
+    * This is the way out after the runtime rejects a call, for example a Submit failing with DoNotHaveFocus
+    * for as long as the app is not in focus. */
+    pub fn abandon_frame(&mut self) {
+        self.state = ExplicitFrameState::WaitingForPoses;
+    }
+}
+
+impl Drop for ExplicitTiming<'_> {
+    fn drop(&mut self) {
+        self.compositor.set_explicit_timing_mode(EVRCompositorTimingMode::VRCompositorTimingMode_Implicit);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::VecDeque;
+
+use super::compositor::Compositor;
+use super::ivrcompositor::Compositor_CumulativeStats;
+use super::vrtypes::*;
+
+/** How many of the newest timings FrameTimingRecorder::poll asks the runtime for. The runtime keeps
+* roughly a second's worth, so polling at least once a second leaves no gaps at common refresh rates. */
+pub const FRAME_TIMING_BATCH: u32 = 128;
+
+/** Statistics over the frames currently held by a FrameTimingRecorder. */
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    pub frames: usize,
+    /** Frames reprojected because the application missed its CPU budget */
+    pub cpu_reprojected: usize,
+    /** Frames reprojected because the application missed its GPU budget */
+    pub gpu_reprojected: usize,
+    /** Sum of num_dropped_frames */
+    pub dropped_frames: u64,
+    /** Sum of num_frame_presents */
+    pub frame_presents: u64,
+    /** Sum of num_mis_presented */
+    pub mis_presented: u64,
+}
+
+impl FrameStats {
+    /** The share of presents that landed on a vsync other than the predicted one. */
+    pub fn mispresent_rate(&self) -> f32 {
+        if self.frame_presents == 0 {
+            return 0.0;
+        }
+        self.mis_presented as f32 / self.frame_presents as f32
+    }
+
+    fn add(&mut self, timing: &Compositor_FrameTiming) {
+        let reason = timing.reprojection_reason();
+        self.frames += 1;
+        self.cpu_reprojected += reason.contains(VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Cpu) as usize;
+        self.gpu_reprojected += reason.contains(VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Gpu) as usize;
+        self.dropped_frames += timing.num_dropped_frames as u64;
+        self.frame_presents += timing.num_frame_presents as u64;
+        self.mis_presented += timing.num_mis_presented as u64;
+    }
+}
+
+/** Totals over every frame a FrameTimingRecorder has seen, including those since dropped from its buffer. */
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SessionSummary {
+    pub stats: FrameStats,
+    /** Frames reprojected for either reason */
+    pub reprojected_frames: u64,
+    /** Frame indices that were skipped because the runtime no longer had their timing when polled */
+    pub missed_frames: u64,
+}
+
+impl SessionSummary {
+    /** Pairs the recorded totals with the runtime's own counters, as (recorded, runtime).
+    * The runtime's counters cover the whole process, so they only match if recording started with the
+    * app's first frame and no frames were missed. */
+    pub fn compare(&self, stats: &Compositor_CumulativeStats) -> SessionComparison {
+        SessionComparison {
+            frame_presents: (self.stats.frame_presents, stats.m_nNumFramePresents as u64),
+            dropped_frames: (self.stats.dropped_frames, stats.m_nNumDroppedFrames as u64),
+            reprojected_frames: (self.reprojected_frames, stats.m_nNumReprojectedFrames as u64),
+        }
+    }
+}
+
+/** Counters from SessionSummary::compare, as (recorded, runtime). */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SessionComparison {
+    pub frame_presents: (u64, u64),
+    pub dropped_frames: (u64, u64),
+    pub reprojected_frames: (u64, u64),
+}
+
+/** Collects Compositor_FrameTiming over a session. Each poll keeps only the frames newer than the last one
+* recorded, so frames are neither counted twice nor skipped as long as it is polled often enough.
+* The newest `capacity` frames are kept for rolling statistics; the session summary covers all of them. */
+#[derive(Clone, Debug)]
+pub struct FrameTimingRecorder {
+    frames: VecDeque<Compositor_FrameTiming>,
+    capacity: usize,
+    last_frame_index: Option<u32>,
+    session: SessionSummary,
+}
+
+impl FrameTimingRecorder {
+    pub fn new(capacity: usize) -> FrameTimingRecorder {
+        FrameTimingRecorder {
+            frames: VecDeque::with_capacity(capacity),
+            capacity,
+            last_frame_index: None,
+            session: SessionSummary::default(),
+        }
+    }
+
+    /** Pulls the frames completed since the last poll. Returns how many were recorded.
+    * The newest timing is the frame still being shown, whose present, mispresent and dropped counts can still
+    * go up, so it is left for the next poll. */
+    pub fn poll(&mut self, compositor: &Compositor) -> usize {
+        let timings = compositor.frame_timings(FRAME_TIMING_BATCH);
+        self.record(&timings[..timings.len().saturating_sub(1)])
+    }
+
+    /** Records a batch of timings, oldest first as GetFrameTimings returns them. Frames already recorded
+    * are skipped. Returns how many were recorded. */
+    pub fn record(&mut self, timings: &[Compositor_FrameTiming]) -> usize {
+        let mut recorded = 0;
+
+        for timing in timings {
+            let frame_index = timing.frame_index;
+            if let Some(last) = self.last_frame_index {
+                // Compared as a signed distance so the recorder keeps working if frame_index wraps.
+                let distance = frame_index.wrapping_sub(last) as i32;
+                if distance <= 0 {
+                    continue;
+                }
+                self.session.missed_frames += distance as u64 - 1;
+            }
+
+            self.last_frame_index = Some(frame_index);
+            self.session.stats.add(timing);
+            self.session.reprojected_frames += timing.was_reprojected() as u64;
+
+            if self.frames.len() == self.capacity {
+                self.frames.pop_front();
+            }
+            if self.capacity > 0 {
+                self.frames.push_back(*timing);
+            }
+            recorded += 1;
+        }
+
+        recorded
+    }
+
+    /** The buffered frames, oldest first. */
+    pub fn frames(&self) -> impl Iterator<Item = &Compositor_FrameTiming> {
+        self.frames.iter()
+    }
+
+    /** The frame_index of the newest frame recorded. */
+    pub fn last_frame_index(&self) -> Option<u32> {
+        self.last_frame_index
+    }
+
+    /** Statistics over the buffered frames. */
+    pub fn stats(&self) -> FrameStats {
+        let mut stats = FrameStats::default();
+        for timing in &self.frames {
+            stats.add(timing);
+        }
+        stats
+    }
+
+    /** The nearest-rank percentile (0 to 100) of total_render_gpu_ms over the buffered frames. */
+    pub fn gpu_ms_percentile(&self, percentile: f32) -> Option<f32> {
+        let mut times: Vec<f32> = self.frames.iter().map(|timing| timing.total_render_gpu_ms).collect();
+        if times.is_empty() {
+            return None;
+        }
+
+        times.sort_by(f32::total_cmp);
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * times.len() as f32).ceil() as usize;
+        Some(times[rank.saturating_sub(1)])
+    }
+
+    /** Totals over every frame recorded so far. */
+    pub fn summary(&self) -> SessionSummary {
+        self.session
+    }
+
+    /** Forgets all frames, for starting a new session. */
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.last_frame_index = None;
+        self.session = SessionSummary::default();
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+/** From, for conversions From cannot be implemented for. */
+pub trait FromMath<T>: Sized {
+    fn from_math(value: T) -> Self;
+}
+
+/** Into, for conversions Into cannot be implemented for. Implemented for every FromMath. */
+pub trait IntoMath<T> {
+    fn into_math(self) -> T;
+}
+
+impl<T, U: FromMath<T>> IntoMath<U> for T {
+    fn into_math(self) -> U {
+        U::from_math(self)
+    }
+}
+
@@ -0,0 +1,193 @@
+use glam::{Affine3A, DQuat, DVec3, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+use super::FromMath;
+use crate::vr::math::HmdMatrix34Ext;
+use crate::vr::public_vrtypes::*;
+use crate::vr::vrtypes::TrackedDevicePose;
+
+impl FromMath<HmdMatrix34> for Affine3A {
+    fn from_math(m: HmdMatrix34) -> Affine3A {
+        let column = |c: usize| Vec3A::new(m[0][c], m[1][c], m[2][c]);
+        Affine3A::from_cols(column(0), column(1), column(2), column(3))
+    }
+}
+
+impl FromMath<Affine3A> for HmdMatrix34 {
+    fn from_math(affine: Affine3A) -> HmdMatrix34 {
+        let c = affine.to_cols_array_2d();
+        let row = |r: usize| [c[0][r], c[1][r], c[2][r], c[3][r]];
+        [row(0), row(1), row(2)]
+    }
+}
+
+/** With [0, 0, 0, 1] as the bottom row. */
+impl FromMath<HmdMatrix34> for Mat4 {
+    fn from_math(m: HmdMatrix34) -> Mat4 {
+        Mat4::from_math(m.to_44())
+    }
+}
+
+/** Drops the bottom row. */
+impl FromMath<Mat4> for HmdMatrix34 {
+    fn from_math(matrix: Mat4) -> HmdMatrix34 {
+        HmdMatrix34::from_44(&HmdMatrix44::from_math(matrix))
+    }
+}
+
+impl FromMath<HmdMatrix33> for Mat3 {
+    fn from_math(m: HmdMatrix33) -> Mat3 {
+        Mat3::from_cols_array_2d(&m).transpose()
+    }
+}
+
+impl FromMath<Mat3> for HmdMatrix33 {
+    fn from_math(matrix: Mat3) -> HmdMatrix33 {
+        matrix.transpose().to_cols_array_2d()
+    }
+}
+
+impl FromMath<HmdMatrix44> for Mat4 {
+    fn from_math(m: HmdMatrix44) -> Mat4 {
+        Mat4::from_cols_array_2d(&m).transpose()
+    }
+}
+
+impl FromMath<Mat4> for HmdMatrix44 {
+    fn from_math(matrix: Mat4) -> HmdMatrix44 {
+        matrix.transpose().to_cols_array_2d()
+    }
+}
+
+impl FromMath<HmdVector2> for Vec2 {
+    fn from_math(v: HmdVector2) -> Vec2 {
+        Vec2::from_array(v)
+    }
+}
+
+impl FromMath<Vec2> for HmdVector2 {
+    fn from_math(v: Vec2) -> HmdVector2 {
+        v.to_array()
+    }
+}
+
+impl FromMath<HmdVector3> for Vec3 {
+    fn from_math(v: HmdVector3) -> Vec3 {
+        Vec3::from_array(v)
+    }
+}
+
+impl FromMath<Vec3> for HmdVector3 {
+    fn from_math(v: Vec3) -> HmdVector3 {
+        v.to_array()
+    }
+}
+
+impl FromMath<HmdVector4> for Vec4 {
+    fn from_math(v: HmdVector4) -> Vec4 {
+        Vec4::from_array(v)
+    }
+}
+
+impl FromMath<Vec4> for HmdVector4 {
+    fn from_math(v: Vec4) -> HmdVector4 {
+        v.to_array()
+    }
+}
+
+impl FromMath<HmdVector3d> for DVec3 {
+    fn from_math(v: HmdVector3d) -> DVec3 {
+        DVec3::from_array(v)
+    }
+}
+
+impl FromMath<DVec3> for HmdVector3d {
+    fn from_math(v: DVec3) -> HmdVector3d {
+        v.to_array()
+    }
+}
+
+impl FromMath<HmdQuad> for [Vec3; 4] {
+    fn from_math(quad: HmdQuad) -> [Vec3; 4] {
+        quad.map(Vec3::from_array)
+    }
+}
+
+impl FromMath<[Vec3; 4]> for HmdQuad {
+    fn from_math(corners: [Vec3; 4]) -> HmdQuad {
+        corners.map(|corner| corner.to_array())
+    }
+}
+
+impl From<HmdQuaternionf> for Quat {
+    fn from(q: HmdQuaternionf) -> Quat {
+        Quat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Quat> for HmdQuaternionf {
+    fn from(q: Quat) -> HmdQuaternionf {
+        HmdQuaternionf { w: q.w, x: q.x, y: q.y, z: q.z }
+    }
+}
+
+impl From<HmdQuaternion> for DQuat {
+    fn from(q: HmdQuaternion) -> DQuat {
+        DQuat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<DQuat> for HmdQuaternion {
+    fn from(q: DQuat) -> HmdQuaternion {
+        HmdQuaternion { w: q.w, x: q.x, y: q.y, z: q.z }
+    }
+}
+
+/** RGBA as x, y, z, w. */
+impl From<HmdColor> for Vec4 {
+    fn from(color: HmdColor) -> Vec4 {
+        Vec4::new(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Vec4> for HmdColor {
+    fn from(v: Vec4) -> HmdColor {
+        HmdColor { r: v.x, g: v.y, b: v.z, a: v.w }
+    }
+}
+
+/** The top left and bottom right corners. */
+impl From<HmdRect2> for (Vec2, Vec2) {
+    fn from(rect: HmdRect2) -> (Vec2, Vec2) {
+        (Vec2::from_array(rect.top_left), Vec2::from_array(rect.bottom_right))
+    }
+}
+
+impl From<(Vec2, Vec2)> for HmdRect2 {
+    fn from((top_left, bottom_right): (Vec2, Vec2)) -> HmdRect2 {
+        HmdRect2 { top_left: top_left.to_array(), bottom_right: bottom_right.to_array() }
+    }
+}
+
+/** The position's w is dropped. */
+impl From<VRBoneTransform> for Affine3A {
+    fn from(bone: VRBoneTransform) -> Affine3A {
+        let [x, y, z, _] = bone.position;
+        Affine3A::from_rotation_translation(bone.orientation.into(), Vec3::new(x, y, z))
+    }
+}
+
+/** The transform has to be rigid. The position's w is 1. */
+impl From<Affine3A> for VRBoneTransform {
+    fn from(affine: Affine3A) -> VRBoneTransform {
+        let matrix = HmdMatrix34::from_math(affine);
+        let [x, y, z] = matrix.position();
+        VRBoneTransform { position: [x, y, z, 1.0], orientation: matrix.rotation_quat() }
+    }
+}
+
+/** The device to absolute tracking transform. */
+impl From<TrackedDevicePose> for Affine3A {
+    fn from(pose: TrackedDevicePose) -> Affine3A {
+        Affine3A::from_math(pose.device_to_absolute_tracking)
+    }
+}
@@ -0,0 +1,213 @@
+use mint::{
+    ColumnMatrix3, ColumnMatrix3x4, ColumnMatrix4, Point2, Quaternion, RowMatrix3, RowMatrix3x4, RowMatrix4, Vector2,
+    Vector3, Vector4,
+};
+
+use super::FromMath;
+use crate::vr::public_vrtypes::*;
+use crate::vr::vrtypes::TrackedDevicePose;
+
+/* mint has both layouts. The row matrices match OpenVR's; the column matrices are what glam, nalgebra and
+ * most renderers take. */
+
+impl FromMath<HmdMatrix34> for RowMatrix3x4<f32> {
+    fn from_math(m: HmdMatrix34) -> RowMatrix3x4<f32> {
+        m.into()
+    }
+}
+
+impl FromMath<RowMatrix3x4<f32>> for HmdMatrix34 {
+    fn from_math(matrix: RowMatrix3x4<f32>) -> HmdMatrix34 {
+        matrix.into()
+    }
+}
+
+impl FromMath<HmdMatrix34> for ColumnMatrix3x4<f32> {
+    fn from_math(m: HmdMatrix34) -> ColumnMatrix3x4<f32> {
+        RowMatrix3x4::from(m).into()
+    }
+}
+
+impl FromMath<ColumnMatrix3x4<f32>> for HmdMatrix34 {
+    fn from_math(matrix: ColumnMatrix3x4<f32>) -> HmdMatrix34 {
+        RowMatrix3x4::from(matrix).into()
+    }
+}
+
+impl FromMath<HmdMatrix33> for RowMatrix3<f32> {
+    fn from_math(m: HmdMatrix33) -> RowMatrix3<f32> {
+        m.into()
+    }
+}
+
+impl FromMath<RowMatrix3<f32>> for HmdMatrix33 {
+    fn from_math(matrix: RowMatrix3<f32>) -> HmdMatrix33 {
+        matrix.into()
+    }
+}
+
+impl FromMath<HmdMatrix33> for ColumnMatrix3<f32> {
+    fn from_math(m: HmdMatrix33) -> ColumnMatrix3<f32> {
+        RowMatrix3::from(m).into()
+    }
+}
+
+impl FromMath<ColumnMatrix3<f32>> for HmdMatrix33 {
+    fn from_math(matrix: ColumnMatrix3<f32>) -> HmdMatrix33 {
+        RowMatrix3::from(matrix).into()
+    }
+}
+
+impl FromMath<HmdMatrix44> for RowMatrix4<f32> {
+    fn from_math(m: HmdMatrix44) -> RowMatrix4<f32> {
+        m.into()
+    }
+}
+
+impl FromMath<RowMatrix4<f32>> for HmdMatrix44 {
+    fn from_math(matrix: RowMatrix4<f32>) -> HmdMatrix44 {
+        matrix.into()
+    }
+}
+
+impl FromMath<HmdMatrix44> for ColumnMatrix4<f32> {
+    fn from_math(m: HmdMatrix44) -> ColumnMatrix4<f32> {
+        RowMatrix4::from(m).into()
+    }
+}
+
+impl FromMath<ColumnMatrix4<f32>> for HmdMatrix44 {
+    fn from_math(matrix: ColumnMatrix4<f32>) -> HmdMatrix44 {
+        RowMatrix4::from(matrix).into()
+    }
+}
+
+impl FromMath<HmdVector2> for Vector2<f32> {
+    fn from_math(v: HmdVector2) -> Vector2<f32> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector2<f32>> for HmdVector2 {
+    fn from_math(v: Vector2<f32>) -> HmdVector2 {
+        v.into()
+    }
+}
+
+impl FromMath<HmdVector3> for Vector3<f32> {
+    fn from_math(v: HmdVector3) -> Vector3<f32> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector3<f32>> for HmdVector3 {
+    fn from_math(v: Vector3<f32>) -> HmdVector3 {
+        v.into()
+    }
+}
+
+impl FromMath<HmdVector4> for Vector4<f32> {
+    fn from_math(v: HmdVector4) -> Vector4<f32> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector4<f32>> for HmdVector4 {
+    fn from_math(v: Vector4<f32>) -> HmdVector4 {
+        v.into()
+    }
+}
+
+impl FromMath<HmdVector3d> for Vector3<f64> {
+    fn from_math(v: HmdVector3d) -> Vector3<f64> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector3<f64>> for HmdVector3d {
+    fn from_math(v: Vector3<f64>) -> HmdVector3d {
+        v.into()
+    }
+}
+
+impl FromMath<HmdQuad> for [Vector3<f32>; 4] {
+    fn from_math(quad: HmdQuad) -> [Vector3<f32>; 4] {
+        quad.map(Vector3::from)
+    }
+}
+
+impl FromMath<[Vector3<f32>; 4]> for HmdQuad {
+    fn from_math(corners: [Vector3<f32>; 4]) -> HmdQuad {
+        corners.map(Vector3::into)
+    }
+}
+
+impl From<HmdQuaternionf> for Quaternion<f32> {
+    fn from(q: HmdQuaternionf) -> Quaternion<f32> {
+        Quaternion { v: Vector3 { x: q.x, y: q.y, z: q.z }, s: q.w }
+    }
+}
+
+impl From<Quaternion<f32>> for HmdQuaternionf {
+    fn from(q: Quaternion<f32>) -> HmdQuaternionf {
+        HmdQuaternionf { w: q.s, x: q.v.x, y: q.v.y, z: q.v.z }
+    }
+}
+
+impl From<HmdQuaternion> for Quaternion<f64> {
+    fn from(q: HmdQuaternion) -> Quaternion<f64> {
+        Quaternion { v: Vector3 { x: q.x, y: q.y, z: q.z }, s: q.w }
+    }
+}
+
+impl From<Quaternion<f64>> for HmdQuaternion {
+    fn from(q: Quaternion<f64>) -> HmdQuaternion {
+        HmdQuaternion { w: q.s, x: q.v.x, y: q.v.y, z: q.v.z }
+    }
+}
+
+/** RGBA as x, y, z, w. */
+impl From<HmdColor> for Vector4<f32> {
+    fn from(color: HmdColor) -> Vector4<f32> {
+        Vector4 { x: color.r, y: color.g, z: color.b, w: color.a }
+    }
+}
+
+impl From<Vector4<f32>> for HmdColor {
+    fn from(v: Vector4<f32>) -> HmdColor {
+        HmdColor { r: v.x, g: v.y, b: v.z, a: v.w }
+    }
+}
+
+/** The top left and bottom right corners. */
+impl From<HmdRect2> for (Point2<f32>, Point2<f32>) {
+    fn from(rect: HmdRect2) -> (Point2<f32>, Point2<f32>) {
+        (rect.top_left.into(), rect.bottom_right.into())
+    }
+}
+
+impl From<(Point2<f32>, Point2<f32>)> for HmdRect2 {
+    fn from((top_left, bottom_right): (Point2<f32>, Point2<f32>)) -> HmdRect2 {
+        HmdRect2 { top_left: top_left.into(), bottom_right: bottom_right.into() }
+    }
+}
+
+/** The position and the orientation; mint has no transform type. */
+impl From<VRBoneTransform> for (Vector4<f32>, Quaternion<f32>) {
+    fn from(bone: VRBoneTransform) -> (Vector4<f32>, Quaternion<f32>) {
+        (bone.position.into(), bone.orientation.into())
+    }
+}
+
+impl From<(Vector4<f32>, Quaternion<f32>)> for VRBoneTransform {
+    fn from((position, orientation): (Vector4<f32>, Quaternion<f32>)) -> VRBoneTransform {
+        VRBoneTransform { position: position.into(), orientation: orientation.into() }
+    }
+}
+
+/** The device to absolute tracking transform, column-major. */
+impl From<TrackedDevicePose> for ColumnMatrix3x4<f32> {
+    fn from(pose: TrackedDevicePose) -> ColumnMatrix3x4<f32> {
+        ColumnMatrix3x4::from_math(pose.device_to_absolute_tracking)
+    }
+}
@@ -0,0 +1,231 @@
+use nalgebra::{
+    Isometry3, Matrix3, Matrix3x4, Matrix4, Point2, Quaternion, Translation3, UnitQuaternion, Vector2, Vector3,
+    Vector4,
+};
+
+use super::FromMath;
+use crate::vr::math::HmdMatrix34Ext;
+use crate::vr::public_vrtypes::*;
+use crate::vr::vrtypes::TrackedDevicePose;
+
+impl FromMath<HmdMatrix34> for Matrix3x4<f32> {
+    fn from_math(m: HmdMatrix34) -> Matrix3x4<f32> {
+        Matrix3x4::from_fn(|r, c| m[r][c])
+    }
+}
+
+impl FromMath<Matrix3x4<f32>> for HmdMatrix34 {
+    fn from_math(matrix: Matrix3x4<f32>) -> HmdMatrix34 {
+        std::array::from_fn(|r| std::array::from_fn(|c| matrix[(r, c)]))
+    }
+}
+
+/** With [0, 0, 0, 1] as the bottom row. */
+impl FromMath<HmdMatrix34> for Matrix4<f32> {
+    fn from_math(m: HmdMatrix34) -> Matrix4<f32> {
+        Matrix4::from_math(m.to_44())
+    }
+}
+
+/** Drops the bottom row. */
+impl FromMath<Matrix4<f32>> for HmdMatrix34 {
+    fn from_math(matrix: Matrix4<f32>) -> HmdMatrix34 {
+        HmdMatrix34::from_44(&HmdMatrix44::from_math(matrix))
+    }
+}
+
+/** The matrix has to be rigid: a rotation and a translation. */
+impl FromMath<HmdMatrix34> for Isometry3<f32> {
+    fn from_math(m: HmdMatrix34) -> Isometry3<f32> {
+        Isometry3::from_parts(Translation3::from(m.position()), m.rotation_quat().into())
+    }
+}
+
+impl FromMath<Isometry3<f32>> for HmdMatrix34 {
+    fn from_math(isometry: Isometry3<f32>) -> HmdMatrix34 {
+        HmdMatrix34::from_pos_quat(isometry.translation.vector.into(), isometry.rotation.into())
+    }
+}
+
+impl FromMath<HmdMatrix33> for Matrix3<f32> {
+    fn from_math(m: HmdMatrix33) -> Matrix3<f32> {
+        Matrix3::from_fn(|r, c| m[r][c])
+    }
+}
+
+impl FromMath<Matrix3<f32>> for HmdMatrix33 {
+    fn from_math(matrix: Matrix3<f32>) -> HmdMatrix33 {
+        std::array::from_fn(|r| std::array::from_fn(|c| matrix[(r, c)]))
+    }
+}
+
+impl FromMath<HmdMatrix44> for Matrix4<f32> {
+    fn from_math(m: HmdMatrix44) -> Matrix4<f32> {
+        Matrix4::from_fn(|r, c| m[r][c])
+    }
+}
+
+impl FromMath<Matrix4<f32>> for HmdMatrix44 {
+    fn from_math(matrix: Matrix4<f32>) -> HmdMatrix44 {
+        std::array::from_fn(|r| std::array::from_fn(|c| matrix[(r, c)]))
+    }
+}
+
+impl FromMath<HmdVector2> for Vector2<f32> {
+    fn from_math(v: HmdVector2) -> Vector2<f32> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector2<f32>> for HmdVector2 {
+    fn from_math(v: Vector2<f32>) -> HmdVector2 {
+        v.into()
+    }
+}
+
+impl FromMath<HmdVector3> for Vector3<f32> {
+    fn from_math(v: HmdVector3) -> Vector3<f32> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector3<f32>> for HmdVector3 {
+    fn from_math(v: Vector3<f32>) -> HmdVector3 {
+        v.into()
+    }
+}
+
+impl FromMath<HmdVector4> for Vector4<f32> {
+    fn from_math(v: HmdVector4) -> Vector4<f32> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector4<f32>> for HmdVector4 {
+    fn from_math(v: Vector4<f32>) -> HmdVector4 {
+        v.into()
+    }
+}
+
+impl FromMath<HmdVector3d> for Vector3<f64> {
+    fn from_math(v: HmdVector3d) -> Vector3<f64> {
+        v.into()
+    }
+}
+
+impl FromMath<Vector3<f64>> for HmdVector3d {
+    fn from_math(v: Vector3<f64>) -> HmdVector3d {
+        v.into()
+    }
+}
+
+impl FromMath<HmdQuad> for [Vector3<f32>; 4] {
+    fn from_math(quad: HmdQuad) -> [Vector3<f32>; 4] {
+        quad.map(Vector3::from)
+    }
+}
+
+impl FromMath<[Vector3<f32>; 4]> for HmdQuad {
+    fn from_math(corners: [Vector3<f32>; 4]) -> HmdQuad {
+        corners.map(Vector3::into)
+    }
+}
+
+impl From<HmdQuaternionf> for Quaternion<f32> {
+    fn from(q: HmdQuaternionf) -> Quaternion<f32> {
+        Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<Quaternion<f32>> for HmdQuaternionf {
+    fn from(q: Quaternion<f32>) -> HmdQuaternionf {
+        HmdQuaternionf { w: q.w, x: q.i, y: q.j, z: q.k }
+    }
+}
+
+/** Normalizes the quaternion. */
+impl From<HmdQuaternionf> for UnitQuaternion<f32> {
+    fn from(q: HmdQuaternionf) -> UnitQuaternion<f32> {
+        UnitQuaternion::from_quaternion(q.into())
+    }
+}
+
+impl From<UnitQuaternion<f32>> for HmdQuaternionf {
+    fn from(q: UnitQuaternion<f32>) -> HmdQuaternionf {
+        q.into_inner().into()
+    }
+}
+
+impl From<HmdQuaternion> for Quaternion<f64> {
+    fn from(q: HmdQuaternion) -> Quaternion<f64> {
+        Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<Quaternion<f64>> for HmdQuaternion {
+    fn from(q: Quaternion<f64>) -> HmdQuaternion {
+        HmdQuaternion { w: q.w, x: q.i, y: q.j, z: q.k }
+    }
+}
+
+/** Normalizes the quaternion. */
+impl From<HmdQuaternion> for UnitQuaternion<f64> {
+    fn from(q: HmdQuaternion) -> UnitQuaternion<f64> {
+        UnitQuaternion::from_quaternion(q.into())
+    }
+}
+
+impl From<UnitQuaternion<f64>> for HmdQuaternion {
+    fn from(q: UnitQuaternion<f64>) -> HmdQuaternion {
+        q.into_inner().into()
+    }
+}
+
+/** RGBA as x, y, z, w. */
+impl From<HmdColor> for Vector4<f32> {
+    fn from(color: HmdColor) -> Vector4<f32> {
+        Vector4::new(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Vector4<f32>> for HmdColor {
+    fn from(v: Vector4<f32>) -> HmdColor {
+        HmdColor { r: v.x, g: v.y, b: v.z, a: v.w }
+    }
+}
+
+/** The top left and bottom right corners. */
+impl From<HmdRect2> for (Point2<f32>, Point2<f32>) {
+    fn from(rect: HmdRect2) -> (Point2<f32>, Point2<f32>) {
+        (rect.top_left.into(), rect.bottom_right.into())
+    }
+}
+
+impl From<(Point2<f32>, Point2<f32>)> for HmdRect2 {
+    fn from((top_left, bottom_right): (Point2<f32>, Point2<f32>)) -> HmdRect2 {
+        HmdRect2 { top_left: top_left.into(), bottom_right: bottom_right.into() }
+    }
+}
+
+/** The position's w is dropped. */
+impl From<VRBoneTransform> for Isometry3<f32> {
+    fn from(bone: VRBoneTransform) -> Isometry3<f32> {
+        let [x, y, z, _] = bone.position;
+        Isometry3::from_parts(Translation3::new(x, y, z), bone.orientation.into())
+    }
+}
+
+/** The position's w is 1. */
+impl From<Isometry3<f32>> for VRBoneTransform {
+    fn from(isometry: Isometry3<f32>) -> VRBoneTransform {
+        let [x, y, z]: [f32; 3] = isometry.translation.vector.into();
+        VRBoneTransform { position: [x, y, z, 1.0], orientation: isometry.rotation.into() }
+    }
+}
+
+/** The device to absolute tracking transform. */
+impl From<TrackedDevicePose> for Isometry3<f32> {
+    fn from(pose: TrackedDevicePose) -> Isometry3<f32> {
+        Isometry3::from_math(pose.device_to_absolute_tracking)
+    }
+}
@@ -1,7 +1,8 @@
 use super::{public_vrtypes::*, vrtypes::ETrackingUniverseOrigin};
+use super::context::Interface;
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum ChaperoneCalibrationState
 {
@@ -28,14 +29,15 @@ pub enum ChaperoneCalibrationState
 * -Z is the preferred forward facing direction. */
 #[allow(non_camel_case_types)]
 #[allow(non_snake_case)]
+#[repr(C)]
 pub struct VR_IVRChaperone_FnTable
 {
     /** Get the current state of Chaperone calibration. This state can change at any time during a session due to physical base station changes. **/
-    pub GetCalibrationState: unsafe extern "stdcall" fn() -> ChaperoneCalibrationState,
+    pub GetCalibrationState: unsafe extern "system" fn() -> ChaperoneCalibrationState,
 
     /** Returns the width and depth of the Play Area (formerly named Soft Bounds) in X and Z.
 	* Tracking space center (0,0,0) is the center of the Play Area. **/
-    pub GetPlayAreaSize: unsafe extern "stdcall" fn(pSizex: *mut f32, pSizeZ: *mut f32) -> bool,
+    pub GetPlayAreaSize: unsafe extern "system" fn(pSizex: *mut f32, pSizeZ: *mut f32) -> bool,
 
     /** Returns the 4 corner positions of the Play Area (formerly named Soft Bounds).
 	* Corners are in counter-clockwise order.
@@ -43,26 +45,26 @@ pub struct VR_IVRChaperone_FnTable
 	* It's a rectangle.
 	* 2 sides are parallel to the X axis and 2 sides are parallel to the Z axis.
 	* Height of every corner is 0Y (on the floor). **/
-    pub GetPlayAreaRect: unsafe extern "stdcall" fn(rect: *mut HmdQuad) -> bool,
+    pub GetPlayAreaRect: unsafe extern "system" fn(rect: *mut HmdQuad) -> bool,
 
     /** Reload Chaperone data from the .vrchap file on disk. */
-    pub ReloadInfo: unsafe extern "stdcall" fn(),
+    pub ReloadInfo: unsafe extern "system" fn(),
 
     /** Optionally give the chaperone system a hit about the color and brightness in the scene **/
-	pub SetSceneColor: unsafe extern "stdcall" fn(color: HmdColor),
+	pub SetSceneColor: unsafe extern "system" fn(color: HmdColor),
 
 	/** Get the current chaperone bounds draw color and brightness **/
-	pub GetBoundsColor: unsafe extern "stdcall" fn(
+	pub GetBoundsColor: unsafe extern "system" fn(
         pOutputColorArray: *mut HmdColor, 
         nNumOutputColors: i32, 
         flCollisionBoundsFadeDistance: f32, 
         pOutputCameraColor: *mut HmdColor),
 
 	/** Determine whether the bounds are showing right now **/
-	pub AreBoundsVisible: unsafe extern "stdcall" fn() -> bool,
+	pub AreBoundsVisible: unsafe extern "system" fn() -> bool,
 
 	/** Force the bounds to show, mostly for utilities **/
-	pub ForceBoundsVisible: unsafe extern "stdcall" fn(bForce: bool),
+	pub ForceBoundsVisible: unsafe extern "system" fn(bForce: bool),
 
 	/** Sets the zero pose for the given tracker coordinate system to the current position and yaw of the HMD. After
 	* ResetZeroPose all GetDeviceToAbsoluteTrackingPose calls as the origin will be relative to this new zero pose.
@@ -73,7 +75,11 @@ pub struct VR_IVRChaperone_FnTable
 	* NOTE: This function overrides the user's previously saved zero pose and should only be called as the result of a user action.
 	* Users are also able to set their zero pose via the OpenVR Dashboard.
 	**/
-	pub ResetZeroPose: unsafe extern "stdcall" fn(eTrackingUniverseOrigin: ETrackingUniverseOrigin),
+	pub ResetZeroPose: unsafe extern "system" fn(eTrackingUniverseOrigin: ETrackingUniverseOrigin),
 }
 
 pub const IVRCHAPERONE_VERSION: &'static str = "IVRChaperone_004";
+
+unsafe impl Interface for VR_IVRChaperone_FnTable {
+    const VERSION: &'static str = IVRCHAPERONE_VERSION;
+}
@@ -2,9 +2,12 @@ use std::ffi::c_void;
 
 use super::public_vrtypes::*;
 use super::vrtypes::*;
+use super::context::Interface;
 
 /** Errors that can occur with the VR compositor */
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[allow(non_camel_case_types)]
+#[repr(C)]
 pub enum EVRCompositorError
 {
 	VRCompositorError_None						= 0,
@@ -23,7 +26,9 @@ pub enum EVRCompositorError
 }
 
 /** Timing mode passed to SetExplicitTimingMode(); see that function for documentation */
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[allow(non_camel_case_types)]
+#[repr(C)]
 pub enum EVRCompositorTimingMode
 {
 	VRCompositorTimingMode_Implicit											= 0,
@@ -31,7 +36,10 @@ pub enum EVRCompositorTimingMode
 	VRCompositorTimingMode_Explicit_ApplicationPerformsPostPresentHandoff	= 2,
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types, non_snake_case)]
+#[repr(C)]
 pub struct Compositor_CumulativeStats {
     pub m_nPid: u32, // Process id associated with these stats (may no longer be running).
 	pub m_nNumFramePresents: u32, // total number of times we called present (includes reprojected frames)
@@ -59,7 +67,9 @@ pub struct Compositor_CumulativeStats {
 	pub m_nNumReprojectedFramesTimedOut: u32,
 }
 
+#[derive(Clone, Copy, Debug)]
 #[allow(non_camel_case_types, non_snake_case)]
+#[repr(C)]
 pub struct Compositor_StageRenderSettings {
     /** Primary color is applied as a tint to (i.e. multiplied with) the model's texture */
 	pub m_PrimaryColor: HmdColor,
@@ -98,15 +108,70 @@ impl Compositor_StageRenderSettings {
             m_bWireframe: false,
         }
     }
+
+    /** Sets the tint multiplied with the model's texture. */
+    pub fn primary_color(mut self, color: HmdColor) -> Compositor_StageRenderSettings {
+        self.m_PrimaryColor = color;
+        self
+    }
+
+    /** Sets the solid color the vignette or fresnel effect fades to. */
+    pub fn secondary_color(mut self, color: HmdColor) -> Compositor_StageRenderSettings {
+        self.m_SecondaryColor = color;
+        self
+    }
+
+    /** Fades to the secondary color between the two distances, in meters from the playspace origin.
+    * Turns fresnel off, since the two are mutually exclusive. */
+    pub fn vignette(mut self, inner_radius: f32, outer_radius: f32) -> Compositor_StageRenderSettings {
+        self.m_flVignetteInnerRadius = inner_radius;
+        self.m_flVignetteOuterRadius = outer_radius;
+        self.m_flFresnelStrength = 0.0;
+        self
+    }
+
+    /** Fades to the secondary color by view incidence, with `strength` controlling how linear the fade is.
+    * Turns the vignette off, since the two are mutually exclusive. */
+    pub fn fresnel(mut self, strength: f32) -> Compositor_StageRenderSettings {
+        self.m_flFresnelStrength = strength;
+        self.m_flVignetteInnerRadius = 0.0;
+        self.m_flVignetteOuterRadius = 0.0;
+        self
+    }
+
+    /** Skips the back faces of the model's triangles instead of drawing both sides. */
+    pub fn backface_culling(mut self, enabled: bool) -> Compositor_StageRenderSettings {
+        self.m_bBackfaceCulling = enabled;
+        self
+    }
+
+    /** Renders the texture as luma, which hides compression artifacts on desaturated textures. */
+    pub fn greyscale(mut self, enabled: bool) -> Compositor_StageRenderSettings {
+        self.m_bGreyscale = enabled;
+        self
+    }
+
+    /** Draws the model's triangle edges only, which helps when checking its geometry. */
+    pub fn wireframe(mut self, enabled: bool) -> Compositor_StageRenderSettings {
+        self.m_bWireframe = enabled;
+        self
+    }
+}
+
+impl Default for Compositor_StageRenderSettings {
+    fn default() -> Compositor_StageRenderSettings {
+        Compositor_StageRenderSettings::new()
+    }
 }
 
 #[allow(non_camel_case_types, non_snake_case)]
+#[repr(C)]
 pub struct VR_IVRCompositor_FnTable {
     /** Sets tracking space returned by WaitGetPoses */
-    pub SetTrackingSpace: unsafe extern "stdcall" fn(eOrigin: ETrackingUniverseOrigin),
+    pub SetTrackingSpace: unsafe extern "system" fn(eOrigin: ETrackingUniverseOrigin),
 
     /** Gets current tracking space returned by WaitGetPoses */
-    pub GetTrackingSpace: unsafe extern "stdcall" fn() -> ETrackingUniverseOrigin,
+    pub GetTrackingSpace: unsafe extern "system" fn() -> ETrackingUniverseOrigin,
 
     /** Scene applications should call this function to get poses to render with (and optionally poses predicted an additional frame out to use for gameplay).
 	* This function will block until "running start" milliseconds before the start of the frame, and should be called at the last moment before needing to
@@ -116,14 +181,14 @@ pub struct VR_IVRCompositor_FnTable {
 	*	- IsNotSceneApplication (make sure to call VR_Init with VRApplicaiton_Scene)
 	*	- DoNotHaveFocus (some other app has taken focus - this will throttle the call to 10hz to reduce the impact on that app)
 	*/
-    pub WaitGetPoses: unsafe extern "stdcall" fn(
+    pub WaitGetPoses: unsafe extern "system" fn(
         pRenderPoseArray: *mut TrackedDevicePose, 
         unRenderPoseArrayCount: u32,
         pGamePoseArray: *mut TrackedDevicePose, 
         unGamePoseArrayCount: u32) -> EVRCompositorError,
 
     /** Get the last set of poses returned by WaitGetPoses. */
-    pub GetLastPoses: unsafe extern "stdcall" fn(
+    pub GetLastPoses: unsafe extern "system" fn(
         pRenderPoseArray: *mut TrackedDevicePose, 
         unRenderPoseArrayCount: u32,
         pGamePoseArray: *mut TrackedDevicePose, 
@@ -132,7 +197,7 @@ pub struct VR_IVRCompositor_FnTable {
     /** Interface for accessing last set of poses returned by WaitGetPoses one at a time.
 	* Returns VRCompositorError_IndexOutOfRange if unDeviceIndex not less than k_unMaxTrackedDeviceCount otherwise VRCompositorError_None.
 	* It is okay to pass NULL for either pose if you only want one of the values. */
-    pub GetLastPoseForTrackedDeviceIndex: unsafe extern "stdcall" fn(
+    pub GetLastPoseForTrackedDeviceIndex: unsafe extern "system" fn(
         unDeviceIndex: u32,
         pOutputPose: *mut TrackedDevicePose,
         pOutputGamePose : *mut TrackedDevicePose) -> EVRCompositorError,
@@ -152,7 +217,7 @@ pub struct VR_IVRCompositor_FnTable {
 	*	- InvalidTexture (usually means bad arguments passed in)
 	*	- AlreadySubmitted (app has submitted two left textures or two right textures in a single frame - i.e. before calling WaitGetPoses again)
 	*/
-    pub Submit: unsafe extern "stdcall" fn(
+    pub Submit: unsafe extern "system" fn(
         eEye: EVREye, 
         pTexture: *const Texture, 
         pBounds: *const VRTextureBounds,
@@ -160,127 +225,127 @@ pub struct VR_IVRCompositor_FnTable {
 
     /** Clears the frame that was sent with the last call to Submit. This will cause the
 	* compositor to show the grid until Submit is called again. */
-    pub ClearLastSubmittedFrame: unsafe extern "stdcall" fn(),
+    pub ClearLastSubmittedFrame: unsafe extern "system" fn(),
 
     /** Call immediately after presenting your app's window (i.e. companion window) to unblock the compositor.
 	* This is an optional call, which only needs to be used if you can't instead call WaitGetPoses immediately after Present.
 	* For example, if your engine's render and game loop are not on separate threads, or blocking the render thread until 3ms before the next vsync would
 	* introduce a deadlock of some sort.  This function tells the compositor that you have finished all rendering after having Submitted buffers for both
 	* eyes, and it is free to start its rendering work.  This should only be called from the same thread you are rendering on. */
-    pub PostPresentHandoff: unsafe extern "stdcall" fn(),
+    pub PostPresentHandoff: unsafe extern "system" fn(),
 
     /** Returns true if timing data is filled it.  Sets oldest timing info if nFramesAgo is larger than the stored history.
 	* Be sure to set timing.size = sizeof(Compositor_FrameTiming) on struct passed in before calling this function. */
-	pub GetFrameTiming: unsafe extern "stdcall" fn(pTiming: *mut Compositor_FrameTiming, unFramesAgo: u32) -> bool,
+	pub GetFrameTiming: unsafe extern "system" fn(pTiming: *mut Compositor_FrameTiming, unFramesAgo: u32) -> bool,
 
 	/** Interface for copying a range of timing data.  Frames are returned in ascending order (oldest to newest) with the last being the most recent frame.
 	* Only the first entry's m_nSize needs to be set, as the rest will be inferred from that.  Returns total number of entries filled out. */
-	pub GetFrameTimings: unsafe extern "stdcall" fn(pTiming: *mut Compositor_FrameTiming, nFrames: u32) -> u32,
+	pub GetFrameTimings: unsafe extern "system" fn(pTiming: *mut Compositor_FrameTiming, nFrames: u32) -> u32,
 
 	/** Returns the time in seconds left in the current (as identified by FrameTiming's frameIndex) frame.
 	* Due to "running start", this value may roll over to the next frame before ever reaching 0.0. */
-	pub GetFrameTimeRemaining: unsafe extern "stdcall" fn() -> f32,
+	pub GetFrameTimeRemaining: unsafe extern "system" fn() -> f32,
 
 	/** Fills out stats accumulated for the last connected application.  Pass in sizeof( Compositor_CumulativeStats ) as second parameter. */
-	pub GetCumulativeStats: unsafe extern "stdcall" fn(pStats: *mut Compositor_CumulativeStats, nStatsSizeInBytes: u32),
+	pub GetCumulativeStats: unsafe extern "system" fn(pStats: *mut Compositor_CumulativeStats, nStatsSizeInBytes: u32),
 
 	/** Fades the view on the HMD to the specified color. The fade will take fSeconds, and the color values are between
 	* 0.0 and 1.0. This color is faded on top of the scene based on the alpha parameter. Removing the fade color instantly
 	* would be FadeToColor( 0.0, 0.0, 0.0, 0.0, 0.0 ).  Values are in un-premultiplied alpha space. */
-	pub FadeToColor: unsafe extern "stdcall" fn(fSeconds: f32, fRed: f32, fGreen: f32, fBlue: f32, fAlpha: f32, bBackground: bool),
+	pub FadeToColor: unsafe extern "system" fn(fSeconds: f32, fRed: f32, fGreen: f32, fBlue: f32, fAlpha: f32, bBackground: bool),
 
 	/** Get current fade color value. */
-	pub GetCurrentFadeColor: unsafe extern "stdcall" fn(bBackground: bool) -> HmdColor,
+	pub GetCurrentFadeColor: unsafe extern "system" fn(bBackground: bool) -> HmdColor,
 
 	/** Fading the Grid in or out in fSeconds */
-	pub FadeGrid: unsafe extern "stdcall" fn(fSeconds: f32, bFadeGridIn: bool),
+	pub FadeGrid: unsafe extern "system" fn(fSeconds: f32, bFadeGridIn: bool),
 
 	/** Get current alpha value of grid. */
-	pub GetCurrentGridAlpha: unsafe extern "stdcall" fn() -> f32,
+	pub GetCurrentGridAlpha: unsafe extern "system" fn() -> f32,
 
 	/** Override the skybox used in the compositor (e.g. for during level loads when the app can't feed scene images fast enough)
 	* Order is Front, Back, Left, Right, Top, Bottom.  If only a single texture is passed, it is assumed in lat-long format.
 	* If two are passed, it is assumed a lat-long stereo pair. */
-	pub SetSkyboxOverride: unsafe extern "stdcall" fn(pTextures: *const Texture, unTextureCount: u32),
+	pub SetSkyboxOverride: unsafe extern "system" fn(pTextures: *const Texture, unTextureCount: u32) -> EVRCompositorError,
 
 	/** Resets compositor skybox back to defaults. */
-	pub ClearSkyboxOverride: unsafe extern "stdcall" fn(),
+	pub ClearSkyboxOverride: unsafe extern "system" fn(),
 
 	/** Brings the compositor window to the front. This is useful for covering any other window that may be on the HMD
 	* and is obscuring the compositor window. */
-	pub CompositorBringToFront: unsafe extern "stdcall" fn(),
+	pub CompositorBringToFront: unsafe extern "system" fn(),
 
 	/** Pushes the compositor window to the back. This is useful for allowing other applications to draw directly to the HMD. */
-	pub CompositorGoToBack: unsafe extern "stdcall" fn(),
+	pub CompositorGoToBack: unsafe extern "system" fn(),
 
 	/** DEPRECATED: Tells the compositor process to clean up and exit. You do not need to call this function at shutdown.
 	* Under normal circumstances the compositor will manage its own life cycle based on what applications are running. */
-	pub CompositorQuit: unsafe extern "stdcall" fn(),
+	pub CompositorQuit: unsafe extern "system" fn(),
 
 	/** Return whether the compositor is fullscreen */
-	pub IsFullscreen: unsafe extern "stdcall" fn() -> bool,
+	pub IsFullscreen: unsafe extern "system" fn() -> bool,
 
 	/** Returns the process ID of the process that is currently rendering the scene */
-	pub GetCurrentSceneFocusProcess: unsafe extern "stdcall" fn() -> u32,
+	pub GetCurrentSceneFocusProcess: unsafe extern "system" fn() -> u32,
 
 	/** Returns the process ID of the process that rendered the last frame (or 0 if the compositor itself rendered the frame.)
 	* Returns 0 when fading out from an app and the app's process Id when fading into an app. */
-	pub GetLastFrameRenderer: unsafe extern "stdcall" fn() -> u32,
+	pub GetLastFrameRenderer: unsafe extern "system" fn() -> u32,
 
 	/** Returns true if the current process has the scene focus */
-	pub CanRenderScene: unsafe extern "stdcall" fn() -> bool,
+	pub CanRenderScene: unsafe extern "system" fn() -> bool,
 
 	/** DEPRECATED: Opens the headset view (as either a window or docked widget depending on user's preferences) that displays what the user
 	* sees in the headset. */
-	pub ShowMirrorWindow: unsafe extern "stdcall" fn(),
+	pub ShowMirrorWindow: unsafe extern "system" fn(),
 
 	/** DEPRECATED: Closes the headset view, either as a window or docked widget. */
-	pub HideMirrorWindow: unsafe extern "stdcall" fn(),
+	pub HideMirrorWindow: unsafe extern "system" fn(),
 
 	/** DEPRECATED: Returns true if the headset view (either as a window or docked widget) is shown. */
-	pub IsMirrorWindowVisible: unsafe extern "stdcall" fn() -> bool,
+	pub IsMirrorWindowVisible: unsafe extern "system" fn() -> bool,
 
 	/** Writes back buffer and stereo left/right pair from the application to a 'screenshots' folder in the SteamVR runtime root. */
-	pub CompositorDumpImages: unsafe extern "stdcall" fn(),
+	pub CompositorDumpImages: unsafe extern "system" fn(),
 
 	/** Let an app know it should be rendering with low resources. */
-	pub ShouldAppRenderWithLowResources: unsafe extern "stdcall" fn() -> bool,
+	pub ShouldAppRenderWithLowResources: unsafe extern "system" fn() -> bool,
 
 	/** Override interleaved reprojection logic to force on. */
-	pub ForceInterleavedReprojectionOn: unsafe extern "stdcall" fn(bOverride: bool),
+	pub ForceInterleavedReprojectionOn: unsafe extern "system" fn(bOverride: bool),
 
 	/** Force reconnecting to the compositor process. */
-	pub ForceReconnectProcess: unsafe extern "stdcall" fn(),
+	pub ForceReconnectProcess: unsafe extern "system" fn(),
 
 	/** Temporarily suspends rendering (useful for finer control over scene transitions). */
-	pub SuspendRendering: unsafe extern "stdcall" fn(bSuspend: bool),
+	pub SuspendRendering: unsafe extern "system" fn(bSuspend: bool),
 
 	/** Opens a shared D3D11 texture with the undistorted composited image for each eye.  Use ReleaseMirrorTextureD3D11 when finished
 	* instead of calling Release on the resource itself. */
-	pub GetMirrorTextureD3D11: unsafe extern "stdcall" fn(
+	pub GetMirrorTextureD3D11: unsafe extern "system" fn(
         eEye: EVREye, 
         pD3D11DeviceOrResource: *mut c_void, 
         ppD3D11ShaderResourceView: *mut *mut c_void) -> EVRCompositorError,
-	pub ReleaseMirrorTextureD3D11: unsafe extern "stdcall" fn(pD3D11ShaderResourceView: *mut c_void),
+	pub ReleaseMirrorTextureD3D11: unsafe extern "system" fn(pD3D11ShaderResourceView: *mut c_void),
 
 	/** Access to mirror textures from OpenGL. */
-	pub GetMirrorTextureGL: unsafe extern "stdcall" fn(
+	pub GetMirrorTextureGL: unsafe extern "system" fn(
         eEye: EVREye, 
-        pglTextureId: GLUint,
+        pglTextureId: *mut GLUint,
         pglSharedTextureHandle: *mut GLSharedTextureHandle) -> EVRCompositorError,
-	pub ReleaseSharedGLTexture: unsafe extern "stdcall" fn(glTextureId: GLUint, glSharedTextureHandle: GLSharedTextureHandle) -> bool,
-	pub LockGLSharedTextureForAccess: unsafe extern "stdcall" fn(glSharedTextureHandle: GLSharedTextureHandle),
-	pub UnlockGLSharedTextureForAccess: unsafe extern "stdcall" fn(glSharedTextureHandle: GLSharedTextureHandle),
+	pub ReleaseSharedGLTexture: unsafe extern "system" fn(glTextureId: GLUint, glSharedTextureHandle: GLSharedTextureHandle) -> bool,
+	pub LockGLSharedTextureForAccess: unsafe extern "system" fn(glSharedTextureHandle: GLSharedTextureHandle),
+	pub UnlockGLSharedTextureForAccess: unsafe extern "system" fn(glSharedTextureHandle: GLSharedTextureHandle),
 
 	/** [Vulkan Only]
 	* return 0. Otherwise it returns the length of the number of bytes necessary to hold this string including the trailing
 	* null.  The string will be a space separated list of-required instance extensions to enable in VkCreateInstance */
-	pub GetVulkanInstanceExtensionsRequired: unsafe extern "stdcall" fn(pchValue: *mut i8, unBufferSize: u32) -> u32,
+	pub GetVulkanInstanceExtensionsRequired: unsafe extern "system" fn(pchValue: *mut i8, unBufferSize: u32) -> u32,
 
 	/** [Vulkan only]
 	* return 0. Otherwise it returns the length of the number of bytes necessary to hold this string including the trailing
 	* null.  The string will be a space separated list of required device extensions to enable in VkCreateDevice */
-	pub GetVulkanDeviceExtensionsRequired: unsafe extern "stdcall" fn(pPhysicalDevice: VkPhysicalDevice, pchValue: *mut i8, unBufferSize: u32) -> u32,
+	pub GetVulkanDeviceExtensionsRequired: unsafe extern "system" fn(pPhysicalDevice: VkPhysicalDevice, pchValue: *mut i8, unBufferSize: u32) -> u32,
 
 	/** [ Vulkan/D3D12 Only ]
 	* There are two purposes for SetExplicitTimingMode:
@@ -300,7 +365,7 @@ pub struct VR_IVRCompositor_FnTable {
 	* itself. If these conditions are met, then WaitGetPoses is guaranteed not to access the queue.  Note that PostPresentHandoff
 	* and SubmitExplicitTimingData will access the queue, so only WaitGetPoses becomes safe for accessing the queue from another
 	* thread. */
-	pub SetExplicitTimingMode: unsafe extern "stdcall" fn(eTimingMode: EVRCompositorTimingMode),
+	pub SetExplicitTimingMode: unsafe extern "system" fn(eTimingMode: EVRCompositorTimingMode),
 
 	/** [ Vulkan/D3D12 Only ]
 	* Submit explicit timing data.  When SetExplicitTimingMode is true, this must be called immediately before
@@ -308,46 +373,50 @@ pub struct VR_IVRCompositor_FnTable {
 	* This function will insert a GPU timestamp write just before the application starts its rendering.  This function
 	* will perform a vkQueueSubmit on Vulkan so must not be done simultaneously with VkQueue operations on another thread.
 	* Returns VRCompositorError_RequestFailed if SetExplicitTimingMode is not enabled. */
-	pub SubmitExplicitTimingData: unsafe extern "stdcall" fn() -> EVRCompositorError,
+	pub SubmitExplicitTimingData: unsafe extern "system" fn() -> EVRCompositorError,
 
 	/** Indicates whether or not motion smoothing is enabled by the user settings.
 	* If you want to know if motion smoothing actually triggered due to a late frame, check Compositor_FrameTiming
 	* m_nReprojectionFlags & VRCompositor_ReprojectionMotion instead. */
-	pub IsMotionSmoothingEnabled: unsafe extern "stdcall" fn() -> bool,
+	pub IsMotionSmoothingEnabled: unsafe extern "system" fn() -> bool,
 
 	/** Indicates whether or not motion smoothing is supported by the current hardware. */
-	pub IsMotionSmoothingSupported: unsafe extern "stdcall" fn() -> bool,
+	pub IsMotionSmoothingSupported: unsafe extern "system" fn() -> bool,
 
 	/** Indicates whether or not the current scene focus app is currently loading.  This is inferred from its use of FadeGrid to
 	* explicitly fade to the compositor to cover up the fact that it cannot render at a sustained full framerate during this time. */
-	pub IsCurrentSceneFocusAppLoading: unsafe extern "stdcall" fn() -> bool,
+	pub IsCurrentSceneFocusAppLoading: unsafe extern "system" fn() -> bool,
 
 	/** Override the stage model used in the compositor to replace the grid.  RenderModelPath is a full path the an OBJ file to load.
 	* This file will be loaded asynchronously from disk and uploaded to the gpu by the runtime.  Once ready for rendering, the
 	* VREvent StageOverrideReady will be sent.  Use FadeToGrid to reveal.  Call ClearStageOverride to free the associated resources when finished. */
-	pub SetStageOverride_Async: unsafe extern "stdcall" fn( 
+	pub SetStageOverride_Async: unsafe extern "system" fn( 
         pchRenderModelPath: *const i8, 
         pTransform: *const HmdMatrix34,
 		pRenderSettings: *const Compositor_StageRenderSettings, 
         nSizeOfRenderSettings: u32) -> EVRCompositorError,
 
 	/** Resets the stage to its default user specified setting. */
-	pub ClearStageOverride: unsafe extern "stdcall" fn(),
+	pub ClearStageOverride: unsafe extern "system" fn(),
 
 	/** Returns true if pBenchmarkResults is filled it.  Sets pBenchmarkResults with the result of the compositor benchmark.
 	* nSizeOfBenchmarkResults should be set to sizeof(Compositor_BenchmarkResults) */
-	pub GetCompositorBenchmarkResults: unsafe extern "stdcall" fn(
+	pub GetCompositorBenchmarkResults: unsafe extern "system" fn(
         pBenchmarkResults: *mut Compositor_BenchmarkResults,
         nSizeOfBenchmarkResults: u32) -> bool,
 
 	/** Returns the frame id associated with the poses last returned by WaitGetPoses.  Deltas between IDs correspond to number of headset vsync intervals. */
-	pub GetLastPosePredictionIDs: unsafe extern "stdcall" fn(pRenderPosePredictionID: *mut u32, pGamePosePredictionID: *mut u32) -> EVRCompositorError,
+	pub GetLastPosePredictionIDs: unsafe extern "system" fn(pRenderPosePredictionID: *mut u32, pGamePosePredictionID: *mut u32) -> EVRCompositorError,
 
 	/** Get the most up-to-date predicted (or recorded - up to 100ms old) set of poses for a given frame id. */
-	pub GetPosesForFrame: unsafe extern "stdcall" fn(
+	pub GetPosesForFrame: unsafe extern "system" fn(
         unPosePredictionID: u32, 
         pPoseArray: *mut TrackedDevicePose, 
         unPoseArrayCount: u32),
 }
 
 pub const IVRCOMPOSITOR_VERSION: &'static str = "IVRCompositor_027";
+
+unsafe impl Interface for VR_IVRCompositor_FnTable {
+    const VERSION: &'static str = IVRCOMPOSITOR_VERSION;
+}
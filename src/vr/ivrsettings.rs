@@ -1,5 +1,7 @@
+use super::context::Interface;
+
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVRSettingsError
 {
@@ -15,25 +17,31 @@ pub const MAX_SETTINGS_KEY_LENGTH: usize = 128;
 
 #[allow(non_camel_case_types)]
 #[allow(non_snake_case)]
+#[repr(C)]
 pub struct VR_IVRSettings_FnTable
 {
-    pub GetSettingsErrorNameFromEnum: unsafe extern "stdcall" fn(eError: EVRSettingsError) -> *const i8,
+    pub GetSettingsErrorNameFromEnum: unsafe extern "system" fn(eError: EVRSettingsError) -> *const i8,
 
-    pub SetBool: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, bValue: bool, peError: *mut EVRSettingsError),
-    pub SetInt32: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, nValue: i32, peError: *mut EVRSettingsError),
-    pub SetFloat: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, flValue: f32, peError: *mut EVRSettingsError),
-    pub SetString: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, pchValue: *const i8, peError: *mut EVRSettingsError),
+    pub SetBool: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, bValue: bool, peError: *mut EVRSettingsError),
+    pub SetInt32: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, nValue: i32, peError: *mut EVRSettingsError),
+    pub SetFloat: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, flValue: f32, peError: *mut EVRSettingsError),
+    pub SetString: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, pchValue: *const i8, peError: *mut EVRSettingsError),
 
-    pub GetBool: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> bool,
-    pub GetInt32: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> i32,
-    pub GetFloat: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> f32,
-    pub GetString: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, pchValue: *mut i8, unValueLen: usize, pError: *mut EVRSettingsError),
+    pub GetBool: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> bool,
+    pub GetInt32: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> i32,
+    pub GetFloat: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> f32,
+    pub GetString: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, pchValue: *mut i8, unValueLen: usize, pError: *mut EVRSettingsError),
 
-    pub RemoveSection: unsafe extern "stdcall" fn(pchSection: *const i8, peError: *mut EVRSettingsError),
-    pub RemoveKeyInSection: unsafe extern "stdcall" fn(pchSection: *const i8, pchSettingsKey: *const i8, peError: *mut EVRSettingsError),
+    pub RemoveSection: unsafe extern "system" fn(pchSection: *const i8, peError: *mut EVRSettingsError),
+    pub RemoveKeyInSection: unsafe extern "system" fn(pchSection: *const i8, pchSettingsKey: *const i8, peError: *mut EVRSettingsError),
 }
 
 pub const IVRSETTINGS_VERSION: &'static str = "IVRSettings_003";
+
+unsafe impl Interface for VR_IVRSettings_FnTable {
+    const VERSION: &'static str = IVRSETTINGS_VERSION;
+}
+
 //-----------------------------------------------------------------------------
 // steamvr keys
 pub const STEAMVR_SECTION: &'static str = "steamvr";
@@ -1,19 +1,22 @@
 use super::{vrtypes::*, public_vrtypes:: *};
+use super::context::Interface;
 
 pub const INVALID_SPATIAL_ANCHOR_HANDLE: SpatialAnchorHandle = 0;
 
+#[repr(C)]
 pub struct SpatialAnchorPose
 {
     pub anchor_to_absolute_tracking: HmdMatrix34,
 }
 
 #[allow(non_camel_case_types, non_snake_case)]
+#[repr(C)]
 pub struct VR_IVRSpatialAnchors_FnTable
 {
     /** Returns a handle for an spatial anchor described by "descriptor".  On success, pHandle
     * will contain a handle valid for this session.  Caller can wait for an event or occasionally
     * poll GetSpatialAnchorPose() to find the virtual coordinate associated with this anchor. */
-    pub CreateSpatialAnchorFromDescriptor: unsafe extern "stdcall" fn( 
+    pub CreateSpatialAnchorFromDescriptor: unsafe extern "system" fn( 
         pchDescriptor: *const i8, 
         pHandleOut: *mut SpatialAnchorHandle) -> EVRSpatialAnchorError,
 
@@ -30,7 +33,7 @@ pub struct VR_IVRSpatialAnchors_FnTable
     * in the future, and the quality of the anchor (when the descriptor is re-used) will be highest.
     * The caller may decide to apply offsets from this initial pose, but is advised to stay relatively close to the
     * original pose location for highest fidelity. */
-    pub CreateSpatialAnchorFromPose: unsafe extern "stdcall" fn( 
+    pub CreateSpatialAnchorFromPose: unsafe extern "system" fn( 
         unDeviceIndex: TrackedDeviceIndex, 
         eOrigin: ETrackingUniverseOrigin, 
         pPose: *mut SpatialAnchorPose, 
@@ -38,7 +41,7 @@ pub struct VR_IVRSpatialAnchors_FnTable
 
     /** Get the pose for a given handle.  This is intended to be cheap enough to call every frame (or fairly often)
     * so that the driver can refine this position when it has more information available. */
-    pub GetSpatialAnchorPose: unsafe extern "stdcall" fn( 
+    pub GetSpatialAnchorPose: unsafe extern "system" fn( 
         unHandle: SpatialAnchorHandle, 
         eOrigin: ETrackingUniverseOrigin, 
         pPoseOut: *mut SpatialAnchorPose) -> EVRSpatialAnchorError,
@@ -49,11 +52,15 @@ pub struct VR_IVRSpatialAnchors_FnTable
     * already in this session, it will be the descriptor provided by the driver.
     * Returns true if the descriptor fits into the buffer, else false.  Buffer size should be at least
     * k_unMaxSpatialAnchorDescriptorSize. */
-    pub GetSpatialAnchorDescriptor: unsafe extern "stdcall" fn(
+    pub GetSpatialAnchorDescriptor: unsafe extern "system" fn(
         unHandle: SpatialAnchorHandle, 
         pchDescriptorOut: *mut i8,
         punDescriptorBufferLenInOut: u32) -> EVRSpatialAnchorError,
 
 }
 
-pub const IVRSPATIALANCHORS_VERSION: &'static str = "IVRSpatialAnchors_001";
\ No newline at end of file
+pub const IVRSPATIALANCHORS_VERSION: &'static str = "IVRSpatialAnchors_001";
+
+unsafe impl Interface for VR_IVRSpatialAnchors_FnTable {
+    const VERSION: &'static str = IVRSPATIALANCHORS_VERSION;
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::ffi::{c_void, OsStr};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::ptr::null;
+use std::sync::OnceLock;
+
+use libloading::Library;
+
+use super::vrtypes::*;
+
+/** Errors that can occur when loading the OpenVR runtime library */
+#[derive(Debug)]
+pub enum LoadError {
+    /** The shared library could not be opened, usually because it is not installed at that path. */
+    Library(PathBuf, libloading::Error),
+    /** The library was opened but does not export this entry point. */
+    MissingSymbol(&'static str),
+    /** A runtime library has already been loaded in this process. */
+    AlreadyLoaded,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Library(path, error) => {
+                write!(f, "could not load the OpenVR runtime library {}: {}", path.display(), error)
+            }
+            LoadError::MissingSymbol(name) => {
+                write!(f, "the OpenVR runtime library does not export {}", name)
+            }
+            LoadError::AlreadyLoaded => write!(f, "an OpenVR runtime library is already loaded"),
+        }
+    }
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadError::Library(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/** Declares the VR_* exports of openvr_api. Each gets a field in EntryPoints and a forwarding function
+* with the same signature as the linked declaration, which runs `$fallback` while no library is loaded. */
+macro_rules! entry_points {
+    ($($name:ident($($arg:ident: $type:ty),*) $(-> $ret:ty)? = $fallback:block)*) => {
+        #[allow(non_snake_case)]
+        struct EntryPoints {
+            _library: Library,
+            $($name: unsafe extern "C" fn($($type),*) $(-> $ret)?,)*
+        }
+
+        impl EntryPoints {
+            #[allow(non_snake_case)]
+            unsafe fn resolve(library: Library) -> Result<EntryPoints, LoadError> {
+                $(
+                    let $name = *library
+                        .get::<unsafe extern "C" fn($($type),*) $(-> $ret)?>(concat!(stringify!($name), "\0").as_bytes())
+                        .map_err(|_| LoadError::MissingSymbol(stringify!($name)))?;
+                )*
+
+                Ok(EntryPoints { _library: library, $($name,)* })
+            }
+        }
+
+        $(
+            /** Calls the export of the same name in the loaded runtime library.
+            *
+            * # Safety
+            * Same contract as the OpenVR export. */
+            #[allow(non_snake_case, unused_variables)]
+            pub unsafe fn $name($($arg: $type),*) $(-> $ret)? {
+                match entry_points() {
+                    Some(entry_points) => (entry_points.$name)($($arg),*),
+                    None => $fallback,
+                }
+            }
+        )*
+    };
+}
+
+entry_points! {
+    VR_IsHmdPresent() -> bool = { false }
+    VR_IsRuntimeInstalled() -> bool = { false }
+    VR_GetRuntimePath(pchPathBuffer: *mut i8, unBufferSize: u32, punRequiredBufferSize: *mut u32) -> bool = { false }
+    VR_GetVRInitErrorAsSymbol(error: EVRInitError) -> *const i8 = { null() }
+    VR_GetVRInitErrorAsEnglishDescription(error: EVRInitError) -> *const i8 = { null() }
+    VR_GetGenericInterface(pchInterfaceVersion: *const i8, error: *mut EVRInitError) -> *const c_void = {
+        if !error.is_null() {
+            *error = EVRInitError::VRInitError_Init_NotInitialized;
+        }
+        null()
+    }
+    VR_IsInterfaceVersionValid(pchInterfaceVersion: *const i8) -> bool = { false }
+    VR_GetInitToken() -> u32 = { 0 }
+    VR_InitInternal2(peError: *mut EVRInitError, eApplicationType: EVRApplicationType, pStartupInfo: *const i8) -> u32 = {
+        if !peError.is_null() {
+            *peError = EVRInitError::VRInitError_Init_InstallationNotFound;
+        }
+        0
+    }
+    VR_ShutdownInternal() = {}
+}
+
+static ENTRY_POINTS: OnceLock<EntryPoints> = OnceLock::new();
+
+/** The loaded entry points. Nothing is loaded implicitly, so a call made before load cannot pick up a
+* library from another path than the one the app asks for. */
+fn entry_points() -> Option<&'static EntryPoints> {
+    ENTRY_POINTS.get()
+}
+
+/** Loads the OpenVR runtime library from `path` and resolves its VR_* entry points.
+* The library stays loaded for the rest of the process, since interface tables point into it. */
+pub fn load<P: AsRef<OsStr>>(path: P) -> Result<(), LoadError> {
+    if ENTRY_POINTS.get().is_some() {
+        return Err(LoadError::AlreadyLoaded);
+    }
+
+    let path = path.as_ref();
+    let library = unsafe { Library::new(path) }.map_err(|error| LoadError::Library(Path::new(path).to_path_buf(), error))?;
+    let entry_points = unsafe { EntryPoints::resolve(library) }?;
+
+    ENTRY_POINTS.set(entry_points).map_err(|_| LoadError::AlreadyLoaded)
+}
+
+/** Loads the runtime library by its platform file name (libopenvr_api.so, openvr_api.dll or libopenvr_api.dylib)
+* through the system's library search path. Context::new calls this if nothing is loaded yet. */
+pub fn load_default() -> Result<(), LoadError> {
+    load(libloading::library_filename("openvr_api"))
+}
+
+/** Loads the default library unless one is already loaded, by this call or a concurrent one. */
+#[cfg(not(feature = "mock"))]
+pub(crate) fn ensure_loaded() -> Result<(), LoadError> {
+    match load_default() {
+        Err(LoadError::AlreadyLoaded) => Ok(()),
+        result => result,
+    }
+}
+
+/** True once a runtime library has been loaded. */
+pub fn is_loaded() -> bool {
+    ENTRY_POINTS.get().is_some()
+}
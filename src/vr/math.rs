@@ -0,0 +1,233 @@
+use super::public_vrtypes::*;
+
+/** Operations on the 3x4 row-major matrices OpenVR uses for poses. The left 3x3 block is the rotation
+* and the last column the translation, so a point p maps to R p + t. The matrices are arrays, so the
+* operations come from this trait, which has to be in scope. */
+pub trait HmdMatrix34Ext: Sized {
+    fn identity() -> Self;
+
+    /** A rigid transform from a position and a rotation. The rotation does not need to be normalized. */
+    fn from_pos_quat(position: HmdVector3, rotation: HmdQuaternionf) -> Self;
+
+    /** The affine part of a 4x4 matrix; the bottom row is dropped. */
+    fn from_44(matrix: &HmdMatrix44) -> Self;
+
+    /** The matrix with [0, 0, 0, 1] as the bottom row. */
+    fn to_44(&self) -> HmdMatrix44;
+
+    /** The translation column. */
+    fn position(&self) -> HmdVector3;
+
+    /** The rotation block as a unit quaternion. The block has to be a rotation, without scale. */
+    fn rotation_quat(&self) -> HmdQuaternionf;
+
+    /** The inverse of a rotation plus translation, which is cheaper and more stable than a general
+    * inverse. Matrices with scale or shear do not invert correctly. */
+    fn inverse_rigid(&self) -> Self;
+
+    /** self * other: the transform that applies other first, then self. */
+    fn mul(&self, other: &Self) -> Self;
+
+    /** Rotates and translates a point. */
+    fn transform_point(&self, point: HmdVector3) -> HmdVector3;
+
+    /** Rotates a direction, ignoring the translation. */
+    fn transform_vector(&self, vector: HmdVector3) -> HmdVector3;
+}
+
+impl HmdMatrix34Ext for HmdMatrix34 {
+    fn identity() -> HmdMatrix34 {
+        [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]]
+    }
+
+    fn from_pos_quat(position: HmdVector3, rotation: HmdQuaternionf) -> HmdMatrix34 {
+        let HmdQuaternionf { w, x, y, z } = rotation.normalize();
+        [
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y), position[0]],
+            [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x), position[1]],
+            [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y), position[2]],
+        ]
+    }
+
+    fn from_44(matrix: &HmdMatrix44) -> HmdMatrix34 {
+        [matrix[0], matrix[1], matrix[2]]
+    }
+
+    fn to_44(&self) -> HmdMatrix44 {
+        [self[0], self[1], self[2], [0.0, 0.0, 0.0, 1.0]]
+    }
+
+    fn position(&self) -> HmdVector3 {
+        [self[0][3], self[1][3], self[2][3]]
+    }
+
+    fn rotation_quat(&self) -> HmdQuaternionf {
+        let m = self;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        // Picks the largest of w, x, y and z to divide by, so the result stays accurate near 180 degrees.
+        let rotation = if trace > 0.0 {
+            let s = 0.5 / (trace + 1.0).sqrt();
+            HmdQuaternionf {
+                w: 0.25 / s,
+                x: (m[2][1] - m[1][2]) * s,
+                y: (m[0][2] - m[2][0]) * s,
+                z: (m[1][0] - m[0][1]) * s,
+            }
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = 2.0 * (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt();
+            HmdQuaternionf {
+                w: (m[2][1] - m[1][2]) / s,
+                x: 0.25 * s,
+                y: (m[0][1] + m[1][0]) / s,
+                z: (m[0][2] + m[2][0]) / s,
+            }
+        } else if m[1][1] > m[2][2] {
+            let s = 2.0 * (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt();
+            HmdQuaternionf {
+                w: (m[0][2] - m[2][0]) / s,
+                x: (m[0][1] + m[1][0]) / s,
+                y: 0.25 * s,
+                z: (m[1][2] + m[2][1]) / s,
+            }
+        } else {
+            let s = 2.0 * (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt();
+            HmdQuaternionf {
+                w: (m[1][0] - m[0][1]) / s,
+                x: (m[0][2] + m[2][0]) / s,
+                y: (m[1][2] + m[2][1]) / s,
+                z: 0.25 * s,
+            }
+        };
+        rotation.normalize()
+    }
+
+    fn inverse_rigid(&self) -> HmdMatrix34 {
+        let m = self;
+        let t = self.position();
+        let mut inverse = [[0.0; 4]; 3];
+        for row in 0..3 {
+            for column in 0..3 {
+                inverse[row][column] = m[column][row];
+            }
+            inverse[row][3] = -(m[0][row] * t[0] + m[1][row] * t[1] + m[2][row] * t[2]);
+        }
+        inverse
+    }
+
+    fn mul(&self, other: &HmdMatrix34) -> HmdMatrix34 {
+        let mut product = [[0.0; 4]; 3];
+        for row in 0..3 {
+            for column in 0..4 {
+                product[row][column] = (0..3).map(|k| self[row][k] * other[k][column]).sum();
+            }
+            product[row][3] += self[row][3];
+        }
+        product
+    }
+
+    fn transform_point(&self, point: HmdVector3) -> HmdVector3 {
+        let [x, y, z] = self.transform_vector(point);
+        [x + self[0][3], y + self[1][3], z + self[2][3]]
+    }
+
+    fn transform_vector(&self, vector: HmdVector3) -> HmdVector3 {
+        let row = |r: usize| self[r][0] * vector[0] + self[r][1] * vector[1] + self[r][2] * vector[2];
+        [row(0), row(1), row(2)]
+    }
+}
+
+impl HmdQuaternionf {
+    pub const IDENTITY: HmdQuaternionf = HmdQuaternionf { w: 1.0, x: 0.0, y: 0.0, z: 0.0 };
+
+    /** A rotation of `angle` radians about `axis`, which does not need to be normalized. */
+    pub fn from_axis_angle(axis: HmdVector3, angle: f32) -> HmdQuaternionf {
+        let length = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        if length == 0.0 {
+            return HmdQuaternionf::IDENTITY;
+        }
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let scale = sin / length;
+        HmdQuaternionf { w: cos, x: axis[0] * scale, y: axis[1] * scale, z: axis[2] * scale }
+    }
+
+    pub fn dot(&self, other: &HmdQuaternionf) -> f32 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn length(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /** The quaternion scaled to unit length. A zero quaternion becomes the identity. */
+    pub fn normalize(&self) -> HmdQuaternionf {
+        let length = self.length();
+        if length == 0.0 {
+            return HmdQuaternionf::IDENTITY;
+        }
+        HmdQuaternionf { w: self.w / length, x: self.x / length, y: self.y / length, z: self.z / length }
+    }
+
+    /** The inverse rotation, for a unit quaternion. */
+    pub fn conjugate(&self) -> HmdQuaternionf {
+        HmdQuaternionf { w: self.w, x: -self.x, y: -self.y, z: -self.z }
+    }
+
+    /** self * other: the rotation that applies other first, then self. */
+    pub fn mul(&self, other: &HmdQuaternionf) -> HmdQuaternionf {
+        let (a, b) = (self, other);
+        HmdQuaternionf {
+            w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        }
+    }
+
+    /** Rotates a vector by a unit quaternion. */
+    pub fn rotate(&self, vector: HmdVector3) -> HmdVector3 {
+        let v = HmdQuaternionf { w: 0.0, x: vector[0], y: vector[1], z: vector[2] };
+        let rotated = self.mul(&v).mul(&self.conjugate());
+        [rotated.x, rotated.y, rotated.z]
+    }
+
+    /** Spherical interpolation from self (t = 0) to other (t = 1), along the shorter arc. Both should be
+    * unit quaternions; the result is one. */
+    pub fn slerp(&self, other: &HmdQuaternionf, t: f32) -> HmdQuaternionf {
+        let mut cos = self.dot(other);
+        let mut other = *other;
+        if cos < 0.0 {
+            cos = -cos;
+            other = HmdQuaternionf { w: -other.w, x: -other.x, y: -other.y, z: -other.z };
+        }
+
+        // Nearly parallel rotations divide by a sine close to 0; a linear blend is accurate there.
+        let (from, to) = if cos > 0.9995 {
+            (1.0 - t, t)
+        } else {
+            let angle = cos.acos();
+            let sin = angle.sin();
+            (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin)
+        };
+
+        HmdQuaternionf {
+            w: self.w * from + other.w * to,
+            x: self.x * from + other.x * to,
+            y: self.y * from + other.y * to,
+            z: self.z * from + other.z * to,
+        }
+        .normalize()
+    }
+}
+
+impl From<HmdQuaternion> for HmdQuaternionf {
+    fn from(rotation: HmdQuaternion) -> HmdQuaternionf {
+        HmdQuaternionf { w: rotation.w as f32, x: rotation.x as f32, y: rotation.y as f32, z: rotation.z as f32 }
+    }
+}
+
+impl From<HmdQuaternionf> for HmdQuaternion {
+    fn from(rotation: HmdQuaternionf) -> HmdQuaternion {
+        HmdQuaternion { w: rotation.w as f64, x: rotation.x as f64, y: rotation.y as f64, z: rotation.z as f64 }
+    }
+}
@@ -0,0 +1,108 @@
+use std::ffi::c_void;
+use std::ptr::null_mut;
+
+use super::compositor::Compositor;
+use super::ivrcompositor::*;
+use super::vrtypes::*;
+
+/** The compositor's undistorted image for one eye, opened as an OpenGL texture. The shared texture is
+* released when this is dropped, so it cannot be leaked in the compositor. */
+pub struct MirrorTexture<'a> {
+    compositor: Compositor<'a>,
+    eye: EVREye,
+    texture_id: GLUint,
+    handle: GLSharedTextureHandle,
+}
+
+impl<'a> MirrorTexture<'a> {
+    pub(crate) unsafe fn new(compositor: Compositor<'a>, eye: EVREye) -> Result<MirrorTexture<'a>, EVRCompositorError> {
+        let mut texture_id = 0;
+        let mut handle = null_mut();
+        match (compositor.fn_table().GetMirrorTextureGL)(eye, &mut texture_id, &mut handle) {
+            EVRCompositorError::VRCompositorError_None => Ok(MirrorTexture { compositor, eye, texture_id, handle }),
+            error => Err(error),
+        }
+    }
+
+    pub fn eye(&self) -> EVREye {
+        self.eye
+    }
+
+    /** The texture's name in the current OpenGL context. Only read it while the texture is locked. */
+    pub fn texture_id(&self) -> GLUint {
+        self.texture_id
+    }
+
+    /** The compositor's shared handle for the texture. */
+    pub fn shared_handle(&self) -> GLSharedTextureHandle {
+        self.handle
+    }
+
+    /** Locks the texture so the compositor does not write to it while the app reads it. It is unlocked
+    * when the returned guard is dropped. */
+    pub fn lock(&mut self) -> MirrorTextureLock<'_, 'a> {
+        unsafe { (self.compositor.fn_table().LockGLSharedTextureForAccess)(self.handle) };
+        MirrorTextureLock { texture: self }
+    }
+}
+
+impl Drop for MirrorTexture<'_> {
+    fn drop(&mut self) {
+        unsafe { (self.compositor.fn_table().ReleaseSharedGLTexture)(self.texture_id, self.handle) };
+    }
+}
+
+/** A locked MirrorTexture, readable until this is dropped. */
+pub struct MirrorTextureLock<'t, 'a> {
+    texture: &'t MirrorTexture<'a>,
+}
+
+impl MirrorTextureLock<'_, '_> {
+    /** The texture's name in the current OpenGL context. */
+    pub fn texture_id(&self) -> GLUint {
+        self.texture.texture_id
+    }
+}
+
+impl Drop for MirrorTextureLock<'_, '_> {
+    fn drop(&mut self) {
+        unsafe { (self.texture.compositor.fn_table().UnlockGLSharedTextureForAccess)(self.texture.handle) };
+    }
+}
+
+/** The compositor's undistorted image for one eye, opened as a D3D11 shader resource view. The view is
+* released through ReleaseMirrorTextureD3D11 when this is dropped; do not call Release on it. */
+pub struct D3D11MirrorTexture<'a> {
+    compositor: Compositor<'a>,
+    eye: EVREye,
+    view: *mut c_void,
+}
+
+impl<'a> D3D11MirrorTexture<'a> {
+    pub(crate) unsafe fn new(
+        compositor: Compositor<'a>,
+        eye: EVREye,
+        device: *mut c_void,
+    ) -> Result<D3D11MirrorTexture<'a>, EVRCompositorError> {
+        let mut view = null_mut();
+        match (compositor.fn_table().GetMirrorTextureD3D11)(eye, device, &mut view) {
+            EVRCompositorError::VRCompositorError_None => Ok(D3D11MirrorTexture { compositor, eye, view }),
+            error => Err(error),
+        }
+    }
+
+    pub fn eye(&self) -> EVREye {
+        self.eye
+    }
+
+    /** The ID3D11ShaderResourceView of the texture. */
+    pub fn shader_resource_view(&self) -> *mut c_void {
+        self.view
+    }
+}
+
+impl Drop for D3D11MirrorTexture<'_> {
+    fn drop(&mut self) {
+        unsafe { (self.compositor.fn_table().ReleaseMirrorTextureD3D11)(self.view) };
+    }
+}
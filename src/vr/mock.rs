@@ -0,0 +1,680 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::ffi::{c_void, CStr, CString};
+use std::fmt::Debug;
+use std::ptr::{copy_nonoverlapping, null};
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+use super::ivrapplications::*;
+use super::ivrchaperone::*;
+use super::ivrcompositor::*;
+use super::ivrsettings::*;
+use super::ivrsystem::*;
+use super::property::PropertyArrayElement;
+use super::public_vrtypes::*;
+use super::system::Fov;
+use super::util::string_from_ptr;
+use super::vrtypes::*;
+
+// The function table implementations keep the parameter names of the declarations they fill in, and their
+// signatures, which return the matrix arrays by value like the IVRSystem table does.
+#[allow(non_snake_case)]
+mod applications;
+#[allow(non_snake_case)]
+mod chaperone;
+#[allow(non_snake_case)]
+mod compositor;
+#[allow(non_snake_case)]
+mod settings;
+#[allow(non_snake_case, improper_ctypes_definitions)]
+mod system;
+
+/** A value stored for a tracked device property. Arrays keep the tag the runtime reports with them. */
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockProperty {
+    Bool(bool),
+    Float(f32),
+    Int32(i32),
+    Uint64(u64),
+    Matrix34(HmdMatrix34),
+    String(String),
+    Array(PropertyTypeTag, Vec<u8>),
+}
+
+impl MockProperty {
+    /** An array property holding `values`, tagged with the element type's PropertyTypeTag. */
+    pub fn array<T: PropertyArrayElement>(values: &[T]) -> MockProperty {
+        let size = std::mem::size_of_val(values);
+        let mut bytes = vec![0u8; size];
+        unsafe { copy_nonoverlapping(values.as_ptr() as *const u8, bytes.as_mut_ptr(), size) };
+
+        MockProperty::Array(T::TAG, bytes)
+    }
+}
+
+impl From<bool> for MockProperty {
+    fn from(value: bool) -> MockProperty {
+        MockProperty::Bool(value)
+    }
+}
+
+impl From<f32> for MockProperty {
+    fn from(value: f32) -> MockProperty {
+        MockProperty::Float(value)
+    }
+}
+
+impl From<i32> for MockProperty {
+    fn from(value: i32) -> MockProperty {
+        MockProperty::Int32(value)
+    }
+}
+
+impl From<u64> for MockProperty {
+    fn from(value: u64) -> MockProperty {
+        MockProperty::Uint64(value)
+    }
+}
+
+impl From<HmdMatrix34> for MockProperty {
+    fn from(value: HmdMatrix34) -> MockProperty {
+        MockProperty::Matrix34(value)
+    }
+}
+
+impl From<&str> for MockProperty {
+    fn from(value: &str) -> MockProperty {
+        MockProperty::String(value.to_string())
+    }
+}
+
+impl From<String> for MockProperty {
+    fn from(value: String) -> MockProperty {
+        MockProperty::String(value)
+    }
+}
+
+/** A tracked device known to the mock runtime. Its index is its position in MockState::devices. */
+#[derive(Clone, Debug)]
+pub struct MockDevice {
+    pub class: ETrackedDeviceClass,
+    pub role: ETrackedControllerRole,
+    pub activity_level: EDeviceActivityLevel,
+    pub pose: TrackedDevicePose,
+    pub controller_state: VRControllerState001,
+    /** Keyed by the ETrackedDeviceProperty value */
+    pub properties: BTreeMap<u32, MockProperty>,
+}
+
+impl MockDevice {
+    /** A connected device of `class` at the tracking origin, with no role and no properties. */
+    pub fn new(class: ETrackedDeviceClass) -> MockDevice {
+        MockDevice {
+            class,
+            role: ETrackedControllerRole::TrackedControllerRole_Invalid,
+            activity_level: EDeviceActivityLevel::k_EDeviceActivityLevel_UserInteraction,
+            pose: identity_pose(),
+            controller_state: VRControllerState001 {
+                packet_num: 0,
+                button_pressed: 0,
+                button_touched: 0,
+                axis: [VRControllerAxis { x: 0.0, y: 0.0 }; k_unControllerStateAxisCount],
+            },
+            properties: BTreeMap::new(),
+        }
+    }
+}
+
+/** A value stored by IVRSettings */
+#[derive(Clone, Debug, PartialEq)]
+pub enum SettingValue {
+    Bool(bool),
+    Int32(i32),
+    Float(f32),
+    String(String),
+}
+
+/** An application registered with the mock IVRApplications. */
+#[derive(Clone, Debug, Default)]
+pub struct MockApplication {
+    pub key: String,
+    pub name: String,
+    pub binary_path: String,
+    pub arguments: String,
+    pub is_template: bool,
+    pub is_dashboard_overlay: bool,
+    pub auto_launch: bool,
+    pub mime_types: Vec<String>,
+    /** 0 while the application is not running */
+    pub process_id: u32,
+}
+
+impl MockApplication {
+    pub fn new(key: &str, name: &str) -> MockApplication {
+        MockApplication { key: key.to_string(), name: name.to_string(), ..Default::default() }
+    }
+}
+
+/** One IVRCompositor::Submit call as seen by the mock. */
+#[derive(Clone, Copy, Debug)]
+pub struct SubmittedFrame {
+    /** Value of MockState::frame_index when the texture was submitted */
+    pub frame_index: u32,
+    pub eye: EVREye,
+    pub handle: *mut c_void,
+    pub texture_type: ETextureType,
+    pub color_space: EColorSpace,
+    pub bounds: Option<[f32; 4]>,
+    pub flags: EVRSubmitFlags,
+}
+
+/** A stage override set through IVRCompositor::SetStageOverride_Async. */
+#[derive(Clone, Debug)]
+pub struct StageOverride {
+    pub render_model_path: String,
+    pub transform: HmdMatrix34,
+    pub settings: Compositor_StageRenderSettings,
+}
+
+/** Everything the mock runtime reports or records. All fields can be changed between calls to script a test. */
+pub struct MockState {
+    /** Returned by VR_InitInternal2 instead of initializing, if set */
+    pub init_error: Option<EVRInitError>,
+    pub initialized: bool,
+    pub init_token: u32,
+    pub application_type: Option<EVRApplicationType>,
+
+    // IVRSystem
+    pub devices: Vec<MockDevice>,
+    pub events: VecDeque<VREvent>,
+    pub render_target_size: (u32, u32),
+    pub projection_raw: [Fov; 2],
+    pub eye_to_head: [HmdMatrix34; 2],
+    pub seated_zero_pose: HmdMatrix34,
+    pub raw_zero_pose: HmdMatrix34,
+    pub display_on_desktop: bool,
+    /** Returned by GetOutputDevice for every texture type, 0 meaning none */
+    pub output_device: u64,
+    pub input_available: bool,
+    pub steamvr_drawing_controllers: bool,
+    pub should_pause: bool,
+    pub should_reduce_rendering_work: bool,
+    pub quit_acknowledged: bool,
+    pub app_container_file_paths: Vec<String>,
+    pub runtime_version: CString,
+    /** (device, axis, duration in microseconds) for every TriggerHapticPulse call */
+    pub haptic_pulses: Vec<(TrackedDeviceIndex, u32, u16)>,
+
+    // IVRCompositor
+    pub tracking_space: ETrackingUniverseOrigin,
+    pub frame_index: u32,
+    pub submitted_frames: Vec<SubmittedFrame>,
+    /** Returned by Submit instead of recording the frame, if set */
+    pub submit_error: Option<EVRCompositorError>,
+    /** Oldest first; GetFrameTiming(0) reads the last entry */
+    pub frame_timings: Vec<Compositor_FrameTiming>,
+    pub frame_time_remaining: f32,
+    pub fade_color: HmdColor,
+    pub background_fade_color: HmdColor,
+    pub grid_alpha: f32,
+    /** The durations passed to the last FadeToColor and FadeGrid */
+    pub fade_seconds: f32,
+    pub grid_fade_seconds: f32,
+    /** (texture type, handle) of each face of the current skybox override */
+    pub skybox_override: Option<Vec<(ETextureType, *mut c_void)>>,
+    pub fullscreen: bool,
+    pub mirror_window_visible: bool,
+    pub interleaved_reprojection_forced: bool,
+    pub rendering_suspended: bool,
+    pub low_resources: bool,
+    /** Mirror textures handed out and not yet released */
+    pub mirror_textures: Vec<*mut c_void>,
+    pub gl_shared_texture_locked: bool,
+    pub vulkan_instance_extensions: String,
+    pub vulkan_device_extensions: String,
+    pub timing_mode: EVRCompositorTimingMode,
+    pub explicit_timing_submissions: u32,
+    pub post_present_handoffs: u32,
+    pub motion_smoothing_enabled: bool,
+    pub motion_smoothing_supported: bool,
+    pub stage_override: Option<StageOverride>,
+    pub benchmark_results: Compositor_BenchmarkResults,
+    /** Returned by GetCumulativeStats, with m_nPid set to the scene application's process id */
+    pub cumulative_stats: Compositor_CumulativeStats,
+
+    // IVRChaperone
+    pub calibration_state: ChaperoneCalibrationState,
+    pub play_area_size: (f32, f32),
+    pub play_area_rect: HmdQuad,
+    pub scene_color: HmdColor,
+    pub bounds_visible: bool,
+    pub bounds_forced_visible: bool,
+    pub zero_pose_resets: Vec<ETrackingUniverseOrigin>,
+
+    // IVRSettings
+    /** Keyed by (section, key) */
+    pub settings: BTreeMap<(String, String), SettingValue>,
+
+    // IVRApplications
+    pub applications: Vec<MockApplication>,
+    pub manifests: Vec<String>,
+    pub default_applications: BTreeMap<String, String>,
+    pub launch_arguments: BTreeMap<u32, String>,
+    pub starting_application: Option<String>,
+    pub scene_application_state: EVRSceneApplicationState,
+    pub scene_process_id: u32,
+    pub internal_processes: Vec<String>,
+}
+
+/* The raw pointers in MockState are handles the mock records and hands back, never dereferences. */
+unsafe impl Send for MockState {}
+
+impl Default for MockState {
+    /** A calibrated room with a single HMD at device index 0 and nothing else. */
+    fn default() -> MockState {
+        let mut hmd = MockDevice::new(ETrackedDeviceClass::TrackedDeviceClass_HMD);
+        hmd.role = ETrackedControllerRole::TrackedControllerRole_Invalid;
+        for (prop, value) in [
+            (ETrackedDeviceProperty::Prop_TrackingSystemName_String, MockProperty::from("mock")),
+            (ETrackedDeviceProperty::Prop_ModelNumber_String, MockProperty::from("Mock HMD")),
+            (ETrackedDeviceProperty::Prop_SerialNumber_String, MockProperty::from("MOCK-HMD-0")),
+            (ETrackedDeviceProperty::Prop_DisplayFrequency_Float, MockProperty::from(90.0f32)),
+            (ETrackedDeviceProperty::Prop_UserIpdMeters_Float, MockProperty::from(0.064f32)),
+        ] {
+            hmd.properties.insert(prop as u32, value);
+        }
+
+        let fov = Fov { left: -1.0, right: 1.0, top: -1.0, bottom: 1.0 };
+
+        MockState {
+            init_error: None,
+            initialized: false,
+            init_token: 0,
+            application_type: None,
+
+            devices: vec![hmd],
+            events: VecDeque::new(),
+            render_target_size: (1024, 1024),
+            projection_raw: [fov, fov],
+            eye_to_head: [translation(-0.032, 0.0, 0.0), translation(0.032, 0.0, 0.0)],
+            seated_zero_pose: IDENTITY,
+            raw_zero_pose: IDENTITY,
+            display_on_desktop: false,
+            output_device: 0,
+            input_available: true,
+            steamvr_drawing_controllers: false,
+            should_pause: false,
+            should_reduce_rendering_work: false,
+            quit_acknowledged: false,
+            app_container_file_paths: Vec::new(),
+            runtime_version: CString::new("mock").unwrap(),
+            haptic_pulses: Vec::new(),
+
+            tracking_space: ETrackingUniverseOrigin::TrackingUniverseStanding,
+            frame_index: 0,
+            submitted_frames: Vec::new(),
+            submit_error: None,
+            frame_timings: Vec::new(),
+            frame_time_remaining: 0.011,
+            fade_color: HmdColor { r: 0.0, g: 0.0, b: 0.0, a: 0.0 },
+            background_fade_color: HmdColor { r: 0.0, g: 0.0, b: 0.0, a: 0.0 },
+            grid_alpha: 0.0,
+            fade_seconds: 0.0,
+            grid_fade_seconds: 0.0,
+            skybox_override: None,
+            fullscreen: true,
+            mirror_window_visible: false,
+            interleaved_reprojection_forced: false,
+            rendering_suspended: false,
+            low_resources: false,
+            mirror_textures: Vec::new(),
+            gl_shared_texture_locked: false,
+            vulkan_instance_extensions: "VK_KHR_external_memory_capabilities VK_KHR_get_physical_device_properties2"
+                .to_string(),
+            vulkan_device_extensions: "VK_KHR_external_memory VK_KHR_external_memory_fd".to_string(),
+            timing_mode: EVRCompositorTimingMode::VRCompositorTimingMode_Implicit,
+            explicit_timing_submissions: 0,
+            post_present_handoffs: 0,
+            motion_smoothing_enabled: false,
+            motion_smoothing_supported: true,
+            stage_override: None,
+            benchmark_results: Compositor_BenchmarkResults {
+                mega_pixels_per_second: 0.0,
+                hmd_recommended_mega_pixels_per_second: 0.0,
+            },
+            cumulative_stats: Compositor_CumulativeStats::default(),
+
+            calibration_state: ChaperoneCalibrationState::ChaperoneCalibrationState_OK,
+            play_area_size: (2.0, 2.0),
+            play_area_rect: [[-1.0, 0.0, -1.0], [1.0, 0.0, -1.0], [1.0, 0.0, 1.0], [-1.0, 0.0, 1.0]],
+            scene_color: HmdColor { r: 0.0, g: 0.0, b: 0.0, a: 1.0 },
+            bounds_visible: false,
+            bounds_forced_visible: false,
+            zero_pose_resets: Vec::new(),
+
+            settings: BTreeMap::new(),
+
+            applications: Vec::new(),
+            manifests: Vec::new(),
+            default_applications: BTreeMap::new(),
+            launch_arguments: BTreeMap::new(),
+            starting_application: None,
+            scene_application_state: EVRSceneApplicationState::EVRSceneApplicationState_None,
+            scene_process_id: 0,
+            internal_processes: Vec::new(),
+        }
+    }
+}
+
+impl MockState {
+    fn device(&self, index: TrackedDeviceIndex) -> Option<&MockDevice> {
+        self.devices.get(index as usize)
+    }
+
+    fn device_mut(&mut self, index: TrackedDeviceIndex) -> Option<&mut MockDevice> {
+        self.devices.get_mut(index as usize)
+    }
+
+    /** Pose of a device as seen from `origin`; slots without a device report a disconnected, invalid pose. */
+    fn pose(&self, index: TrackedDeviceIndex, origin: ETrackingUniverseOrigin) -> TrackedDevicePose {
+        let Some(device) = self.device(index) else {
+            return TrackedDevicePose { pose_is_valid: false, device_is_connected: false, ..identity_pose() };
+        };
+
+        let mut pose = device.pose;
+        if origin == ETrackingUniverseOrigin::TrackingUniverseSeated {
+            pose.device_to_absolute_tracking = multiply(&invert(&self.seated_zero_pose), &pose.device_to_absolute_tracking);
+        }
+        pose
+    }
+
+    fn fill_poses(&self, origin: ETrackingUniverseOrigin, poses: *mut TrackedDevicePose, count: u32) {
+        if poses.is_null() {
+            return;
+        }
+
+        for index in 0..count.min(k_unMaxTrackedDeviceCount) {
+            unsafe { *poses.add(index as usize) = self.pose(index, origin) };
+        }
+    }
+}
+
+static STATE: OnceLock<Mutex<MockState>> = OnceLock::new();
+
+/** Held by MockRuntime so tests that script the mock run one at a time. */
+static EXCLUSIVE: Mutex<()> = Mutex::new(());
+
+fn state() -> MutexGuard<'static, MockState> {
+    STATE
+        .get_or_init(|| Mutex::new(MockState::default()))
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/** Exclusive access to the mock runtime for one test.
+* Creating a MockRuntime waits for any other one to be dropped, then resets the mock to MockState::default().
+* Contexts created while it is alive talk to the mock through the normal VR_* entry points. */
+pub struct MockRuntime {
+    _exclusive: MutexGuard<'static, ()>,
+}
+
+impl MockRuntime {
+    pub fn new() -> MockRuntime {
+        let exclusive = EXCLUSIVE.lock().unwrap_or_else(PoisonError::into_inner);
+        *state() = MockState::default();
+
+        MockRuntime { _exclusive: exclusive }
+    }
+
+    /** Direct access to everything the mock reports and records. Do not hold it across calls into the API. */
+    pub fn state(&self) -> MutexGuard<'static, MockState> {
+        state()
+    }
+
+    /** Adds a connected device of `class` and returns its index. */
+    pub fn add_device(&self, class: ETrackedDeviceClass) -> TrackedDeviceIndex {
+        let mut state = state();
+        state.devices.push(MockDevice::new(class));
+        (state.devices.len() - 1) as TrackedDeviceIndex
+    }
+
+    /** Adds a connected controller in `role` and returns its index. */
+    pub fn add_controller(&self, role: ETrackedControllerRole) -> TrackedDeviceIndex {
+        let device = self.add_device(ETrackedDeviceClass::TrackedDeviceClass_Controller);
+        if let Some(device) = state().device_mut(device) {
+            device.role = role;
+        }
+        device
+    }
+
+    pub fn set_pose(&self, device: TrackedDeviceIndex, pose: TrackedDevicePose) {
+        if let Some(device) = state().device_mut(device) {
+            device.pose = pose;
+        }
+    }
+
+    /** Marks a device as connected or not, keeping its other state. */
+    pub fn set_connected(&self, device: TrackedDeviceIndex, connected: bool) {
+        if let Some(device) = state().device_mut(device) {
+            device.pose.device_is_connected = connected;
+        }
+    }
+
+    pub fn set_controller_state(&self, device: TrackedDeviceIndex, controller_state: VRControllerState001) {
+        if let Some(device) = state().device_mut(device) {
+            device.controller_state = controller_state;
+        }
+    }
+
+    pub fn set_property<V: Into<MockProperty>>(&self, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty, value: V) {
+        if let Some(device) = state().device_mut(device) {
+            device.properties.insert(prop as u32, value.into());
+        }
+    }
+
+    /** Queues an event for PollNextEvent. */
+    pub fn push_event(&self, event_type: EVREventType, device: TrackedDeviceIndex, data: VREvent_Data) {
+        state().events.push_back(VREvent {
+            event_type: event_type as u32,
+            tracked_device_index: device,
+            event_age_seconds: 0.0,
+            data,
+        });
+    }
+
+    pub fn set_setting(&self, section: &str, key: &str, value: SettingValue) {
+        state().settings.insert((section.to_string(), key.to_string()), value);
+    }
+
+    pub fn setting(&self, section: &str, key: &str) -> Option<SettingValue> {
+        state().settings.get(&(section.to_string(), key.to_string())).cloned()
+    }
+
+    pub fn add_application(&self, application: MockApplication) {
+        state().applications.push(application);
+    }
+
+    /** Every texture submitted since the mock was reset, in submission order. */
+    pub fn submitted_frames(&self) -> Vec<SubmittedFrame> {
+        state().submitted_frames.clone()
+    }
+
+    /** Appends the timing GetFrameTiming(0) will report next. */
+    pub fn push_frame_timing(&self, timing: Compositor_FrameTiming) {
+        state().frame_timings.push(timing);
+    }
+}
+
+impl Default for MockRuntime {
+    fn default() -> MockRuntime {
+        MockRuntime::new()
+    }
+}
+
+pub(crate) const IDENTITY: HmdMatrix34 = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]];
+
+/** A valid, connected pose at the tracking origin. */
+pub fn identity_pose() -> TrackedDevicePose {
+    TrackedDevicePose {
+        device_to_absolute_tracking: IDENTITY,
+        velocity: [0.0; 3],
+        angular_velocity: [0.0; 3],
+        tracking_result: ETrackingResult::TrackingResult_Running_OK,
+        pose_is_valid: true,
+        device_is_connected: true,
+    }
+}
+
+fn translation(x: f32, y: f32, z: f32) -> HmdMatrix34 {
+    [[1.0, 0.0, 0.0, x], [0.0, 1.0, 0.0, y], [0.0, 0.0, 1.0, z]]
+}
+
+/** a * b, treating both as affine transforms with an implicit [0 0 0 1] row. */
+fn multiply(a: &HmdMatrix34, b: &HmdMatrix34) -> HmdMatrix34 {
+    let mut out = [[0.0; 4]; 3];
+    for (row, out_row) in out.iter_mut().enumerate() {
+        for (column, value) in out_row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[row][k] * b[k][column]).sum::<f32>();
+        }
+        out_row[3] += a[row][3];
+    }
+    out
+}
+
+/** Inverse of a rigid transform. */
+fn invert(m: &HmdMatrix34) -> HmdMatrix34 {
+    let mut out = [[0.0; 4]; 3];
+    for (row, out_row) in out.iter_mut().enumerate() {
+        for (column, value) in out_row.iter_mut().take(3).enumerate() {
+            *value = m[column][row];
+        }
+        out_row[3] = -(0..3).map(|k| m[k][row] * m[k][3]).sum::<f32>();
+    }
+    out
+}
+
+/** Rotates a vector by the 3x3 part of m. */
+fn rotate(m: &HmdMatrix34, v: &HmdVector3) -> HmdVector3 {
+    [0, 1, 2].map(|row| (0..3).map(|k| m[row][k] * v[k]).sum())
+}
+
+/** A null-terminated copy of `name` that lives for the rest of the process, for the *NameFromEnum entry points. */
+fn static_name<T: Debug>(value: T) -> *const i8 {
+    static NAMES: Mutex<BTreeMap<String, &'static CStr>> = Mutex::new(BTreeMap::new());
+
+    let name = format!("{:?}", value);
+    let mut names = NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+    let entry = names
+        .entry(name)
+        .or_insert_with_key(|name| Box::leak(CString::new(name.as_str()).unwrap().into_boxed_c_str()));
+    entry.as_ptr()
+}
+
+/** Copies `value` into a caller buffer the way the runtime does and returns the size it needs,
+* including the trailing null. Nothing is written if the buffer is null or too small. */
+unsafe fn write_string(value: &str, buffer: *mut i8, buffer_size: u32) -> u32 {
+    let required = value.len() as u32 + 1;
+
+    if !buffer.is_null() && buffer_size >= required {
+        copy_nonoverlapping(value.as_ptr() as *const i8, buffer, value.len());
+        *buffer.add(value.len()) = 0;
+    }
+
+    required
+}
+
+unsafe fn read_str(ptr: *const i8) -> String {
+    string_from_ptr(ptr)
+}
+
+unsafe fn set<T>(out: *mut T, value: T) {
+    if !out.is_null() {
+        *out = value;
+    }
+}
+
+/** The mock's implementation of the VR_* entry points of openvr_api. They take the place of the linked
+* exports when the mock feature is enabled, with the same contracts. Interfaces other than the five
+* implemented here are reported as unsupported. */
+#[allow(non_snake_case, clippy::missing_safety_doc)]
+mod entry_points {
+    use super::*;
+
+    pub unsafe fn VR_IsHmdPresent() -> bool {
+        state().devices.iter().any(|device| device.class == ETrackedDeviceClass::TrackedDeviceClass_HMD)
+    }
+
+    pub unsafe fn VR_IsRuntimeInstalled() -> bool {
+        true
+    }
+
+    pub unsafe fn VR_GetRuntimePath(pchPathBuffer: *mut i8, unBufferSize: u32, punRequiredBufferSize: *mut u32) -> bool {
+        let required = write_string("mock", pchPathBuffer, unBufferSize);
+        set(punRequiredBufferSize, required);
+        unBufferSize >= required
+    }
+
+    pub unsafe fn VR_GetVRInitErrorAsSymbol(error: EVRInitError) -> *const i8 {
+        static_name(error)
+    }
+
+    pub unsafe fn VR_GetVRInitErrorAsEnglishDescription(error: EVRInitError) -> *const i8 {
+        static_name(error)
+    }
+
+    pub unsafe fn VR_GetGenericInterface(pchInterfaceVersion: *const i8, error: *mut EVRInitError) -> *const c_void {
+        let version = read_str(pchInterfaceVersion);
+
+        if !state().initialized {
+            set(error, EVRInitError::VRInitError_Init_NotInitialized);
+            return null();
+        }
+
+        let table: *const c_void = match version.strip_prefix("FnTable:").unwrap_or(&version) {
+            IVRSYSTEM_VERSION => &system::TABLE as *const VR_IVRSystem_FnTable as *const c_void,
+            IVRCOMPOSITOR_VERSION => &compositor::TABLE as *const VR_IVRCompositor_FnTable as *const c_void,
+            IVRCHAPERONE_VERSION => &chaperone::TABLE as *const VR_IVRChaperone_FnTable as *const c_void,
+            IVRSETTINGS_VERSION => &settings::TABLE as *const VR_IVRSettings_FnTable as *const c_void,
+            IVRAPPLICATIONS_VERSION => &applications::TABLE as *const VR_IVRApplications_FnTable as *const c_void,
+            _ => null(),
+        };
+
+        set(
+            error,
+            if table.is_null() { EVRInitError::VRInitError_Init_InterfaceNotFound } else { EVRInitError::VRInitError_None },
+        );
+        table
+    }
+
+    pub unsafe fn VR_IsInterfaceVersionValid(pchInterfaceVersion: *const i8) -> bool {
+        let version = read_str(pchInterfaceVersion);
+        [IVRSYSTEM_VERSION, IVRCOMPOSITOR_VERSION, IVRCHAPERONE_VERSION, IVRSETTINGS_VERSION, IVRAPPLICATIONS_VERSION]
+            .contains(&version.as_str())
+    }
+
+    pub unsafe fn VR_GetInitToken() -> u32 {
+        state().init_token
+    }
+
+    pub unsafe fn VR_InitInternal2(peError: *mut EVRInitError, eApplicationType: EVRApplicationType, _pStartupInfo: *const i8) -> u32 {
+        let mut state = state();
+
+        if let Some(error) = state.init_error {
+            set(peError, error);
+            return 0;
+        }
+
+        state.initialized = true;
+        state.init_token += 1;
+        state.application_type = Some(eApplicationType);
+        set(peError, EVRInitError::VRInitError_None);
+        state.init_token
+    }
+
+    pub unsafe fn VR_ShutdownInternal() {
+        let mut state = state();
+        state.initialized = false;
+        state.application_type = None;
+    }
+}
+
+pub use entry_points::*;
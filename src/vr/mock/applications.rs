@@ -0,0 +1,429 @@
+use std::slice;
+
+use super::*;
+
+pub(super) static TABLE: VR_IVRApplications_FnTable = VR_IVRApplications_FnTable {
+    AddApplicationManifest: add_application_manifest,
+    RemoveApplicationManifest: remove_application_manifest,
+    IsApplicationInstalled: is_application_installed,
+    GetApplicationCount: get_application_count,
+    GetApplicationKeyByIndex: get_application_key_by_index,
+    GetApplicationKeyByProcessId: get_application_key_by_process_id,
+    LaunchApplication: launch_application,
+    LaunchTemplateApplication: launch_template_application,
+    LaunchApplicationFromMimeType: launch_application_from_mime_type,
+    LaunchDashboardOverlay: launch_dashboard_overlay,
+    CancelApplicationLaunch: cancel_application_launch,
+    IdentifyApplication: identify_application,
+    GetApplicationProcessId: get_application_process_id,
+    GetApplicationsErrorNameFromEnum: get_applications_error_name_from_enum,
+    GetApplicationPropertyString: get_application_property_string,
+    GetApplicationPropertyBool: get_application_property_bool,
+    GetApplicationPropertyUint64: get_application_property_uint64,
+    SetApplicationAutoLaunch: set_application_auto_launch,
+    GetApplicationAutoLaunch: get_application_auto_launch,
+    SetDefaultApplicationForMimeType: set_default_application_for_mime_type,
+    GetDefaultApplicationForMimeType: get_default_application_for_mime_type,
+    GetApplicationSupportedMimeTypes: get_application_supported_mime_types,
+    GetApplicationsThatSupportMimeType: get_applications_that_support_mime_type,
+    GetApplicationLaunchArguments: get_application_launch_arguments,
+    GetStartingApplication: get_starting_application,
+    GetSceneApplicationState: get_scene_application_state,
+    PerformApplicationPrelaunchCheck: perform_application_prelaunch_check,
+    GetSceneApplicationStateNameFromEnum: get_scene_application_state_name_from_enum,
+    LaunchInternalProcess: launch_internal_process,
+    GetCurrentSceneProcessId: get_current_scene_process_id,
+};
+
+impl MockState {
+    fn application(&self, key: &str) -> Option<&MockApplication> {
+        self.applications.iter().find(|application| application.key == key)
+    }
+
+    fn application_mut(&mut self, key: &str) -> Option<&mut MockApplication> {
+        self.applications.iter_mut().find(|application| application.key == key)
+    }
+
+    /** Process ids handed out by the mock start at 1000. */
+    fn next_process_id(&self) -> u32 {
+        self.applications.iter().map(|application| application.process_id).max().unwrap_or(0).max(999) + 1
+    }
+
+    /** Starts `key` as the scene application, giving it the next free process id. Launch arguments are
+    * stored under that process id, which doubles as the handle for GetApplicationLaunchArguments. */
+    fn launch(&mut self, key: &str, arguments: Option<String>) -> EVRApplicationError {
+        let process_id = self.next_process_id();
+
+        let Some(application) = self.application_mut(key) else {
+            return EVRApplicationError::VRApplicationError_UnknownApplication;
+        };
+        if application.process_id != 0 {
+            return EVRApplicationError::VRApplicationError_ApplicationAlreadyRunning;
+        }
+        application.process_id = process_id;
+
+        if let Some(arguments) = arguments {
+            self.launch_arguments.insert(process_id, arguments);
+        }
+        self.starting_application = Some(key.to_string());
+        self.scene_application_state = EVRSceneApplicationState::EVRSceneApplicationState_Running;
+        self.scene_process_id = process_id;
+        EVRApplicationError::VRApplicationError_None
+    }
+}
+
+/** Copies an application key into a caller buffer, failing with BufferTooSmall if it does not fit. */
+unsafe fn write_key(key: &str, buffer: *mut i8, buffer_size: u32) -> EVRApplicationError {
+    if write_string(key, buffer, buffer_size) > buffer_size {
+        EVRApplicationError::VRApplicationError_BufferTooSmall
+    } else {
+        EVRApplicationError::VRApplicationError_None
+    }
+}
+
+unsafe extern "system" fn add_application_manifest(pchApplicationManifestFullPath: *const i8, _bTemporary: bool) -> EVRApplicationError {
+    if pchApplicationManifestFullPath.is_null() {
+        return EVRApplicationError::VRApplicationError_InvalidParameter;
+    }
+
+    state().manifests.push(read_str(pchApplicationManifestFullPath));
+    EVRApplicationError::VRApplicationError_None
+}
+
+unsafe extern "system" fn remove_application_manifest(pchApplicationManifestFullPath: *const i8) -> EVRApplicationError {
+    let path = read_str(pchApplicationManifestFullPath);
+    let mut state = state();
+
+    match state.manifests.iter().position(|manifest| *manifest == path) {
+        Some(index) => {
+            state.manifests.remove(index);
+            EVRApplicationError::VRApplicationError_None
+        }
+        None => EVRApplicationError::VRApplicationError_NoManifest,
+    }
+}
+
+unsafe extern "system" fn is_application_installed(pchAppKey: *const i8) -> bool {
+    state().application(&read_str(pchAppKey)).is_some()
+}
+
+unsafe extern "system" fn get_application_count() -> u32 {
+    state().applications.len() as u32
+}
+
+unsafe extern "system" fn get_application_key_by_index(
+    unApplicationIndex: u32,
+    pchAppKeyBuffer: *mut i8,
+    unAppKeyBufferLen: u32,
+) -> EVRApplicationError {
+    match state().applications.get(unApplicationIndex as usize) {
+        Some(application) => write_key(&application.key, pchAppKeyBuffer, unAppKeyBufferLen),
+        None => EVRApplicationError::VRApplicationError_InvalidIndex,
+    }
+}
+
+unsafe extern "system" fn get_application_key_by_process_id(
+    unProcessId: u32,
+    pchAppKeyBuffer: *mut i8,
+    unAppKeyBufferLen: u32,
+) -> EVRApplicationError {
+    let state = state();
+    match state.applications.iter().find(|application| unProcessId != 0 && application.process_id == unProcessId) {
+        Some(application) => write_key(&application.key, pchAppKeyBuffer, unAppKeyBufferLen),
+        None => EVRApplicationError::VRApplicationError_UnknownApplication,
+    }
+}
+
+unsafe extern "system" fn launch_application(pchAppKey: *const i8) -> EVRApplicationError {
+    let key = read_str(pchAppKey);
+    let mut state = state();
+
+    if state.application(&key).is_some_and(|application| application.is_template) {
+        return EVRApplicationError::VRApplicationError_IsTemplate;
+    }
+    state.launch(&key, None)
+}
+
+/** Registers a copy of the template under the new key and launches it. Only the "arguments" and
+* "binary_path" override keys change anything in the copy. */
+unsafe extern "system" fn launch_template_application(
+    pchTemplateAppKey: *const i8,
+    pchNewAppKey: *const i8,
+    pKeys: *const AppOverrideKeys,
+    unKeys: u32,
+) -> EVRApplicationError {
+    let (template_key, new_key) = (read_str(pchTemplateAppKey), read_str(pchNewAppKey));
+    let mut state = state();
+
+    let Some(template) = state.application(&template_key) else {
+        return EVRApplicationError::VRApplicationError_UnknownApplication;
+    };
+    if !template.is_template {
+        return EVRApplicationError::VRApplicationError_InvalidApplication;
+    }
+    if state.application(&new_key).is_some() {
+        return EVRApplicationError::VRApplicationError_AppKeyAlreadyExists;
+    }
+
+    let mut application = MockApplication { key: new_key.clone(), is_template: false, process_id: 0, ..template.clone() };
+    if !pKeys.is_null() {
+        for keys in slice::from_raw_parts(pKeys, unKeys as usize) {
+            let value = read_str(keys.pchValue);
+            match read_str(keys.pchKey).as_str() {
+                "arguments" => application.arguments = value,
+                "binary_path" => application.binary_path = value,
+                _ => {}
+            }
+        }
+    }
+
+    state.applications.push(application);
+    state.launch(&new_key, None)
+}
+
+/** Launches the default application for the mime type, or else the first one that supports it. */
+unsafe extern "system" fn launch_application_from_mime_type(pchMimeType: *const i8, pchArgs: *const i8) -> EVRApplicationError {
+    let mime_type = read_str(pchMimeType);
+    let arguments = read_str(pchArgs);
+    let mut state = state();
+
+    let key = state.default_applications.get(&mime_type).cloned().or_else(|| {
+        state
+            .applications
+            .iter()
+            .find(|application| application.mime_types.contains(&mime_type))
+            .map(|application| application.key.clone())
+    });
+
+    match key {
+        Some(key) => state.launch(&key, Some(arguments)),
+        None => EVRApplicationError::VRApplicationError_UnknownApplication,
+    }
+}
+
+unsafe extern "system" fn launch_dashboard_overlay(pchAppKey: *const i8) -> EVRApplicationError {
+    let key = read_str(pchAppKey);
+    let mut state = state();
+    let process_id = state.next_process_id();
+
+    let Some(application) = state.application_mut(&key) else {
+        return EVRApplicationError::VRApplicationError_UnknownApplication;
+    };
+    if !application.is_dashboard_overlay {
+        return EVRApplicationError::VRApplicationError_InvalidApplication;
+    }
+
+    application.process_id = process_id;
+    EVRApplicationError::VRApplicationError_None
+}
+
+/** Launches complete immediately in the mock, so there is never anything to cancel. */
+unsafe extern "system" fn cancel_application_launch(_pchAppKey: *const i8) -> bool {
+    false
+}
+
+unsafe extern "system" fn identify_application(unProcessId: u32, pchAppKey: *const i8) -> EVRApplicationError {
+    let key = read_str(pchAppKey);
+    let mut state = state();
+
+    match state.application_mut(&key) {
+        Some(application) => {
+            application.process_id = unProcessId;
+            EVRApplicationError::VRApplicationError_None
+        }
+        None => EVRApplicationError::VRApplicationError_UnknownApplication,
+    }
+}
+
+unsafe extern "system" fn get_application_process_id(pchAppKey: *const i8) -> u32 {
+    state().application(&read_str(pchAppKey)).map(|application| application.process_id).unwrap_or(0)
+}
+
+unsafe extern "system" fn get_applications_error_name_from_enum(error: EVRApplicationError) -> *const i8 {
+    static_name(error)
+}
+
+/** Looks up an application and reads one of its properties with `read`, which returns None for properties the mock does not store. */
+unsafe fn property<T: Default>(
+    key: *const i8,
+    property: EVRApplicationProperty,
+    error: *mut EVRApplicationError,
+    read: impl FnOnce(&MockApplication, EVRApplicationProperty) -> Option<T>,
+) -> T {
+    let state = state();
+    let (result, value) = match state.application(&read_str(key)) {
+        None => (EVRApplicationError::VRApplicationError_UnknownApplication, T::default()),
+        Some(application) => match read(application, property) {
+            Some(value) => (EVRApplicationError::VRApplicationError_None, value),
+            None => (EVRApplicationError::VRApplicationError_UnknownProperty, T::default()),
+        },
+    };
+
+    set(error, result);
+    value
+}
+
+unsafe extern "system" fn get_application_property_string(
+    pchAppKey: *const i8,
+    eProperty: EVRApplicationProperty,
+    pchPropertyValueBuffer: *mut i8,
+    unPropertyValueBufferLen: u32,
+    peError: *mut EVRApplicationError,
+) -> u32 {
+    let value = property(pchAppKey, eProperty, peError, |application, property| match property {
+        EVRApplicationProperty::VRApplicationProperty_Name_String => Some(Some(application.name.clone())),
+        EVRApplicationProperty::VRApplicationProperty_BinaryPath_String => Some(Some(application.binary_path.clone())),
+        EVRApplicationProperty::VRApplicationProperty_Arguments_String => Some(Some(application.arguments.clone())),
+        _ => None,
+    });
+
+    let Some(value) = value else {
+        return 0;
+    };
+
+    let required = write_string(&value, pchPropertyValueBuffer, unPropertyValueBufferLen);
+    if unPropertyValueBufferLen < required {
+        set(peError, EVRApplicationError::VRApplicationError_BufferTooSmall);
+    }
+    required
+}
+
+unsafe extern "system" fn get_application_property_bool(
+    pchAppKey: *const i8,
+    eProperty: EVRApplicationProperty,
+    peError: *mut EVRApplicationError,
+) -> bool {
+    property(pchAppKey, eProperty, peError, |application, property| match property {
+        EVRApplicationProperty::VRApplicationProperty_IsDashboardOverlay_Bool => Some(application.is_dashboard_overlay),
+        EVRApplicationProperty::VRApplicationProperty_IsTemplate_Bool => Some(application.is_template),
+        _ => None,
+    })
+}
+
+unsafe extern "system" fn get_application_property_uint64(
+    pchAppKey: *const i8,
+    eProperty: EVRApplicationProperty,
+    peError: *mut EVRApplicationError,
+) -> u64 {
+    property(pchAppKey, eProperty, peError, |_, _| None)
+}
+
+unsafe extern "system" fn set_application_auto_launch(pchAppKey: *const i8, bAutoLaunch: bool) -> EVRApplicationError {
+    match state().application_mut(&read_str(pchAppKey)) {
+        Some(application) => {
+            application.auto_launch = bAutoLaunch;
+            EVRApplicationError::VRApplicationError_None
+        }
+        None => EVRApplicationError::VRApplicationError_UnknownApplication,
+    }
+}
+
+unsafe extern "system" fn get_application_auto_launch(pchAppKey: *const i8) -> bool {
+    state().application(&read_str(pchAppKey)).is_some_and(|application| application.auto_launch)
+}
+
+unsafe extern "system" fn set_default_application_for_mime_type(pchAppKey: *const i8, pchMimeType: *const i8) -> EVRApplicationError {
+    let (key, mime_type) = (read_str(pchAppKey), read_str(pchMimeType));
+    let mut state = state();
+
+    if state.application(&key).is_none() {
+        return EVRApplicationError::VRApplicationError_UnknownApplication;
+    }
+
+    state.default_applications.insert(mime_type, key);
+    EVRApplicationError::VRApplicationError_None
+}
+
+unsafe extern "system" fn get_default_application_for_mime_type(
+    pchMimeType: *const i8,
+    pchAppKeyBuffer: *mut i8,
+    unAppKeyBufferLen: u32,
+) -> bool {
+    match state().default_applications.get(&read_str(pchMimeType)) {
+        Some(key) => write_key(key, pchAppKeyBuffer, unAppKeyBufferLen) == EVRApplicationError::VRApplicationError_None,
+        None => false,
+    }
+}
+
+/** Mime types are returned comma separated. */
+unsafe extern "system" fn get_application_supported_mime_types(
+    pchAppKey: *const i8,
+    pchMimeTypesBuffer: *mut i8,
+    unMimeTypesBuffer: u32,
+) -> bool {
+    match state().application(&read_str(pchAppKey)) {
+        Some(application) => write_string(&application.mime_types.join(","), pchMimeTypesBuffer, unMimeTypesBuffer) <= unMimeTypesBuffer,
+        None => false,
+    }
+}
+
+/** Keys are returned as a list of null-terminated strings, ending with an empty one. */
+unsafe extern "system" fn get_applications_that_support_mime_type(
+    pchMimeType: *const i8,
+    pchAppKeysThatSupportBuffer: *mut i8,
+    unAppKeysThatSupportBuffer: u32,
+) -> u32 {
+    let mime_type = read_str(pchMimeType);
+    let state = state();
+
+    let mut keys = Vec::new();
+    for application in state.applications.iter().filter(|application| application.mime_types.contains(&mime_type)) {
+        keys.extend_from_slice(application.key.as_bytes());
+        keys.push(0);
+    }
+
+    let required = keys.len() as u32 + 1;
+    if !pchAppKeysThatSupportBuffer.is_null() && unAppKeysThatSupportBuffer >= required {
+        copy_nonoverlapping(keys.as_ptr() as *const i8, pchAppKeysThatSupportBuffer, keys.len());
+        *pchAppKeysThatSupportBuffer.add(keys.len()) = 0;
+    }
+    required
+}
+
+unsafe extern "system" fn get_application_launch_arguments(unHandle: u32, pchArgs: *mut i8, unArgs: u32) -> u32 {
+    match state().launch_arguments.get(&unHandle) {
+        Some(arguments) => write_string(arguments, pchArgs, unArgs),
+        None => 0,
+    }
+}
+
+unsafe extern "system" fn get_starting_application(pchAppKeyBuffer: *mut i8, unAppKeyBufferLen: u32) -> EVRApplicationError {
+    match &state().starting_application {
+        Some(key) => write_key(key, pchAppKeyBuffer, unAppKeyBufferLen),
+        None => EVRApplicationError::VRApplicationError_NoApplication,
+    }
+}
+
+unsafe extern "system" fn get_scene_application_state() -> EVRSceneApplicationState {
+    state().scene_application_state
+}
+
+unsafe extern "system" fn perform_application_prelaunch_check(pchAppKey: *const i8) -> EVRApplicationError {
+    match state().application(&read_str(pchAppKey)) {
+        Some(application) if application.process_id != 0 => EVRApplicationError::VRApplicationError_ApplicationAlreadyRunning,
+        Some(_) => EVRApplicationError::VRApplicationError_None,
+        None => EVRApplicationError::VRApplicationError_UnknownApplication,
+    }
+}
+
+unsafe extern "system" fn get_scene_application_state_name_from_enum(state: EVRSceneApplicationState) -> *const i8 {
+    static_name(state)
+}
+
+/** Records the binary path; nothing is started. */
+unsafe extern "system" fn launch_internal_process(
+    pchBinaryPath: *const i8,
+    _pchArguments: *const i8,
+    _pchWorkingDirectory: *const i8,
+) -> EVRApplicationError {
+    if pchBinaryPath.is_null() {
+        return EVRApplicationError::VRApplicationError_InvalidParameter;
+    }
+
+    state().internal_processes.push(read_str(pchBinaryPath));
+    EVRApplicationError::VRApplicationError_None
+}
+
+unsafe extern "system" fn get_current_scene_process_id() -> u32 {
+    state().scene_process_id
+}
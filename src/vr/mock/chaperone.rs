@@ -0,0 +1,88 @@
+use super::*;
+
+pub(super) static TABLE: VR_IVRChaperone_FnTable = VR_IVRChaperone_FnTable {
+    GetCalibrationState: get_calibration_state,
+    GetPlayAreaSize: get_play_area_size,
+    GetPlayAreaRect: get_play_area_rect,
+    ReloadInfo: reload_info,
+    SetSceneColor: set_scene_color,
+    GetBoundsColor: get_bounds_color,
+    AreBoundsVisible: are_bounds_visible,
+    ForceBoundsVisible: force_bounds_visible,
+    ResetZeroPose: reset_zero_pose,
+};
+
+/** The play area is only reported while calibration is OK, like the runtime does. */
+fn calibrated(state: &MockState) -> bool {
+    state.calibration_state == ChaperoneCalibrationState::ChaperoneCalibrationState_OK
+}
+
+unsafe extern "system" fn get_calibration_state() -> ChaperoneCalibrationState {
+    state().calibration_state
+}
+
+unsafe extern "system" fn get_play_area_size(pSizeX: *mut f32, pSizeZ: *mut f32) -> bool {
+    let state = state();
+    if !calibrated(&state) {
+        return false;
+    }
+
+    set(pSizeX, state.play_area_size.0);
+    set(pSizeZ, state.play_area_size.1);
+    true
+}
+
+unsafe extern "system" fn get_play_area_rect(rect: *mut HmdQuad) -> bool {
+    let state = state();
+    if !calibrated(&state) {
+        return false;
+    }
+
+    set(rect, state.play_area_rect);
+    true
+}
+
+unsafe extern "system" fn reload_info() {}
+
+unsafe extern "system" fn set_scene_color(color: HmdColor) {
+    state().scene_color = color;
+}
+
+/** Bounds are drawn in the inverse of the scene color, with the camera color matching the first bound. */
+unsafe extern "system" fn get_bounds_color(
+    pOutputColorArray: *mut HmdColor,
+    nNumOutputColors: i32,
+    _flCollisionBoundsFadeDistance: f32,
+    pOutputCameraColor: *mut HmdColor,
+) {
+    let scene = state().scene_color;
+    let color = HmdColor { r: 1.0 - scene.r, g: 1.0 - scene.g, b: 1.0 - scene.b, a: 1.0 };
+
+    if !pOutputColorArray.is_null() {
+        for index in 0..nNumOutputColors.max(0) as usize {
+            *pOutputColorArray.add(index) = color;
+        }
+    }
+    set(pOutputCameraColor, color);
+}
+
+unsafe extern "system" fn are_bounds_visible() -> bool {
+    let state = state();
+    state.bounds_visible || state.bounds_forced_visible
+}
+
+unsafe extern "system" fn force_bounds_visible(bForce: bool) {
+    state().bounds_forced_visible = bForce;
+}
+
+/** Records the reset. Resetting the seated origin also moves the seated zero pose to the HMD's current pose. */
+unsafe extern "system" fn reset_zero_pose(eTrackingUniverseOrigin: ETrackingUniverseOrigin) {
+    let mut state = state();
+    state.zero_pose_resets.push(eTrackingUniverseOrigin);
+
+    if eTrackingUniverseOrigin == ETrackingUniverseOrigin::TrackingUniverseSeated {
+        if let Some(hmd) = state.device(k_unTrackedDeviceIndex_Hmd) {
+            state.seated_zero_pose = hmd.pose.device_to_absolute_tracking;
+        }
+    }
+}
@@ -0,0 +1,439 @@
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::ptr::copy_nonoverlapping;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::slice;
+
+use super::*;
+
+pub(super) static TABLE: VR_IVRCompositor_FnTable = VR_IVRCompositor_FnTable {
+    SetTrackingSpace: set_tracking_space,
+    GetTrackingSpace: get_tracking_space,
+    WaitGetPoses: wait_get_poses,
+    GetLastPoses: get_last_poses,
+    GetLastPoseForTrackedDeviceIndex: get_last_pose_for_tracked_device_index,
+    Submit: submit,
+    ClearLastSubmittedFrame: clear_last_submitted_frame,
+    PostPresentHandoff: post_present_handoff,
+    GetFrameTiming: get_frame_timing,
+    GetFrameTimings: get_frame_timings,
+    GetFrameTimeRemaining: get_frame_time_remaining,
+    GetCumulativeStats: get_cumulative_stats,
+    FadeToColor: fade_to_color,
+    GetCurrentFadeColor: get_current_fade_color,
+    FadeGrid: fade_grid,
+    GetCurrentGridAlpha: get_current_grid_alpha,
+    SetSkyboxOverride: set_skybox_override,
+    ClearSkyboxOverride: clear_skybox_override,
+    CompositorBringToFront: do_nothing,
+    CompositorGoToBack: do_nothing,
+    CompositorQuit: do_nothing,
+    IsFullscreen: is_fullscreen,
+    GetCurrentSceneFocusProcess: get_current_scene_focus_process,
+    GetLastFrameRenderer: get_current_scene_focus_process,
+    CanRenderScene: can_render_scene,
+    ShowMirrorWindow: show_mirror_window,
+    HideMirrorWindow: hide_mirror_window,
+    IsMirrorWindowVisible: is_mirror_window_visible,
+    CompositorDumpImages: do_nothing,
+    ShouldAppRenderWithLowResources: should_app_render_with_low_resources,
+    ForceInterleavedReprojectionOn: force_interleaved_reprojection_on,
+    ForceReconnectProcess: do_nothing,
+    SuspendRendering: suspend_rendering,
+    GetMirrorTextureD3D11: get_mirror_texture_d3d11,
+    ReleaseMirrorTextureD3D11: release_mirror_texture_d3d11,
+    GetMirrorTextureGL: get_mirror_texture_gl,
+    ReleaseSharedGLTexture: release_shared_gl_texture,
+    LockGLSharedTextureForAccess: lock_gl_shared_texture_for_access,
+    UnlockGLSharedTextureForAccess: unlock_gl_shared_texture_for_access,
+    GetVulkanInstanceExtensionsRequired: get_vulkan_instance_extensions_required,
+    GetVulkanDeviceExtensionsRequired: get_vulkan_device_extensions_required,
+    SetExplicitTimingMode: set_explicit_timing_mode,
+    SubmitExplicitTimingData: submit_explicit_timing_data,
+    IsMotionSmoothingEnabled: is_motion_smoothing_enabled,
+    IsMotionSmoothingSupported: is_motion_smoothing_supported,
+    IsCurrentSceneFocusAppLoading: is_current_scene_focus_app_loading,
+    SetStageOverride_Async: set_stage_override_async,
+    ClearStageOverride: clear_stage_override,
+    GetCompositorBenchmarkResults: get_compositor_benchmark_results,
+    GetLastPosePredictionIDs: get_last_pose_prediction_ids,
+    GetPosesForFrame: get_poses_for_frame,
+};
+
+unsafe extern "system" fn do_nothing() {}
+
+unsafe extern "system" fn set_tracking_space(eOrigin: ETrackingUniverseOrigin) {
+    state().tracking_space = eOrigin;
+}
+
+unsafe extern "system" fn get_tracking_space() -> ETrackingUniverseOrigin {
+    state().tracking_space
+}
+
+/** Starts a new frame. The mock never blocks and predicts no motion, so render and game poses are the current poses. */
+unsafe extern "system" fn wait_get_poses(
+    pRenderPoseArray: *mut TrackedDevicePose,
+    unRenderPoseArrayCount: u32,
+    pGamePoseArray: *mut TrackedDevicePose,
+    unGamePoseArrayCount: u32,
+) -> EVRCompositorError {
+    let mut state = state();
+    state.frame_index += 1;
+    state.fill_poses(state.tracking_space, pRenderPoseArray, unRenderPoseArrayCount);
+    state.fill_poses(state.tracking_space, pGamePoseArray, unGamePoseArrayCount);
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn get_last_poses(
+    pRenderPoseArray: *mut TrackedDevicePose,
+    unRenderPoseArrayCount: u32,
+    pGamePoseArray: *mut TrackedDevicePose,
+    unGamePoseArrayCount: u32,
+) -> EVRCompositorError {
+    let state = state();
+    state.fill_poses(state.tracking_space, pRenderPoseArray, unRenderPoseArrayCount);
+    state.fill_poses(state.tracking_space, pGamePoseArray, unGamePoseArrayCount);
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn get_last_pose_for_tracked_device_index(
+    unDeviceIndex: u32,
+    pOutputPose: *mut TrackedDevicePose,
+    pOutputGamePose: *mut TrackedDevicePose,
+) -> EVRCompositorError {
+    if unDeviceIndex >= k_unMaxTrackedDeviceCount {
+        return EVRCompositorError::VRCompositorError_IndexOutOfRange;
+    }
+
+    let state = state();
+    let pose = state.pose(unDeviceIndex, state.tracking_space);
+    set(pOutputPose, pose);
+    set(pOutputGamePose, pose);
+    EVRCompositorError::VRCompositorError_None
+}
+
+/** Records the texture in MockState::submitted_frames. Only the leading Texture part of the extended texture structs is read. */
+unsafe extern "system" fn submit(
+    eEye: EVREye,
+    pTexture: *const Texture,
+    pBounds: *const VRTextureBounds,
+    nSubmitFlags: EVRSubmitFlags,
+) -> EVRCompositorError {
+    let mut state = state();
+
+    if let Some(error) = state.submit_error {
+        return error;
+    }
+
+    if pTexture.is_null() || (*pTexture).handle.is_null() {
+        return EVRCompositorError::VRCompositorError_InvalidTexture;
+    }
+
+    let frame_index = state.frame_index;
+    if state.submitted_frames.iter().any(|frame| frame.frame_index == frame_index && frame.eye == eEye) {
+        return EVRCompositorError::VRCompositorError_AlreadySubmitted;
+    }
+
+    let texture = &*pTexture;
+    let bounds = pBounds.as_ref().map(|bounds| [bounds.u_min, bounds.v_min, bounds.u_max, bounds.v_max]);
+
+    state.submitted_frames.push(SubmittedFrame {
+        frame_index,
+        eye: eEye,
+        handle: texture.handle,
+        texture_type: texture.texture_type,
+        color_space: texture.color_space,
+        bounds,
+        flags: nSubmitFlags,
+    });
+    EVRCompositorError::VRCompositorError_None
+}
+
+/** Forgets the textures submitted for the current frame, so both eyes can be submitted again. */
+unsafe extern "system" fn clear_last_submitted_frame() {
+    let mut state = state();
+    let frame_index = state.frame_index;
+    state.submitted_frames.retain(|frame| frame.frame_index != frame_index);
+}
+
+unsafe extern "system" fn post_present_handoff() {
+    state().post_present_handoffs += 1;
+}
+
+unsafe extern "system" fn get_frame_timing(pTiming: *mut Compositor_FrameTiming, unFramesAgo: u32) -> bool {
+    if pTiming.is_null() || (*pTiming).size as usize != size_of::<Compositor_FrameTiming>() {
+        return false;
+    }
+
+    let state = state();
+    let Some(newest) = state.frame_timings.len().checked_sub(1) else {
+        return false;
+    };
+
+    *pTiming = state.frame_timings[newest.saturating_sub(unFramesAgo as usize)];
+    true
+}
+
+unsafe extern "system" fn get_frame_timings(pTiming: *mut Compositor_FrameTiming, nFrames: u32) -> u32 {
+    if pTiming.is_null() || (*pTiming).size as usize != size_of::<Compositor_FrameTiming>() {
+        return 0;
+    }
+
+    let state = state();
+    let count = state.frame_timings.len().min(nFrames as usize);
+    let newest = &state.frame_timings[state.frame_timings.len() - count..];
+    copy_nonoverlapping(newest.as_ptr(), pTiming, count);
+    count as u32
+}
+
+unsafe extern "system" fn get_frame_time_remaining() -> f32 {
+    state().frame_time_remaining
+}
+
+/** The mock keeps no statistics of its own; it reports MockState::cumulative_stats for the scene application. */
+unsafe extern "system" fn get_cumulative_stats(pStats: *mut Compositor_CumulativeStats, nStatsSizeInBytes: u32) {
+    if pStats.is_null() || nStatsSizeInBytes as usize != size_of::<Compositor_CumulativeStats>() {
+        return;
+    }
+
+    let state = state();
+    *pStats = Compositor_CumulativeStats { m_nPid: state.scene_process_id, ..state.cumulative_stats };
+}
+
+/** Fades complete immediately. */
+unsafe extern "system" fn fade_to_color(fSeconds: f32, fRed: f32, fGreen: f32, fBlue: f32, fAlpha: f32, bBackground: bool) {
+    let color = HmdColor { r: fRed, g: fGreen, b: fBlue, a: fAlpha };
+    let mut state = state();
+    state.fade_seconds = fSeconds;
+    if bBackground {
+        state.background_fade_color = color;
+    } else {
+        state.fade_color = color;
+    }
+}
+
+unsafe extern "system" fn get_current_fade_color(bBackground: bool) -> HmdColor {
+    let state = state();
+    if bBackground {
+        state.background_fade_color
+    } else {
+        state.fade_color
+    }
+}
+
+unsafe extern "system" fn fade_grid(fSeconds: f32, bFadeGridIn: bool) {
+    let mut state = state();
+    state.grid_fade_seconds = fSeconds;
+    state.grid_alpha = if bFadeGridIn { 1.0 } else { 0.0 };
+}
+
+unsafe extern "system" fn get_current_grid_alpha() -> f32 {
+    state().grid_alpha
+}
+
+/** Rejects anything but 1, 2 or 6 faces, which are the counts the runtime accepts. */
+unsafe extern "system" fn set_skybox_override(pTextures: *const Texture, unTextureCount: u32) -> EVRCompositorError {
+    if pTextures.is_null() || ![1, 2, 6].contains(&unTextureCount) {
+        return EVRCompositorError::VRCompositorError_InvalidTexture;
+    }
+
+    let faces = slice::from_raw_parts(pTextures, unTextureCount as usize);
+    state().skybox_override = Some(faces.iter().map(|face| (face.texture_type, face.handle)).collect());
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn clear_skybox_override() {
+    state().skybox_override = None;
+}
+
+unsafe extern "system" fn is_fullscreen() -> bool {
+    state().fullscreen
+}
+
+unsafe extern "system" fn get_current_scene_focus_process() -> u32 {
+    state().scene_process_id
+}
+
+unsafe extern "system" fn can_render_scene() -> bool {
+    let state = state();
+    state.initialized && state.application_type == Some(EVRApplicationType::VRApplication_Scene)
+}
+
+unsafe extern "system" fn show_mirror_window() {
+    state().mirror_window_visible = true;
+}
+
+unsafe extern "system" fn hide_mirror_window() {
+    state().mirror_window_visible = false;
+}
+
+unsafe extern "system" fn is_mirror_window_visible() -> bool {
+    state().mirror_window_visible
+}
+
+unsafe extern "system" fn should_app_render_with_low_resources() -> bool {
+    state().low_resources
+}
+
+unsafe extern "system" fn force_interleaved_reprojection_on(bOverride: bool) {
+    state().interleaved_reprojection_forced = bOverride;
+}
+
+unsafe extern "system" fn suspend_rendering(bSuspend: bool) {
+    state().rendering_suspended = bSuspend;
+}
+
+/** Hands out a fresh handle per call and tracks it in MockState::mirror_textures until it is released.
+* Handles start at 1 so none of them is null. */
+fn next_mirror_texture(state: &mut MockState) -> *mut c_void {
+    static NEXT: AtomicUsize = AtomicUsize::new(1);
+
+    let handle = NEXT.fetch_add(1, Ordering::Relaxed) as *mut c_void;
+    state.mirror_textures.push(handle);
+    handle
+}
+
+fn release_mirror_texture(state: &mut MockState, handle: *mut c_void) -> bool {
+    let before = state.mirror_textures.len();
+    state.mirror_textures.retain(|&texture| texture != handle);
+    state.mirror_textures.len() != before
+}
+
+unsafe extern "system" fn get_mirror_texture_d3d11(
+    _eEye: EVREye,
+    pD3D11DeviceOrResource: *mut c_void,
+    ppD3D11ShaderResourceView: *mut *mut c_void,
+) -> EVRCompositorError {
+    if pD3D11DeviceOrResource.is_null() || ppD3D11ShaderResourceView.is_null() {
+        return EVRCompositorError::VRCompositorError_InvalidTexture;
+    }
+
+    *ppD3D11ShaderResourceView = next_mirror_texture(&mut state());
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn release_mirror_texture_d3d11(pD3D11ShaderResourceView: *mut c_void) {
+    release_mirror_texture(&mut state(), pD3D11ShaderResourceView);
+}
+
+/** The texture id handed out is the shared handle's value. */
+unsafe extern "system" fn get_mirror_texture_gl(
+    _eEye: EVREye,
+    pglTextureId: *mut GLUint,
+    pglSharedTextureHandle: *mut GLSharedTextureHandle,
+) -> EVRCompositorError {
+    if pglTextureId.is_null() || pglSharedTextureHandle.is_null() {
+        return EVRCompositorError::VRCompositorError_InvalidTexture;
+    }
+
+    let handle = next_mirror_texture(&mut state());
+    *pglTextureId = handle as usize as GLUint;
+    *pglSharedTextureHandle = handle;
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn release_shared_gl_texture(_glTextureId: GLUint, glSharedTextureHandle: GLSharedTextureHandle) -> bool {
+    release_mirror_texture(&mut state(), glSharedTextureHandle)
+}
+
+unsafe extern "system" fn lock_gl_shared_texture_for_access(_glSharedTextureHandle: GLSharedTextureHandle) {
+    state().gl_shared_texture_locked = true;
+}
+
+unsafe extern "system" fn unlock_gl_shared_texture_for_access(_glSharedTextureHandle: GLSharedTextureHandle) {
+    state().gl_shared_texture_locked = false;
+}
+
+unsafe extern "system" fn get_vulkan_instance_extensions_required(pchValue: *mut i8, unBufferSize: u32) -> u32 {
+    write_string(&state().vulkan_instance_extensions, pchValue, unBufferSize)
+}
+
+unsafe extern "system" fn get_vulkan_device_extensions_required(
+    _pPhysicalDevice: VkPhysicalDevice,
+    pchValue: *mut i8,
+    unBufferSize: u32,
+) -> u32 {
+    write_string(&state().vulkan_device_extensions, pchValue, unBufferSize)
+}
+
+unsafe extern "system" fn set_explicit_timing_mode(eTimingMode: EVRCompositorTimingMode) {
+    state().timing_mode = eTimingMode;
+}
+
+/** Only valid once an explicit timing mode has been set. */
+unsafe extern "system" fn submit_explicit_timing_data() -> EVRCompositorError {
+    let mut state = state();
+    if state.timing_mode == EVRCompositorTimingMode::VRCompositorTimingMode_Implicit {
+        return EVRCompositorError::VRCompositorError_RequestFailed;
+    }
+
+    state.explicit_timing_submissions += 1;
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn is_motion_smoothing_enabled() -> bool {
+    state().motion_smoothing_enabled
+}
+
+unsafe extern "system" fn is_motion_smoothing_supported() -> bool {
+    state().motion_smoothing_supported
+}
+
+unsafe extern "system" fn is_current_scene_focus_app_loading() -> bool {
+    state().scene_application_state == EVRSceneApplicationState::EVRSceneApplicationState_Starting
+}
+
+unsafe extern "system" fn set_stage_override_async(
+    pchRenderModelPath: *const i8,
+    pTransform: *const HmdMatrix34,
+    pRenderSettings: *const Compositor_StageRenderSettings,
+    nSizeOfRenderSettings: u32,
+) -> EVRCompositorError {
+    if pchRenderModelPath.is_null() || pTransform.is_null() {
+        return EVRCompositorError::VRCompositorError_RequestFailed;
+    }
+
+    let settings = if pRenderSettings.is_null() {
+        Compositor_StageRenderSettings::new()
+    } else if nSizeOfRenderSettings as usize == size_of::<Compositor_StageRenderSettings>() {
+        *pRenderSettings
+    } else {
+        return EVRCompositorError::VRCompositorError_IncompatibleVersion;
+    };
+
+    state().stage_override = Some(StageOverride {
+        render_model_path: read_str(pchRenderModelPath),
+        transform: *pTransform,
+        settings,
+    });
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn clear_stage_override() {
+    state().stage_override = None;
+}
+
+unsafe extern "system" fn get_compositor_benchmark_results(
+    pBenchmarkResults: *mut Compositor_BenchmarkResults,
+    nSizeOfBenchmarkResults: u32,
+) -> bool {
+    if pBenchmarkResults.is_null() || nSizeOfBenchmarkResults as usize != size_of::<Compositor_BenchmarkResults>() {
+        return false;
+    }
+
+    *pBenchmarkResults = state().benchmark_results;
+    true
+}
+
+/** Poses are never predicted, so both IDs are the current frame index. */
+unsafe extern "system" fn get_last_pose_prediction_ids(pRenderPosePredictionID: *mut u32, pGamePosePredictionID: *mut u32) -> EVRCompositorError {
+    let frame_index = state().frame_index;
+    set(pRenderPosePredictionID, frame_index);
+    set(pGamePosePredictionID, frame_index);
+    EVRCompositorError::VRCompositorError_None
+}
+
+unsafe extern "system" fn get_poses_for_frame(_unPosePredictionID: u32, pPoseArray: *mut TrackedDevicePose, unPoseArrayCount: u32) {
+    let state = state();
+    state.fill_poses(state.tracking_space, pPoseArray, unPoseArrayCount);
+}
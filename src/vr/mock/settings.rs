@@ -0,0 +1,137 @@
+use super::*;
+
+pub(super) static TABLE: VR_IVRSettings_FnTable = VR_IVRSettings_FnTable {
+    GetSettingsErrorNameFromEnum: get_settings_error_name_from_enum,
+    SetBool: set_bool,
+    SetInt32: set_int32,
+    SetFloat: set_float,
+    SetString: set_string,
+    GetBool: get_bool,
+    GetInt32: get_int32,
+    GetFloat: get_float,
+    GetString: get_string,
+    RemoveSection: remove_section,
+    RemoveKeyInSection: remove_key_in_section,
+};
+
+unsafe extern "system" fn get_settings_error_name_from_enum(eError: EVRSettingsError) -> *const i8 {
+    static_name(eError)
+}
+
+unsafe fn store(section: *const i8, key: *const i8, value: SettingValue, error: *mut EVRSettingsError) {
+    if section.is_null() || key.is_null() {
+        set(error, EVRSettingsError::VRSettingsError_WriteFailed);
+        return;
+    }
+
+    state().settings.insert((read_str(section), read_str(key)), value);
+    set(error, EVRSettingsError::VRSettingsError_None);
+}
+
+/** Reads a stored setting with `read`, which returns None if the stored value has a different type. */
+unsafe fn load<T: Default>(
+    section: *const i8,
+    key: *const i8,
+    error: *mut EVRSettingsError,
+    read: impl FnOnce(&SettingValue) -> Option<T>,
+) -> T {
+    let (result, value) = if section.is_null() || key.is_null() {
+        (EVRSettingsError::VRSettingsError_ReadFailed, T::default())
+    } else {
+        match state().settings.get(&(read_str(section), read_str(key))) {
+            None => (EVRSettingsError::VRSettingsError_UnsetSettingHasNoDefault, T::default()),
+            Some(stored) => match read(stored) {
+                Some(value) => (EVRSettingsError::VRSettingsError_None, value),
+                None => (EVRSettingsError::VRSettingsError_ReadFailed, T::default()),
+            },
+        }
+    };
+
+    set(error, result);
+    value
+}
+
+unsafe extern "system" fn set_bool(pchSection: *const i8, pchSettingsKey: *const i8, bValue: bool, peError: *mut EVRSettingsError) {
+    store(pchSection, pchSettingsKey, SettingValue::Bool(bValue), peError);
+}
+
+unsafe extern "system" fn set_int32(pchSection: *const i8, pchSettingsKey: *const i8, nValue: i32, peError: *mut EVRSettingsError) {
+    store(pchSection, pchSettingsKey, SettingValue::Int32(nValue), peError);
+}
+
+unsafe extern "system" fn set_float(pchSection: *const i8, pchSettingsKey: *const i8, flValue: f32, peError: *mut EVRSettingsError) {
+    store(pchSection, pchSettingsKey, SettingValue::Float(flValue), peError);
+}
+
+unsafe extern "system" fn set_string(pchSection: *const i8, pchSettingsKey: *const i8, pchValue: *const i8, peError: *mut EVRSettingsError) {
+    if pchValue.is_null() {
+        set(peError, EVRSettingsError::VRSettingsError_WriteFailed);
+        return;
+    }
+
+    store(pchSection, pchSettingsKey, SettingValue::String(read_str(pchValue)), peError);
+}
+
+unsafe extern "system" fn get_bool(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> bool {
+    load(pchSection, pchSettingsKey, pError, |stored| match stored {
+        SettingValue::Bool(value) => Some(*value),
+        _ => None,
+    })
+}
+
+unsafe extern "system" fn get_int32(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> i32 {
+    load(pchSection, pchSettingsKey, pError, |stored| match stored {
+        SettingValue::Int32(value) => Some(*value),
+        _ => None,
+    })
+}
+
+unsafe extern "system" fn get_float(pchSection: *const i8, pchSettingsKey: *const i8, pError: *mut EVRSettingsError) -> f32 {
+    load(pchSection, pchSettingsKey, pError, |stored| match stored {
+        SettingValue::Float(value) => Some(*value),
+        _ => None,
+    })
+}
+
+/** Truncates to fit the buffer, always leaving it null terminated. */
+unsafe extern "system" fn get_string(
+    pchSection: *const i8,
+    pchSettingsKey: *const i8,
+    pchValue: *mut i8,
+    unValueLen: usize,
+    pError: *mut EVRSettingsError,
+) {
+    let value = load(pchSection, pchSettingsKey, pError, |stored| match stored {
+        SettingValue::String(value) => Some(value.clone()),
+        _ => None,
+    });
+
+    if pchValue.is_null() || unValueLen == 0 {
+        return;
+    }
+
+    let len = value.len().min(unValueLen - 1);
+    copy_nonoverlapping(value.as_ptr() as *const i8, pchValue, len);
+    *pchValue.add(len) = 0;
+}
+
+unsafe extern "system" fn remove_section(pchSection: *const i8, peError: *mut EVRSettingsError) {
+    if pchSection.is_null() {
+        set(peError, EVRSettingsError::VRSettingsError_WriteFailed);
+        return;
+    }
+
+    let section = read_str(pchSection);
+    state().settings.retain(|(stored, _), _| *stored != section);
+    set(peError, EVRSettingsError::VRSettingsError_None);
+}
+
+unsafe extern "system" fn remove_key_in_section(pchSection: *const i8, pchSettingsKey: *const i8, peError: *mut EVRSettingsError) {
+    if pchSection.is_null() || pchSettingsKey.is_null() {
+        set(peError, EVRSettingsError::VRSettingsError_WriteFailed);
+        return;
+    }
+
+    state().settings.remove(&(read_str(pchSection), read_str(pchSettingsKey)));
+    set(peError, EVRSettingsError::VRSettingsError_None);
+}
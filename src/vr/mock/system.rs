@@ -0,0 +1,460 @@
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::ptr::{copy_nonoverlapping, null};
+
+use super::*;
+
+pub(super) static TABLE: VR_IVRSystem_FnTable = VR_IVRSystem_FnTable {
+    GetRecommendedRenderTargetSize: get_recommended_render_target_size,
+    GetProjectionMatrix: get_projection_matrix,
+    GetProjectionRaw: get_projection_raw,
+    ComputeDistortion: compute_distortion,
+    GetEyeToHeadTransform: get_eye_to_head_transform,
+    GetTimeSinceLastVsync: get_time_since_last_vsync,
+    GetD3D9AdapterIndex: get_d3d9_adapter_index,
+    GetDXGIOutputInfo: get_dxgi_output_info,
+    GetOutputDevice: get_output_device,
+    IsDisplayOnDesktop: is_display_on_desktop,
+    SetDisplayVisibility: set_display_visibility,
+    GetDeviceToAbsoluteTrackingPose: get_device_to_absolute_tracking_pose,
+    GetSeatedZeroPoseToStandingAbsoluteTrackingPose: get_seated_zero_pose_to_standing_absolute_tracking_pose,
+    GetRawZeroPoseToStandingAbsoluteTrackingPose: get_raw_zero_pose_to_standing_absolute_tracking_pose,
+    GetSortedTrackedDeviceIndicesOfClass: get_sorted_tracked_device_indices_of_class,
+    GetTrackedDeviceActivityLevel: get_tracked_device_activity_level,
+    ApplyTransform: apply_transform,
+    GetTrackedDeviceIndexForControllerRole: get_tracked_device_index_for_controller_role,
+    GetControllerRoleForTrackedDeviceIndex: get_controller_role_for_tracked_device_index,
+    GetTrackedDeviceClass: get_tracked_device_class,
+    IsTrackedDeviceConnected: is_tracked_device_connected,
+    GetBoolTrackedDeviceProperty: get_bool_tracked_device_property,
+    GetFloatTrackedDeviceProperty: get_float_tracked_device_property,
+    GetInt32TrackedDeviceProperty: get_int32_tracked_device_property,
+    GetUint64TrackedDeviceProperty: get_uint64_tracked_device_property,
+    GetMatrix34TrackedDeviceProperty: get_matrix34_tracked_device_property,
+    GetArrayTrackedDeviceProperty: get_array_tracked_device_property,
+    GetStringTrackedDeviceProperty: get_string_tracked_device_property,
+    GetPropErrorNameFromEnum: get_prop_error_name_from_enum,
+    PollNextEvent: poll_next_event,
+    PollNextEventWithPose: poll_next_event_with_pose,
+    GetEventTypeNameFromEnum: get_event_type_name_from_enum,
+    GetHiddenAreaMesh: get_hidden_area_mesh,
+    GetControllerState: get_controller_state,
+    GetControllerStateWithPose: get_controller_state_with_pose,
+    TriggerHapticPulse: trigger_haptic_pulse,
+    GetButtonIdNameFromEnum: get_button_id_name_from_enum,
+    GetControllerAxisTypeNameFromEnum: get_controller_axis_type_name_from_enum,
+    IsInputAvailable: is_input_available,
+    IsSteamVRDrawingControllers: is_steamvr_drawing_controllers,
+    ShouldApplicationPause: should_application_pause,
+    ShouldApplicationReduceRenderingWork: should_application_reduce_rendering_work,
+    PerformFirmwareUpdate: perform_firmware_update,
+    AcknowledgeQuit_Exiting: acknowledge_quit_exiting,
+    GetAppContainerFilePaths: get_app_container_file_paths,
+    GetRuntimeVersion: get_runtime_version,
+};
+
+unsafe extern "system" fn get_recommended_render_target_size(pnWidth: *mut u32, pnHeight: *mut u32) {
+    let (width, height) = state().render_target_size;
+    set(pnWidth, width);
+    set(pnHeight, height);
+}
+
+/** Same construction as the OpenVR samples use for a D3D-style projection from the raw tangents. */
+unsafe extern "system" fn get_projection_matrix(eEye: EVREye, fNearZ: f32, fFarZ: f32) -> HmdMatrix44 {
+    let fov = state().projection_raw[eEye as usize];
+    let idx = 1.0 / (fov.right - fov.left);
+    let idy = 1.0 / (fov.bottom - fov.top);
+    let idz = 1.0 / (fFarZ - fNearZ);
+
+    [
+        [2.0 * idx, 0.0, (fov.right + fov.left) * idx, 0.0],
+        [0.0, 2.0 * idy, (fov.bottom + fov.top) * idy, 0.0],
+        [0.0, 0.0, -fFarZ * idz, -fFarZ * fNearZ * idz],
+        [0.0, 0.0, -1.0, 0.0],
+    ]
+}
+
+unsafe extern "system" fn get_projection_raw(eEye: EVREye, pfLeft: *mut f32, pfRight: *mut f32, pfTop: *mut f32, pfBottom: *mut f32) {
+    let fov = state().projection_raw[eEye as usize];
+    set(pfLeft, fov.left);
+    set(pfRight, fov.right);
+    set(pfTop, fov.top);
+    set(pfBottom, fov.bottom);
+}
+
+/** The mock lens has no distortion: every channel samples where it lands. */
+unsafe extern "system" fn compute_distortion(_eEye: EVREye, fU: f32, fV: f32, pDistortionCoordinates: *mut DistortionCoordinates) -> bool {
+    set(pDistortionCoordinates, DistortionCoordinates { red: [fU, fV], green: [fU, fV], blue: [fU, fV] });
+    true
+}
+
+unsafe extern "system" fn get_eye_to_head_transform(eEye: EVREye) -> HmdMatrix34 {
+    state().eye_to_head[eEye as usize]
+}
+
+unsafe extern "system" fn get_time_since_last_vsync(pfSecondsSinceLastVsync: *mut f32, pulFrameCounter: *mut u64) -> bool {
+    set(pfSecondsSinceLastVsync, 0.0);
+    set(pulFrameCounter, state().frame_index as u64);
+    true
+}
+
+unsafe extern "system" fn get_d3d9_adapter_index() -> i32 {
+    -1
+}
+
+unsafe extern "system" fn get_dxgi_output_info(pnAdapterIndex: *mut i32) {
+    set(pnAdapterIndex, -1);
+}
+
+unsafe extern "system" fn get_output_device(pnDevice: *mut u64, _textureType: ETextureType, _pInstance: VkInstance) {
+    set(pnDevice, state().output_device);
+}
+
+unsafe extern "system" fn is_display_on_desktop() -> bool {
+    state().display_on_desktop
+}
+
+unsafe extern "system" fn set_display_visibility(bIsVisibleOnDesktop: bool) -> bool {
+    state().display_on_desktop = bIsVisibleOnDesktop;
+    true
+}
+
+unsafe extern "system" fn get_device_to_absolute_tracking_pose(
+    eOrigin: ETrackingUniverseOrigin,
+    _fPredictedSecondsToPhotonsFromNow: f32,
+    pTrackedDevicePoseArray: *mut TrackedDevicePose,
+    unTrackedDevicePoseArrayCount: u32,
+) {
+    state().fill_poses(eOrigin, pTrackedDevicePoseArray, unTrackedDevicePoseArrayCount);
+}
+
+unsafe extern "system" fn get_seated_zero_pose_to_standing_absolute_tracking_pose() -> HmdMatrix34 {
+    state().seated_zero_pose
+}
+
+unsafe extern "system" fn get_raw_zero_pose_to_standing_absolute_tracking_pose() -> HmdMatrix34 {
+    state().raw_zero_pose
+}
+
+/** Devices are returned in index order; the mock does not sort by position. */
+unsafe extern "system" fn get_sorted_tracked_device_indices_of_class(
+    eTrackedDeviceClass: ETrackedDeviceClass,
+    punTrackedDeviceIndexArray: *mut TrackedDeviceIndex,
+    unTrackedDeviceIndexArrayCount: u32,
+    _unRelativeToTrackedDeviceIndex: TrackedDeviceIndex,
+) -> u32 {
+    let state = state();
+    let indices: Vec<TrackedDeviceIndex> = (0..state.devices.len() as TrackedDeviceIndex)
+        .filter(|&index| state.devices[index as usize].class == eTrackedDeviceClass)
+        .collect();
+
+    if !punTrackedDeviceIndexArray.is_null() && unTrackedDeviceIndexArrayCount as usize >= indices.len() {
+        copy_nonoverlapping(indices.as_ptr(), punTrackedDeviceIndexArray, indices.len());
+    }
+
+    indices.len() as u32
+}
+
+unsafe extern "system" fn get_tracked_device_activity_level(unDeviceId: TrackedDeviceIndex) -> EDeviceActivityLevel {
+    state()
+        .device(unDeviceId)
+        .map(|device| device.activity_level)
+        .unwrap_or(EDeviceActivityLevel::k_EDeviceActivityLevel_Unknown)
+}
+
+unsafe extern "system" fn apply_transform(
+    pOutputPose: *mut TrackedDevicePose,
+    pTrackedDevicePose: *const TrackedDevicePose,
+    pTransform: *const HmdMatrix34,
+) {
+    let (pose, transform) = (*pTrackedDevicePose, *pTransform);
+
+    *pOutputPose = TrackedDevicePose {
+        device_to_absolute_tracking: multiply(&transform, &pose.device_to_absolute_tracking),
+        velocity: rotate(&transform, &pose.velocity),
+        angular_velocity: rotate(&transform, &pose.angular_velocity),
+        ..pose
+    };
+}
+
+unsafe extern "system" fn get_tracked_device_index_for_controller_role(unDeviceType: ETrackedControllerRole) -> TrackedDeviceIndex {
+    state()
+        .devices
+        .iter()
+        .position(|device| device.role == unDeviceType)
+        .map(|index| index as TrackedDeviceIndex)
+        .unwrap_or(k_unTrackedDeviceIndexInvalid)
+}
+
+unsafe extern "system" fn get_controller_role_for_tracked_device_index(unDeviceIndex: TrackedDeviceIndex) -> ETrackedControllerRole {
+    state()
+        .device(unDeviceIndex)
+        .map(|device| device.role)
+        .unwrap_or(ETrackedControllerRole::TrackedControllerRole_Invalid)
+}
+
+unsafe extern "system" fn get_tracked_device_class(unDeviceIndex: TrackedDeviceIndex) -> ETrackedDeviceClass {
+    state()
+        .device(unDeviceIndex)
+        .map(|device| device.class)
+        .unwrap_or(ETrackedDeviceClass::TrackedDeviceClass_Invalid)
+}
+
+unsafe extern "system" fn is_tracked_device_connected(unDeviceIndex: TrackedDeviceIndex) -> bool {
+    state().device(unDeviceIndex).is_some_and(|device| device.pose.device_is_connected)
+}
+
+/** Looks up a stored property and converts it with `read`, which returns None on a type mismatch. */
+unsafe fn property<T: Default>(
+    device: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    error: *mut ETrackedPropertyError,
+    read: impl FnOnce(&MockProperty) -> Option<T>,
+) -> T {
+    let state = state();
+    let (result, value) = match state.device(device) {
+        None => (ETrackedPropertyError::TrackedProp_InvalidDevice, T::default()),
+        Some(device) => match device.properties.get(&(prop as u32)) {
+            None => (ETrackedPropertyError::TrackedProp_UnknownProperty, T::default()),
+            Some(stored) => match read(stored) {
+                Some(value) => (ETrackedPropertyError::TrackedProp_Success, value),
+                None => (ETrackedPropertyError::TrackedProp_WrongDataType, T::default()),
+            },
+        },
+    };
+
+    set(error, result);
+    value
+}
+
+unsafe extern "system" fn get_bool_tracked_device_property(
+    unDeviceIndex: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    pError: *mut ETrackedPropertyError,
+) -> bool {
+    property(unDeviceIndex, prop, pError, |stored| match stored {
+        MockProperty::Bool(value) => Some(*value),
+        _ => None,
+    })
+}
+
+unsafe extern "system" fn get_float_tracked_device_property(
+    unDeviceIndex: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    pError: *mut ETrackedPropertyError,
+) -> f32 {
+    property(unDeviceIndex, prop, pError, |stored| match stored {
+        MockProperty::Float(value) => Some(*value),
+        _ => None,
+    })
+}
+
+unsafe extern "system" fn get_int32_tracked_device_property(
+    unDeviceIndex: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    pError: *mut ETrackedPropertyError,
+) -> i32 {
+    property(unDeviceIndex, prop, pError, |stored| match stored {
+        MockProperty::Int32(value) => Some(*value),
+        _ => None,
+    })
+}
+
+unsafe extern "system" fn get_uint64_tracked_device_property(
+    unDeviceIndex: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    pError: *mut ETrackedPropertyError,
+) -> u64 {
+    property(unDeviceIndex, prop, pError, |stored| match stored {
+        MockProperty::Uint64(value) => Some(*value),
+        _ => None,
+    })
+}
+
+unsafe extern "system" fn get_matrix34_tracked_device_property(
+    unDeviceIndex: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    pError: *mut ETrackedPropertyError,
+) -> HmdMatrix34 {
+    property(unDeviceIndex, prop, pError, |stored| match stored {
+        MockProperty::Matrix34(value) => Some(*value),
+        _ => None,
+    })
+}
+
+/** Returns the size the value needs, and fills the buffer only if it is large enough. */
+unsafe extern "system" fn get_array_tracked_device_property(
+    unDeviceIndex: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    propType: PropertyTypeTag,
+    pBuffer: *mut c_void,
+    unBufferSize: u32,
+    pError: *mut ETrackedPropertyError,
+) -> u32 {
+    let bytes = property(unDeviceIndex, prop, pError, |stored| match stored {
+        MockProperty::Array(tag, bytes) if *tag == propType => Some(bytes.clone()),
+        _ => None,
+    });
+
+    if (unBufferSize as usize) < bytes.len() {
+        set(pError, ETrackedPropertyError::TrackedProp_BufferTooSmall);
+    } else if !pBuffer.is_null() {
+        copy_nonoverlapping(bytes.as_ptr(), pBuffer as *mut u8, bytes.len());
+    }
+
+    bytes.len() as u32
+}
+
+unsafe extern "system" fn get_string_tracked_device_property(
+    unDeviceIndex: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    pchValue: *mut i8,
+    unBufferSize: u32,
+    pError: *mut ETrackedPropertyError,
+) -> u32 {
+    let value = property(unDeviceIndex, prop, pError, |stored| match stored {
+        MockProperty::String(value) => Some(Some(value.clone())),
+        _ => None,
+    });
+
+    let Some(value) = value else {
+        return 0;
+    };
+
+    let required = write_string(&value, pchValue, unBufferSize);
+    if unBufferSize < required {
+        set(pError, ETrackedPropertyError::TrackedProp_BufferTooSmall);
+    }
+    required
+}
+
+unsafe extern "system" fn get_prop_error_name_from_enum(error: ETrackedPropertyError) -> *const i8 {
+    static_name(error)
+}
+
+/** Moves the next queued event into `event`, and returns the device's pose if asked for one. */
+unsafe fn next_event(event: *mut VREvent, size: u32, origin: ETrackingUniverseOrigin, pose: *mut TrackedDevicePose) -> bool {
+    let mut state = state();
+    let Some(next) = state.events.pop_front() else {
+        return false;
+    };
+
+    if !event.is_null() {
+        let size = (size as usize).min(size_of::<VREvent>());
+        copy_nonoverlapping(&next as *const VREvent as *const u8, event as *mut u8, size);
+    }
+    set(pose, state.pose(next.tracked_device_index, origin));
+    true
+}
+
+unsafe extern "system" fn poll_next_event(pEvent: *mut VREvent, uncbVREvent: u32) -> bool {
+    next_event(pEvent, uncbVREvent, ETrackingUniverseOrigin::TrackingUniverseStanding, std::ptr::null_mut())
+}
+
+unsafe extern "system" fn poll_next_event_with_pose(
+    eOrigin: ETrackingUniverseOrigin,
+    pEvent: *mut VREvent,
+    uncbVREvent: u32,
+    pTrackedDevicePose: *mut TrackedDevicePose,
+) -> bool {
+    next_event(pEvent, uncbVREvent, eOrigin, pTrackedDevicePose)
+}
+
+unsafe extern "system" fn get_event_type_name_from_enum(eType: EVREventType) -> *const i8 {
+    static_name(eType)
+}
+
+/** The mock display has no hidden area. */
+unsafe extern "system" fn get_hidden_area_mesh(_Eye: EVREye, _type_: EHiddenAreaMeshType) -> HiddenAreaMesh {
+    HiddenAreaMesh { vertex_data: null(), triangle_count: 0 }
+}
+
+/** Copies the device's controller state and returns its pose if asked for one. False for unknown or disconnected devices. */
+unsafe fn controller_state(
+    device: TrackedDeviceIndex,
+    controller_state: *mut VRControllerState,
+    size: u32,
+    origin: ETrackingUniverseOrigin,
+    pose: *mut TrackedDevicePose,
+) -> bool {
+    let state = state();
+    let Some(stored) = state.device(device).filter(|device| device.pose.device_is_connected) else {
+        return false;
+    };
+
+    if !controller_state.is_null() {
+        let size = (size as usize).min(size_of::<VRControllerState>());
+        copy_nonoverlapping(&stored.controller_state as *const VRControllerState as *const u8, controller_state as *mut u8, size);
+    }
+    set(pose, state.pose(device, origin));
+    true
+}
+
+unsafe extern "system" fn get_controller_state(
+    unControllerDeviceIndex: TrackedDeviceIndex,
+    pControllerState: *mut VRControllerState,
+    unControllerStateSize: u32,
+) -> bool {
+    controller_state(
+        unControllerDeviceIndex,
+        pControllerState,
+        unControllerStateSize,
+        ETrackingUniverseOrigin::TrackingUniverseStanding,
+        std::ptr::null_mut(),
+    )
+}
+
+unsafe extern "system" fn get_controller_state_with_pose(
+    eOrigin: ETrackingUniverseOrigin,
+    unControllerDeviceIndex: TrackedDeviceIndex,
+    pControllerState: *mut VRControllerState,
+    unControllerStateSize: u32,
+    pTrackedDevicePose: *mut TrackedDevicePose,
+) -> bool {
+    controller_state(unControllerDeviceIndex, pControllerState, unControllerStateSize, eOrigin, pTrackedDevicePose)
+}
+
+unsafe extern "system" fn trigger_haptic_pulse(unControllerDeviceIndex: TrackedDeviceIndex, unAxisId: u32, usDurationMicroSec: u16) {
+    state().haptic_pulses.push((unControllerDeviceIndex, unAxisId, usDurationMicroSec));
+}
+
+unsafe extern "system" fn get_button_id_name_from_enum(eButtonId: EVRButtonId) -> *const i8 {
+    static_name(eButtonId)
+}
+
+unsafe extern "system" fn get_controller_axis_type_name_from_enum(eAxisType: EVRControllerAxisType) -> *const i8 {
+    static_name(eAxisType)
+}
+
+unsafe extern "system" fn is_input_available() -> bool {
+    state().input_available
+}
+
+unsafe extern "system" fn is_steamvr_drawing_controllers() -> bool {
+    state().steamvr_drawing_controllers
+}
+
+unsafe extern "system" fn should_application_pause() -> bool {
+    state().should_pause
+}
+
+unsafe extern "system" fn should_application_reduce_rendering_work() -> bool {
+    state().should_reduce_rendering_work
+}
+
+unsafe extern "system" fn perform_firmware_update(_unDeviceIndex: TrackedDeviceIndex) -> EVRFirmwareError {
+    EVRFirmwareError::VRFirmwareError_None
+}
+
+unsafe extern "system" fn acknowledge_quit_exiting() {
+    state().quit_acknowledged = true;
+}
+
+unsafe extern "system" fn get_app_container_file_paths(pchBuffer: *mut i8, unBufferSize: u32) -> u32 {
+    write_string(&state().app_container_file_paths.join(";"), pchBuffer, unBufferSize)
+}
+
+/** Points into MockState::runtime_version, so it stays valid until the mock is reset. */
+unsafe extern "system" fn get_runtime_version() -> *const i8 {
+    state().runtime_version.as_ptr()
+}
@@ -0,0 +1,227 @@
+use super::math::HmdMatrix34Ext;
+use super::public_vrtypes::*;
+use super::system::Fov;
+
+/** The clip space depth a projection maps the near and far planes to. */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthRange {
+    /** OpenGL: -1 at the near plane and 1 at the far plane. */
+    NegativeOneToOne,
+    /** D3D and Vulkan: 0 at the near plane and 1 at the far plane. This is what GetProjectionMatrix produces. */
+    ZeroToOne,
+}
+
+/** Builds the projection matrix for an eye from the tangents of GetProjectionRaw, for what GetProjectionMatrix
+* cannot do. The default, from new, is the matrix GetProjectionMatrix returns: right-handed, looking down -z,
+* with 0..1 depth. The tangents are used as GetProjectionMatrix uses them, so `top` ends up at clip space
+* y = -1 and `bottom` at y = 1. */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projection {
+    fov: Fov,
+    near_z: f32,
+    far_z: Option<f32>,
+    depth_range: DepthRange,
+    reverse_z: bool,
+    flip_y: bool,
+}
+
+impl Projection {
+    pub fn new(fov: Fov, near_z: f32, far_z: f32) -> Projection {
+        Projection {
+            fov,
+            near_z,
+            far_z: Some(far_z),
+            depth_range: DepthRange::ZeroToOne,
+            reverse_z: false,
+            flip_y: false,
+        }
+    }
+
+    pub fn depth_range(mut self, depth_range: DepthRange) -> Projection {
+        self.depth_range = depth_range;
+        self
+    }
+
+    /** Maps the near plane to the far end of the depth range and the far plane to the near end, which spreads
+    * float depth precision evenly over distance. The depth test has to be flipped to greater. */
+    pub fn reverse_z(mut self) -> Projection {
+        self.reverse_z = true;
+        self
+    }
+
+    /** Moves the far plane to infinity, so nothing is clipped for being far away. */
+    pub fn infinite_far(mut self) -> Projection {
+        self.far_z = None;
+        self
+    }
+
+    /** Negates clip space y, for Vulkan, where y points down. */
+    pub fn flip_y(mut self) -> Projection {
+        self.flip_y = true;
+        self
+    }
+
+    pub fn fov(&self) -> Fov {
+        self.fov
+    }
+
+    pub fn near_z(&self) -> f32 {
+        self.near_z
+    }
+
+    /** None for an infinite far plane. */
+    pub fn far_z(&self) -> Option<f32> {
+        self.far_z
+    }
+
+    /** The row-major projection matrix, for column vectors, like GetProjectionMatrix's. */
+    pub fn matrix(&self) -> HmdMatrix44 {
+        let Fov { left, right, top, bottom } = self.fov;
+        let idx = 1.0 / (right - left);
+        let idy = if self.flip_y { -1.0 } else { 1.0 } / (bottom - top);
+
+        let (near_depth, far_depth) = match self.depth_range {
+            DepthRange::NegativeOneToOne => (-1.0, 1.0),
+            DepthRange::ZeroToOne => (0.0, 1.0),
+        };
+        let (near_depth, far_depth) = if self.reverse_z { (far_depth, near_depth) } else { (near_depth, far_depth) };
+
+        // With w = -z, depth at distance d is -a + b / d; solve for near_depth at near_z and far_depth at far_z,
+        // or as d goes to infinity.
+        let near = self.near_z;
+        let (a, b) = match self.far_z {
+            Some(far) => {
+                let b = (near_depth - far_depth) * near * far / (far - near);
+                (b / near - near_depth, b)
+            }
+            None => (-far_depth, (near_depth - far_depth) * near),
+        };
+
+        [
+            [2.0 * idx, 0.0, (right + left) * idx, 0.0],
+            [0.0, 2.0 * idy, (bottom + top) * idy, 0.0],
+            [0.0, 0.0, a, b],
+            [0.0, 0.0, -1.0, 0.0],
+        ]
+    }
+}
+
+/** A single frustum in head space that contains both eyes' frustums, so a scene can be culled once for both.
+* Its apex sits behind the eyes, where the outer sides of the eye frustums meet, and it looks down the head's
+* -z like the eyes do. */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StereoFrustum {
+    /** The tangents of the combined frustum's sides, seen from the apex. */
+    pub fov: Fov,
+    /** The apex, in head space. */
+    pub apex: HmdVector3,
+    /** Distance from the apex to a near plane in front of both eyes' near planes. */
+    pub near_z: f32,
+    /** Distance from the apex to a far plane behind both eyes' far planes, or None if either is infinite. */
+    pub far_z: Option<f32>,
+}
+
+impl StereoFrustum {
+    /** Combines the eyes' projections, each with its eye to head transform from GetEyeToHeadTransform.
+    * Canted eyes are handled; the frustum is then a little larger than it needs to be. */
+    pub fn new(
+        left: &Projection,
+        left_eye_to_head: &HmdMatrix34,
+        right: &Projection,
+        right_eye_to_head: &HmdMatrix34,
+    ) -> StereoFrustum {
+        let eyes = [(left, left_eye_to_head), (right, right_eye_to_head)];
+
+        // Back from the midpoint between the eyes until the outer sides of the frustums meet.
+        let (left_eye, right_eye) = (left_eye_to_head.position(), right_eye_to_head.position());
+        let half_separation = 0.5 * (right_eye[0] - left_eye[0]).abs();
+        let outer = (-left.fov.left).min(right.fov.right).max(f32::EPSILON);
+        let back = half_separation / outer;
+        let apex = [
+            0.5 * (left_eye[0] + right_eye[0]),
+            0.5 * (left_eye[1] + right_eye[1]),
+            0.5 * (left_eye[2] + right_eye[2]) + back,
+        ];
+
+        // Each eye frustum is the hull of its near corners and, at the far end, its far corners or the directions
+        // to them. Seen from the apex, the tangents of those bound the tangents of everything in the frustum.
+        let mut fov = Fov { left: f32::MAX, right: f32::MIN, top: f32::MAX, bottom: f32::MIN };
+        let mut near_z = f32::MAX;
+        let mut far_z = Some(0.0f32);
+        let mut bound = |x: f32, y: f32, depth: f32| {
+            fov.left = fov.left.min(x / depth);
+            fov.right = fov.right.max(x / depth);
+            fov.top = fov.top.min(y / depth);
+            fov.bottom = fov.bottom.max(y / depth);
+        };
+        for (projection, eye_to_head) in eyes {
+            let Fov { left, right, top, bottom } = projection.fov;
+            for corner in [[left, top], [right, top], [left, bottom], [right, bottom]] {
+                let near = eye_to_head.transform_point(point_in_eye(corner, projection.near_z));
+                let near_depth = apex[2] - near[2];
+                bound(near[0] - apex[0], near[1] - apex[1], near_depth);
+                near_z = near_z.min(near_depth);
+
+                match (projection.far_z, far_z) {
+                    (Some(far), Some(furthest)) => {
+                        let far = eye_to_head.transform_point(point_in_eye(corner, far));
+                        let far_depth = apex[2] - far[2];
+                        bound(far[0] - apex[0], far[1] - apex[1], far_depth);
+                        far_z = Some(furthest.max(far_depth));
+                    }
+                    _ => {
+                        let direction = eye_to_head.transform_vector(point_in_eye(corner, 1.0));
+                        bound(direction[0], direction[1], -direction[2]);
+                        far_z = None;
+                    }
+                }
+            }
+        }
+
+        StereoFrustum { fov, apex, near_z, far_z }
+    }
+
+    /** A projection for the combined frustum, to be used with head_to_frustum. */
+    pub fn projection(&self) -> Projection {
+        Projection { far_z: self.far_z, ..Projection::new(self.fov, self.near_z, 0.0) }
+    }
+
+    /** The transform from head space to the frustum's space, where the apex is the origin. */
+    pub fn head_to_frustum(&self) -> HmdMatrix34 {
+        let [x, y, z] = self.apex;
+        [[1.0, 0.0, 0.0, -x], [0.0, 1.0, 0.0, -y], [0.0, 0.0, 1.0, -z]]
+    }
+
+    /** The left, right, top, bottom, near and far planes in head space, as [a, b, c, d] with a unit normal
+    * (a, b, c) pointing into the frustum, so a point p is inside every plane where a p.x + b p.y + c p.z + d >= 0.
+    * With an infinite far plane the last one is [0, 0, 0, 1], which everything is inside. */
+    pub fn planes(&self) -> [HmdVector4; 6] {
+        let Fov { left, right, top, bottom } = self.fov;
+        let plane = |normal: HmdVector3, distance: f32| {
+            let length = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+            let offset = normal[0] * self.apex[0] + normal[1] * self.apex[1] + normal[2] * self.apex[2];
+            [normal[0] / length, normal[1] / length, normal[2] / length, (distance - offset) / length]
+        };
+        [
+            plane([1.0, 0.0, left], 0.0),
+            plane([-1.0, 0.0, -right], 0.0),
+            plane([0.0, 1.0, top], 0.0),
+            plane([0.0, -1.0, -bottom], 0.0),
+            plane([0.0, 0.0, -1.0], -self.near_z),
+            match self.far_z {
+                Some(far) => plane([0.0, 0.0, 1.0], far),
+                None => [0.0, 0.0, 0.0, 1.0],
+            },
+        ]
+    }
+
+    /** Whether any part of a sphere in head space may be inside the frustum. */
+    pub fn intersects_sphere(&self, center: HmdVector3, radius: f32) -> bool {
+        self.planes().iter().all(|p| p[0] * center[0] + p[1] * center[1] + p[2] * center[2] + p[3] >= -radius)
+    }
+}
+
+/** The point at distance `depth` in front of the eye, along the tangents `corner`. */
+fn point_in_eye(corner: [f32; 2], depth: f32) -> HmdVector3 {
+    [corner[0] * depth, corner[1] * depth, -depth]
+}
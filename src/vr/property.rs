@@ -0,0 +1,456 @@
+use std::mem::size_of;
+use std::ptr::null_mut;
+
+use super::public_vrtypes::*;
+use super::system::System;
+use super::util::enum_try_from_u32;
+use super::vrtypes::*;
+use super::vrtypes::ETrackedDeviceProperty::*;
+
+/** The value type of a tracked device property, as encoded in the suffix of its ETrackedDeviceProperty name. */
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PropertyType {
+    pub tag: PropertyTypeTag,
+    pub array: bool,
+}
+
+impl ETrackedDeviceProperty {
+    /** The value type named by this property's suffix, or None for markers and binary properties. */
+    pub fn property_type(self) -> Option<PropertyType> {
+        match self {
+            Prop_WillDriftInYaw_Bool
+            | Prop_DeviceIsWireless_Bool
+            | Prop_DeviceIsCharging_Bool
+            | Prop_Firmware_UpdateAvailable_Bool
+            | Prop_Firmware_ManualUpdate_Bool
+            | Prop_BlockServerShutdown_Bool
+            | Prop_CanUnifyCoordinateSystemWithHmd_Bool
+            | Prop_ContainsProximitySensor_Bool
+            | Prop_DeviceProvidesBatteryStatus_Bool
+            | Prop_DeviceCanPowerOff_Bool
+            | Prop_HasCamera_Bool
+            | Prop_Firmware_ForceUpdateRequired_Bool
+            | Prop_ViveSystemButtonFixRequired_Bool
+            | Prop_NeverTracked_Bool
+            | Prop_Identifiable_Bool
+            | Prop_Firmware_RemindUpdate_Bool
+            | Prop_ReportsTimeSinceVSync_Bool
+            | Prop_IsOnDesktop_Bool
+            | Prop_DisplaySuppressed_Bool
+            | Prop_DisplayAllowNightMode_Bool
+            | Prop_DriverDirectModeSendsVsyncEvents_Bool
+            | Prop_DisplayDebugMode_Bool
+            | Prop_DoNotApplyPrediction_Bool
+            | Prop_DriverIsDrawingControllers_Bool
+            | Prop_DriverRequestsApplicationPause_Bool
+            | Prop_DriverRequestsReducedRendering_Bool
+            | Prop_ConfigurationIncludesLighthouse20Features_Bool
+            | Prop_DriverProvidedChaperoneVisibility_Bool
+            | Prop_CameraSupportsCompatibilityModes_Bool
+            | Prop_SupportsRoomViewDepthProjection_Bool
+            | Prop_DisplaySupportsMultipleFramerates_Bool
+            | Prop_DisplaySupportsRuntimeFramerateChange_Bool
+            | Prop_DisplaySupportsAnalogGain_Bool
+            | Prop_Hmd_SupportsHDCP14LegacyCompat_Bool
+            | Prop_Hmd_SupportsMicMonitoring_Bool
+            | Prop_Audio_SupportsDualSpeakerAndJackOutput_Bool
+            | Prop_CanWirelessIdentify_Bool
+            | Prop_HasDisplayComponent_Bool
+            | Prop_HasControllerComponent_Bool
+            | Prop_HasCameraComponent_Bool
+            | Prop_HasDriverDirectModeComponent_Bool
+            | Prop_HasVirtualDisplayComponent_Bool
+            | Prop_HasSpatialAnchorsSupport_Bool => Some(PropertyType { tag: k_unBoolPropertyTag, array: false }),
+            Prop_DeviceBatteryPercentage_Float
+            | Prop_SecondsFromVsyncToPhotons_Float
+            | Prop_DisplayFrequency_Float
+            | Prop_UserIpdMeters_Float
+            | Prop_DisplayMCOffset_Float
+            | Prop_DisplayMCScale_Float
+            | Prop_DisplayGCBlackClamp_Float
+            | Prop_DisplayGCOffset_Float
+            | Prop_DisplayGCScale_Float
+            | Prop_DisplayGCPrescale_Float
+            | Prop_LensCenterLeftU_Float
+            | Prop_LensCenterLeftV_Float
+            | Prop_LensCenterRightU_Float
+            | Prop_LensCenterRightV_Float
+            | Prop_UserHeadToEyeDepthMeters_Float
+            | Prop_ScreenshotHorizontalFieldOfViewDegrees_Float
+            | Prop_ScreenshotVerticalFieldOfViewDegrees_Float
+            | Prop_SecondsFromPhotonsToVblank_Float
+            | Prop_MinimumIpdStepMeters_Float
+            | Prop_DisplayMinAnalogGain_Float
+            | Prop_DisplayMaxAnalogGain_Float
+            | Prop_CameraExposureTime_Float
+            | Prop_CameraGlobalGain_Float
+            | Prop_DashboardScale_Float
+            | Prop_IpdUIRangeMinMeters_Float
+            | Prop_IpdUIRangeMaxMeters_Float
+            | Prop_Audio_DefaultPlaybackDeviceVolume_Float
+            | Prop_FieldOfViewLeftDegrees_Float
+            | Prop_FieldOfViewRightDegrees_Float
+            | Prop_FieldOfViewTopDegrees_Float
+            | Prop_FieldOfViewBottomDegrees_Float
+            | Prop_TrackingRangeMinimumMeters_Float
+            | Prop_TrackingRangeMaximumMeters_Float => Some(PropertyType { tag: k_unFloatPropertyTag, array: false }),
+            Prop_DeviceClass_Int32
+            | Prop_NumCameras_Int32
+            | Prop_CameraFrameLayout_Int32
+            | Prop_CameraStreamFormat_Int32
+            | Prop_EstimatedDeviceFirstUseTime_Int32
+            | Prop_DisplayMCType_Int32
+            | Prop_EdidVendorID_Int32
+            | Prop_EdidProductID_Int32
+            | Prop_DisplayGCType_Int32
+            | Prop_CameraCompatibilityMode_Int32
+            | Prop_DisplayMCImageWidth_Int32
+            | Prop_DisplayMCImageHeight_Int32
+            | Prop_DisplayMCImageNumChannels_Int32
+            | Prop_ExpectedTrackingReferenceCount_Int32
+            | Prop_ExpectedControllerCount_Int32
+            | Prop_DistortionMeshResolution_Int32
+            | Prop_HmdTrackingStyle_Int32
+            | Prop_DriverRequestedMuraCorrectionMode_Int32
+            | Prop_DriverRequestedMuraFeather_InnerLeft_Int32
+            | Prop_DriverRequestedMuraFeather_InnerRight_Int32
+            | Prop_DriverRequestedMuraFeather_InnerTop_Int32
+            | Prop_DriverRequestedMuraFeather_InnerBottom_Int32
+            | Prop_DriverRequestedMuraFeather_OuterLeft_Int32
+            | Prop_DriverRequestedMuraFeather_OuterRight_Int32
+            | Prop_DriverRequestedMuraFeather_OuterTop_Int32
+            | Prop_DriverRequestedMuraFeather_OuterBottom_Int32
+            | Prop_Axis0Type_Int32
+            | Prop_Axis1Type_Int32
+            | Prop_Axis2Type_Int32
+            | Prop_Axis3Type_Int32
+            | Prop_Axis4Type_Int32
+            | Prop_ControllerRoleHint_Int32
+            | Prop_Nonce_Int32
+            | Prop_ControllerHandSelectionPriority_Int32 => Some(PropertyType { tag: k_unInt32PropertyTag, array: false }),
+            Prop_HardwareRevision_Uint64
+            | Prop_FirmwareVersion_Uint64
+            | Prop_FPGAVersion_Uint64
+            | Prop_VRCVersion_Uint64
+            | Prop_RadioVersion_Uint64
+            | Prop_DongleVersion_Uint64
+            | Prop_ParentDriver_Uint64
+            | Prop_BootloaderVersion_Uint64
+            | Prop_PeripheralApplicationVersion_Uint64
+            | Prop_CurrentUniverseId_Uint64
+            | Prop_PreviousUniverseId_Uint64
+            | Prop_DisplayFirmwareVersion_Uint64
+            | Prop_CameraFirmwareVersion_Uint64
+            | Prop_DisplayFPGAVersion_Uint64
+            | Prop_DisplayBootloaderVersion_Uint64
+            | Prop_DisplayHardwareVersion_Uint64
+            | Prop_AudioFirmwareVersion_Uint64
+            | Prop_GraphicsAdapterLuid_Uint64
+            | Prop_AudioBridgeFirmwareVersion_Uint64
+            | Prop_ImageBridgeFirmwareVersion_Uint64
+            | Prop_AdditionalRadioFeatures_Uint64
+            | Prop_SupportedButtons_Uint64
+            | Prop_OverrideContainer_Uint64 => Some(PropertyType { tag: k_unUint64PropertyTag, array: false }),
+            Prop_TrackingSystemName_String
+            | Prop_ModelNumber_String
+            | Prop_SerialNumber_String
+            | Prop_RenderModelName_String
+            | Prop_ManufacturerName_String
+            | Prop_TrackingFirmwareVersion_String
+            | Prop_HardwareRevision_String
+            | Prop_AllWirelessDongleDescriptions_String
+            | Prop_ConnectedWirelessDongle_String
+            | Prop_Firmware_ManualUpdateURL_String
+            | Prop_Firmware_ProgrammingTarget_String
+            | Prop_DriverVersion_String
+            | Prop_ResourceRoot_String
+            | Prop_RegisteredDeviceType_String
+            | Prop_InputProfilePath_String
+            | Prop_AdditionalDeviceSettingsPath_String
+            | Prop_AdditionalSystemReportData_String
+            | Prop_CompositeFirmwareVersion_String
+            | Prop_ManufacturerSerialNumber_String
+            | Prop_ComputedSerialNumber_String
+            | Prop_DisplayMCImageLeft_String
+            | Prop_DisplayMCImageRight_String
+            | Prop_DisplayGCImage_String
+            | Prop_CameraFirmwareDescription_String
+            | Prop_DriverProvidedChaperonePath_String
+            | Prop_NamedIconPathControllerLeftDeviceOff_String
+            | Prop_NamedIconPathControllerRightDeviceOff_String
+            | Prop_NamedIconPathTrackingReferenceDeviceOff_String
+            | Prop_ExpectedControllerType_String
+            | Prop_HmdColumnCorrectionSettingPrefix_String
+            | Prop_Audio_DefaultPlaybackDeviceId_String
+            | Prop_Audio_DefaultRecordingDeviceId_String
+            | Prop_AttachedDeviceId_String
+            | Prop_ModeLabel_String
+            | Prop_IconPathName_String
+            | Prop_NamedIconPathDeviceOff_String
+            | Prop_NamedIconPathDeviceSearching_String
+            | Prop_NamedIconPathDeviceSearchingAlert_String
+            | Prop_NamedIconPathDeviceReady_String
+            | Prop_NamedIconPathDeviceReadyAlert_String
+            | Prop_NamedIconPathDeviceNotReady_String
+            | Prop_NamedIconPathDeviceStandby_String
+            | Prop_NamedIconPathDeviceAlertLow_String
+            | Prop_NamedIconPathDeviceStandbyAlert_String
+            | Prop_UserConfigPath_String
+            | Prop_InstallPath_String
+            | Prop_ControllerType_String => Some(PropertyType { tag: k_unStringPropertyTag, array: false }),
+            Prop_StatusDisplayTransform_Matrix34
+            | Prop_CameraToHeadTransform_Matrix34
+            | Prop_ImuToHeadTransform_Matrix34 => Some(PropertyType { tag: k_unHmdMatrix34PropertyTag, array: false }),
+            Prop_ImuFactoryGyroBias_Vector3
+            | Prop_ImuFactoryGyroScale_Vector3
+            | Prop_ImuFactoryAccelerometerBias_Vector3
+            | Prop_ImuFactoryAccelerometerScale_Vector3
+            | Prop_DisplayColorMultLeft_Vector3
+            | Prop_DisplayColorMultRight_Vector3 => Some(PropertyType { tag: k_unHmdVector3PropertyTag, array: false }),
+            Prop_CameraDistortionCoefficients_Float_Array
+            | Prop_DisplayAvailableFrameRates_Float_Array => Some(PropertyType { tag: k_unFloatPropertyTag, array: true }),
+            Prop_CameraDistortionFunction_Int32_Array => Some(PropertyType { tag: k_unInt32PropertyTag, array: true }),
+            Prop_CameraToHeadTransforms_Matrix34_Array => Some(PropertyType { tag: k_unHmdMatrix34PropertyTag, array: true }),
+            Prop_CameraWhiteBalance_Vector4_Array => Some(PropertyType { tag: k_unHmdVector4PropertyTag, array: true }),
+            _ => None,
+        }
+    }
+}
+
+enum_try_from_u32!(ETrackedDeviceProperty {
+    Prop_Invalid, Prop_TrackingSystemName_String, Prop_ModelNumber_String, Prop_SerialNumber_String,
+    Prop_RenderModelName_String, Prop_WillDriftInYaw_Bool, Prop_ManufacturerName_String,
+    Prop_TrackingFirmwareVersion_String, Prop_HardwareRevision_String,
+    Prop_AllWirelessDongleDescriptions_String, Prop_ConnectedWirelessDongle_String,
+    Prop_DeviceIsWireless_Bool, Prop_DeviceIsCharging_Bool, Prop_DeviceBatteryPercentage_Float,
+    Prop_StatusDisplayTransform_Matrix34, Prop_Firmware_UpdateAvailable_Bool, Prop_Firmware_ManualUpdate_Bool,
+    Prop_Firmware_ManualUpdateURL_String, Prop_HardwareRevision_Uint64, Prop_FirmwareVersion_Uint64,
+    Prop_FPGAVersion_Uint64, Prop_VRCVersion_Uint64, Prop_RadioVersion_Uint64, Prop_DongleVersion_Uint64,
+    Prop_BlockServerShutdown_Bool, Prop_CanUnifyCoordinateSystemWithHmd_Bool,
+    Prop_ContainsProximitySensor_Bool, Prop_DeviceProvidesBatteryStatus_Bool, Prop_DeviceCanPowerOff_Bool,
+    Prop_Firmware_ProgrammingTarget_String, Prop_DeviceClass_Int32, Prop_HasCamera_Bool,
+    Prop_DriverVersion_String, Prop_Firmware_ForceUpdateRequired_Bool, Prop_ViveSystemButtonFixRequired_Bool,
+    Prop_ParentDriver_Uint64, Prop_ResourceRoot_String, Prop_RegisteredDeviceType_String,
+    Prop_InputProfilePath_String, Prop_NeverTracked_Bool, Prop_NumCameras_Int32, Prop_CameraFrameLayout_Int32,
+    Prop_CameraStreamFormat_Int32, Prop_AdditionalDeviceSettingsPath_String, Prop_Identifiable_Bool,
+    Prop_BootloaderVersion_Uint64, Prop_AdditionalSystemReportData_String,
+    Prop_CompositeFirmwareVersion_String, Prop_Firmware_RemindUpdate_Bool,
+    Prop_PeripheralApplicationVersion_Uint64, Prop_ManufacturerSerialNumber_String,
+    Prop_ComputedSerialNumber_String, Prop_EstimatedDeviceFirstUseTime_Int32, Prop_ReportsTimeSinceVSync_Bool,
+    Prop_SecondsFromVsyncToPhotons_Float, Prop_DisplayFrequency_Float, Prop_UserIpdMeters_Float,
+    Prop_CurrentUniverseId_Uint64, Prop_PreviousUniverseId_Uint64, Prop_DisplayFirmwareVersion_Uint64,
+    Prop_IsOnDesktop_Bool, Prop_DisplayMCType_Int32, Prop_DisplayMCOffset_Float, Prop_DisplayMCScale_Float,
+    Prop_EdidVendorID_Int32, Prop_DisplayMCImageLeft_String, Prop_DisplayMCImageRight_String,
+    Prop_DisplayGCBlackClamp_Float, Prop_EdidProductID_Int32, Prop_CameraToHeadTransform_Matrix34,
+    Prop_DisplayGCType_Int32, Prop_DisplayGCOffset_Float, Prop_DisplayGCScale_Float,
+    Prop_DisplayGCPrescale_Float, Prop_DisplayGCImage_String, Prop_LensCenterLeftU_Float,
+    Prop_LensCenterLeftV_Float, Prop_LensCenterRightU_Float, Prop_LensCenterRightV_Float,
+    Prop_UserHeadToEyeDepthMeters_Float, Prop_CameraFirmwareVersion_Uint64,
+    Prop_CameraFirmwareDescription_String, Prop_DisplayFPGAVersion_Uint64,
+    Prop_DisplayBootloaderVersion_Uint64, Prop_DisplayHardwareVersion_Uint64,
+    Prop_AudioFirmwareVersion_Uint64, Prop_CameraCompatibilityMode_Int32,
+    Prop_ScreenshotHorizontalFieldOfViewDegrees_Float, Prop_ScreenshotVerticalFieldOfViewDegrees_Float,
+    Prop_DisplaySuppressed_Bool, Prop_DisplayAllowNightMode_Bool, Prop_DisplayMCImageWidth_Int32,
+    Prop_DisplayMCImageHeight_Int32, Prop_DisplayMCImageNumChannels_Int32, Prop_DisplayMCImageData_Binary,
+    Prop_SecondsFromPhotonsToVblank_Float, Prop_DriverDirectModeSendsVsyncEvents_Bool,
+    Prop_DisplayDebugMode_Bool, Prop_GraphicsAdapterLuid_Uint64, Prop_DriverProvidedChaperonePath_String,
+    Prop_ExpectedTrackingReferenceCount_Int32, Prop_ExpectedControllerCount_Int32,
+    Prop_NamedIconPathControllerLeftDeviceOff_String, Prop_NamedIconPathControllerRightDeviceOff_String,
+    Prop_NamedIconPathTrackingReferenceDeviceOff_String, Prop_DoNotApplyPrediction_Bool,
+    Prop_CameraToHeadTransforms_Matrix34_Array, Prop_DistortionMeshResolution_Int32,
+    Prop_DriverIsDrawingControllers_Bool, Prop_DriverRequestsApplicationPause_Bool,
+    Prop_DriverRequestsReducedRendering_Bool, Prop_MinimumIpdStepMeters_Float,
+    Prop_AudioBridgeFirmwareVersion_Uint64, Prop_ImageBridgeFirmwareVersion_Uint64,
+    Prop_ImuToHeadTransform_Matrix34, Prop_ImuFactoryGyroBias_Vector3, Prop_ImuFactoryGyroScale_Vector3,
+    Prop_ImuFactoryAccelerometerBias_Vector3, Prop_ImuFactoryAccelerometerScale_Vector3,
+    Prop_ConfigurationIncludesLighthouse20Features_Bool, Prop_AdditionalRadioFeatures_Uint64,
+    Prop_CameraWhiteBalance_Vector4_Array, Prop_CameraDistortionFunction_Int32_Array,
+    Prop_CameraDistortionCoefficients_Float_Array, Prop_ExpectedControllerType_String,
+    Prop_HmdTrackingStyle_Int32, Prop_DriverProvidedChaperoneVisibility_Bool,
+    Prop_HmdColumnCorrectionSettingPrefix_String, Prop_CameraSupportsCompatibilityModes_Bool,
+    Prop_SupportsRoomViewDepthProjection_Bool, Prop_DisplayAvailableFrameRates_Float_Array,
+    Prop_DisplaySupportsMultipleFramerates_Bool, Prop_DisplayColorMultLeft_Vector3,
+    Prop_DisplayColorMultRight_Vector3, Prop_DisplaySupportsRuntimeFramerateChange_Bool,
+    Prop_DisplaySupportsAnalogGain_Bool, Prop_DisplayMinAnalogGain_Float, Prop_DisplayMaxAnalogGain_Float,
+    Prop_CameraExposureTime_Float, Prop_CameraGlobalGain_Float, Prop_DashboardScale_Float,
+    Prop_IpdUIRangeMinMeters_Float, Prop_IpdUIRangeMaxMeters_Float, Prop_Hmd_SupportsHDCP14LegacyCompat_Bool,
+    Prop_Hmd_SupportsMicMonitoring_Bool, Prop_DriverRequestedMuraCorrectionMode_Int32,
+    Prop_DriverRequestedMuraFeather_InnerLeft_Int32, Prop_DriverRequestedMuraFeather_InnerRight_Int32,
+    Prop_DriverRequestedMuraFeather_InnerTop_Int32, Prop_DriverRequestedMuraFeather_InnerBottom_Int32,
+    Prop_DriverRequestedMuraFeather_OuterLeft_Int32, Prop_DriverRequestedMuraFeather_OuterRight_Int32,
+    Prop_DriverRequestedMuraFeather_OuterTop_Int32, Prop_DriverRequestedMuraFeather_OuterBottom_Int32,
+    Prop_Audio_DefaultPlaybackDeviceId_String, Prop_Audio_DefaultRecordingDeviceId_String,
+    Prop_Audio_DefaultPlaybackDeviceVolume_Float, Prop_Audio_SupportsDualSpeakerAndJackOutput_Bool,
+    Prop_AttachedDeviceId_String, Prop_SupportedButtons_Uint64, Prop_Axis0Type_Int32, Prop_Axis1Type_Int32,
+    Prop_Axis2Type_Int32, Prop_Axis3Type_Int32, Prop_Axis4Type_Int32, Prop_ControllerRoleHint_Int32,
+    Prop_FieldOfViewLeftDegrees_Float, Prop_FieldOfViewRightDegrees_Float, Prop_FieldOfViewTopDegrees_Float,
+    Prop_FieldOfViewBottomDegrees_Float, Prop_TrackingRangeMinimumMeters_Float,
+    Prop_TrackingRangeMaximumMeters_Float, Prop_ModeLabel_String, Prop_CanWirelessIdentify_Bool,
+    Prop_Nonce_Int32, Prop_IconPathName_String, Prop_NamedIconPathDeviceOff_String,
+    Prop_NamedIconPathDeviceSearching_String, Prop_NamedIconPathDeviceSearchingAlert_String,
+    Prop_NamedIconPathDeviceReady_String, Prop_NamedIconPathDeviceReadyAlert_String,
+    Prop_NamedIconPathDeviceNotReady_String, Prop_NamedIconPathDeviceStandby_String,
+    Prop_NamedIconPathDeviceAlertLow_String, Prop_NamedIconPathDeviceStandbyAlert_String,
+    Prop_DisplayHiddenArea_Binary_Start, Prop_DisplayHiddenArea_Binary_End, Prop_ParentContainer,
+    Prop_OverrideContainer_Uint64, Prop_UserConfigPath_String, Prop_InstallPath_String,
+    Prop_HasDisplayComponent_Bool, Prop_HasControllerComponent_Bool, Prop_HasCameraComponent_Bool,
+    Prop_HasDriverDirectModeComponent_Bool, Prop_HasVirtualDisplayComponent_Bool,
+    Prop_HasSpatialAnchorsSupport_Bool, Prop_ControllerType_String,
+    Prop_ControllerHandSelectionPriority_Int32, Prop_VendorSpecific_Reserved_Start,
+    Prop_VendorSpecific_Reserved_End, Prop_TrackedDeviceProperty_Max,
+});
+
+/** A Rust type that can be read with System::property. */
+pub trait TrackedPropertyValue: Sized {
+    /** The property type this Rust type corresponds to. */
+    const PROPERTY_TYPE: PropertyType;
+
+    /** Reads the property without checking its declared type. */
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError>;
+}
+
+impl TrackedPropertyValue for bool {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: k_unBoolPropertyTag, array: false };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        system.bool_tracked_device_property(device, prop)
+    }
+}
+
+impl TrackedPropertyValue for f32 {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: k_unFloatPropertyTag, array: false };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        system.float_tracked_device_property(device, prop)
+    }
+}
+
+impl TrackedPropertyValue for i32 {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: k_unInt32PropertyTag, array: false };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        system.int32_tracked_device_property(device, prop)
+    }
+}
+
+impl TrackedPropertyValue for u64 {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: k_unUint64PropertyTag, array: false };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        system.uint64_tracked_device_property(device, prop)
+    }
+}
+
+impl TrackedPropertyValue for String {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: k_unStringPropertyTag, array: false };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        system.string_tracked_device_property(device, prop)
+    }
+}
+
+impl TrackedPropertyValue for HmdMatrix34 {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: k_unHmdMatrix34PropertyTag, array: false };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        system.matrix34_tracked_device_property(device, prop)
+    }
+}
+
+impl TrackedPropertyValue for HmdVector3 {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: k_unHmdVector3PropertyTag, array: false };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        let values = read_array::<HmdVector3>(system, device, prop, k_unHmdVector3PropertyTag)?;
+        values.first().copied().ok_or(ETrackedPropertyError::TrackedProp_ValueNotProvidedByDevice)
+    }
+}
+
+/** Element types that can be read as a Vec through GetArrayTrackedDeviceProperty. */
+pub trait PropertyArrayElement: Copy + Default {
+    const TAG: PropertyTypeTag;
+}
+
+impl PropertyArrayElement for f32 {
+    const TAG: PropertyTypeTag = k_unFloatPropertyTag;
+}
+
+impl PropertyArrayElement for i32 {
+    const TAG: PropertyTypeTag = k_unInt32PropertyTag;
+}
+
+impl PropertyArrayElement for u64 {
+    const TAG: PropertyTypeTag = k_unUint64PropertyTag;
+}
+
+impl PropertyArrayElement for HmdMatrix34 {
+    const TAG: PropertyTypeTag = k_unHmdMatrix34PropertyTag;
+}
+
+impl PropertyArrayElement for HmdMatrix44 {
+    const TAG: PropertyTypeTag = k_unHmdMatrix44PropertyTag;
+}
+
+impl PropertyArrayElement for HmdVector2 {
+    const TAG: PropertyTypeTag = k_unHmdVector2PropertyTag;
+}
+
+impl PropertyArrayElement for HmdVector3 {
+    const TAG: PropertyTypeTag = k_unHmdVector3PropertyTag;
+}
+
+impl PropertyArrayElement for HmdVector4 {
+    const TAG: PropertyTypeTag = k_unHmdVector4PropertyTag;
+}
+
+impl<T: PropertyArrayElement> TrackedPropertyValue for Vec<T> {
+    const PROPERTY_TYPE: PropertyType = PropertyType { tag: T::TAG, array: true };
+
+    fn read(system: &System, device: TrackedDeviceIndex, prop: ETrackedDeviceProperty) -> Result<Self, ETrackedPropertyError> {
+        read_array::<T>(system, device, prop, T::TAG)
+    }
+}
+
+/** Reads an array property, growing the buffer to the byte size the runtime reports. */
+fn read_array<T: PropertyArrayElement>(
+    system: &System,
+    device: TrackedDeviceIndex,
+    prop: ETrackedDeviceProperty,
+    tag: PropertyTypeTag,
+) -> Result<Vec<T>, ETrackedPropertyError> {
+    let get_array = system.fn_table().GetArrayTrackedDeviceProperty;
+    let mut values: Vec<T> = Vec::new();
+
+    loop {
+        let mut error = ETrackedPropertyError::TrackedProp_Success;
+        let buffer = if values.is_empty() { null_mut() } else { values.as_mut_ptr() as *mut _ };
+        let byte_size = (values.len() * size_of::<T>()) as u32;
+        let required = unsafe { get_array(device, prop, tag, buffer, byte_size, &mut error) } as usize;
+
+        match error {
+            ETrackedPropertyError::TrackedProp_Success => {
+                values.truncate(required / size_of::<T>());
+                return Ok(values);
+            }
+            ETrackedPropertyError::TrackedProp_BufferTooSmall if required > values.len() * size_of::<T>() => {
+                values = vec![T::default(); required.div_ceil(size_of::<T>())];
+            }
+            error => return Err(error),
+        }
+    }
+}
+
+impl<'a> System<'a> {
+    /** Reads a tracked device property as T. Returns TrackedProp_WrongDataType without calling the runtime
+    * if T does not match the type named by the property's suffix. */
+    pub fn property<T: TrackedPropertyValue>(
+        &self,
+        device: TrackedDeviceIndex,
+        prop: ETrackedDeviceProperty,
+    ) -> Result<T, ETrackedPropertyError> {
+        match prop.property_type() {
+            Some(property_type) if property_type != T::PROPERTY_TYPE => Err(ETrackedPropertyError::TrackedProp_WrongDataType),
+            _ => T::read(self, device, prop),
+        }
+    }
+}
@@ -7,6 +7,9 @@ pub type HmdVector3 = [f32; 3];
 pub type HmdVector4 = [f32; 4];
 pub type HmdVector3d = [f64; 3];
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct HmdQuaternion {
   pub w: f64,
   pub x: f64,
@@ -14,6 +17,8 @@ pub struct HmdQuaternion {
   pub z: f64,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
 pub struct HmdQuaternionf {
   pub w: f32,
   pub x: f32,
@@ -21,6 +26,8 @@ pub struct HmdQuaternionf {
   pub z: f32,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
 pub struct HmdColor {
   pub r: f32,
   pub g: f32,
@@ -30,11 +37,15 @@ pub struct HmdColor {
 
 pub type HmdQuad = [HmdVector3; 4];
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
 pub struct HmdRect2 {
   pub top_left: HmdVector2,
   pub bottom_right: HmdVector2,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
 pub struct VRBoneTransform {
   pub position: HmdVector4,
   pub orientation: HmdQuaternionf,
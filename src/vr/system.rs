@@ -0,0 +1,485 @@
+use std::mem::{size_of, MaybeUninit};
+use std::slice;
+
+use super::ivrsystem::*;
+use super::projection::Projection;
+use super::public_vrtypes::*;
+use super::util::{read_string, string_from_ptr};
+use super::vrtypes::*;
+
+/** Tangents of the half-angles of an eye's view frustum, as returned by GetProjectionRaw.
+* Left and top are usually negative. */
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fov {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+/** Safe access to IVRSystem. Obtained from Context::system and valid until the Context is dropped. */
+#[derive(Clone, Copy)]
+pub struct System<'a> {
+    table: &'a VR_IVRSystem_FnTable,
+}
+
+impl<'a> System<'a> {
+    pub(crate) fn new(table: &'a VR_IVRSystem_FnTable) -> System<'a> {
+        System { table }
+    }
+
+    /** The underlying function table, for entry points this wrapper does not cover. */
+    pub fn fn_table(&self) -> &'a VR_IVRSystem_FnTable {
+        self.table
+    }
+
+    // ------------------------------------
+    // Display Methods
+    // ------------------------------------
+
+    /** Suggested (width, height) for the intermediate render target that the distortion pulls from. */
+    pub fn recommended_render_target_size(&self) -> (u32, u32) {
+        let mut width = 0;
+        let mut height = 0;
+        unsafe { (self.table.GetRecommendedRenderTargetSize)(&mut width, &mut height) };
+        (width, height)
+    }
+
+    /** The projection matrix for the specified eye */
+    pub fn projection_matrix(&self, eye: EVREye, near_z: f32, far_z: f32) -> HmdMatrix44 {
+        unsafe { (self.table.GetProjectionMatrix)(eye, near_z, far_z) }
+    }
+
+    /** The components necessary to build your own projection matrix */
+    pub fn projection_raw(&self, eye: EVREye) -> Fov {
+        let mut fov = Fov { left: 0.0, right: 0.0, top: 0.0, bottom: 0.0 };
+        unsafe {
+            (self.table.GetProjectionRaw)(eye, &mut fov.left, &mut fov.right, &mut fov.top, &mut fov.bottom)
+        };
+        fov
+    }
+
+    /** A projection builder for the specified eye, from GetProjectionRaw. Unchanged, it builds the same
+    * matrix as projection_matrix. */
+    pub fn projection(&self, eye: EVREye, near_z: f32, far_z: f32) -> Projection {
+        Projection::new(self.projection_raw(eye), near_z, far_z)
+    }
+
+    /** The result of the distortion function for the specified eye and input UVs, or None if the
+    * runtime could not compute it. */
+    pub fn compute_distortion(&self, eye: EVREye, u: f32, v: f32) -> Option<DistortionCoordinates> {
+        let mut coordinates = DistortionCoordinates { red: [0.0; 2], green: [0.0; 2], blue: [0.0; 2] };
+        if unsafe { (self.table.ComputeDistortion)(eye, u, v, &mut coordinates) } {
+            Some(coordinates)
+        } else {
+            None
+        }
+    }
+
+    /** The transform from eye space to head space */
+    pub fn eye_to_head_transform(&self, eye: EVREye) -> HmdMatrix34 {
+        unsafe { (self.table.GetEyeToHeadTransform)(eye) }
+    }
+
+    /** Seconds since the last vsync and the frame counter, or None if no vsync times are available. */
+    pub fn time_since_last_vsync(&self) -> Option<(f32, u64)> {
+        let mut seconds = 0.0;
+        let mut frame_counter = 0;
+        if unsafe { (self.table.GetTimeSinceLastVsync)(&mut seconds, &mut frame_counter) } {
+            Some((seconds, frame_counter))
+        } else {
+            None
+        }
+    }
+
+    /** [D3D9 Only] Adapter index to pass to CreateDevice, or -1 on error. */
+    pub fn d3d9_adapter_index(&self) -> i32 {
+        unsafe { (self.table.GetD3D9AdapterIndex)() }
+    }
+
+    /** [D3D10/11 Only] Adapter index to pass to EnumAdapters, or -1 on error. */
+    pub fn dxgi_output_info(&self) -> i32 {
+        let mut adapter_index = -1;
+        unsafe { (self.table.GetDXGIOutputInfo)(&mut adapter_index) };
+        adapter_index
+    }
+
+    /** Platform and texture-type specific adapter identification, or 0 on error.
+    *
+    * # Safety
+    * For TextureType_Vulkan the instance must be a live VkInstance; otherwise it is ignored and may be null. */
+    pub unsafe fn output_device(&self, texture_type: ETextureType, instance: VkInstance) -> u64 {
+        let mut device = 0;
+        (self.table.GetOutputDevice)(&mut device, texture_type, instance);
+        device
+    }
+
+    /** The VkPhysicalDevice the HMD is attached to, or None if the runtime does not know it.
+    *
+    * # Safety
+    * The instance must be a live VkInstance created with the extensions from
+    * Compositor::vulkan_instance_extensions_required enabled. */
+    pub unsafe fn vulkan_output_device(&self, instance: VkInstance) -> Option<VkPhysicalDevice> {
+        match self.output_device(ETextureType::TextureType_Vulkan, instance) {
+            0 => None,
+            device => Some(device as usize as VkPhysicalDevice),
+        }
+    }
+
+    // ------------------------------------
+    // Display Mode methods
+    // ------------------------------------
+
+    /** Whether the headset display is part of the desktop (extended) rather than hidden (direct mode). */
+    pub fn is_display_on_desktop(&self) -> bool {
+        unsafe { (self.table.IsDisplayOnDesktop)() }
+    }
+
+    /** Sets the display visibility (true = extended, false = direct mode). Returns true if the change succeeded. */
+    pub fn set_display_visibility(&self, visible_on_desktop: bool) -> bool {
+        unsafe { (self.table.SetDisplayVisibility)(visible_on_desktop) }
+    }
+
+    // ------------------------------------
+    // Tracking Methods
+    // ------------------------------------
+
+    /** Poses of every tracked device slot, predicted the given number of seconds into the future. */
+    pub fn device_to_absolute_tracking_pose(
+        &self,
+        origin: ETrackingUniverseOrigin,
+        predicted_seconds_to_photons_from_now: f32,
+    ) -> [TrackedDevicePose; k_unMaxTrackedDeviceCount as usize] {
+        let mut poses = MaybeUninit::<[TrackedDevicePose; k_unMaxTrackedDeviceCount as usize]>::uninit();
+        unsafe {
+            (self.table.GetDeviceToAbsoluteTrackingPose)(
+                origin,
+                predicted_seconds_to_photons_from_now,
+                poses.as_mut_ptr() as *mut TrackedDevicePose,
+                k_unMaxTrackedDeviceCount,
+            );
+            poses.assume_init()
+        }
+    }
+
+    /** The transform from the seated zero pose to the standing absolute tracking system. */
+    pub fn seated_zero_pose_to_standing_absolute_tracking_pose(&self) -> HmdMatrix34 {
+        unsafe { (self.table.GetSeatedZeroPoseToStandingAbsoluteTrackingPose)() }
+    }
+
+    /** The transform from the tracking origin to the standing absolute tracking system. */
+    pub fn raw_zero_pose_to_standing_absolute_tracking_pose(&self) -> HmdMatrix34 {
+        unsafe { (self.table.GetRawZeroPoseToStandingAbsoluteTrackingPose)() }
+    }
+
+    /** Device indices of a given class, sorted right to left relative to the given device
+    * (k_unTrackedDeviceIndexInvalid for absolute tracking space). */
+    pub fn sorted_tracked_device_indices_of_class(
+        &self,
+        class: ETrackedDeviceClass,
+        relative_to: TrackedDeviceIndex,
+    ) -> Vec<TrackedDeviceIndex> {
+        let mut indices = vec![0; k_unMaxTrackedDeviceCount as usize];
+        let count = unsafe {
+            (self.table.GetSortedTrackedDeviceIndicesOfClass)(
+                class,
+                indices.as_mut_ptr(),
+                indices.len() as u32,
+                relative_to,
+            )
+        };
+        indices.truncate(count as usize);
+        indices
+    }
+
+    /** The level of activity on the device. */
+    pub fn tracked_device_activity_level(&self, device: TrackedDeviceIndex) -> EDeviceActivityLevel {
+        unsafe { (self.table.GetTrackedDeviceActivityLevel)(device) }
+    }
+
+    /** Applies a transform to every component of a pose, including velocity and angular velocity. */
+    pub fn apply_transform(&self, pose: &TrackedDevicePose, transform: &HmdMatrix34) -> TrackedDevicePose {
+        let mut output = MaybeUninit::<TrackedDevicePose>::uninit();
+        unsafe {
+            (self.table.ApplyTransform)(output.as_mut_ptr(), pose, transform);
+            output.assume_init()
+        }
+    }
+
+    /** The device index associated with a controller role, if any device has that role. */
+    pub fn tracked_device_index_for_controller_role(&self, role: ETrackedControllerRole) -> Option<TrackedDeviceIndex> {
+        let index = unsafe { (self.table.GetTrackedDeviceIndexForControllerRole)(role) };
+        if index == k_unTrackedDeviceIndexInvalid {
+            None
+        } else {
+            Some(index)
+        }
+    }
+
+    /** The controller role associated with a device index. */
+    pub fn controller_role_for_tracked_device_index(&self, device: TrackedDeviceIndex) -> ETrackedControllerRole {
+        unsafe { (self.table.GetControllerRoleForTrackedDeviceIndex)(device) }
+    }
+
+    // ------------------------------------
+    // Property methods
+    // ------------------------------------
+
+    /** The device class of a tracked device slot. */
+    pub fn tracked_device_class(&self, device: TrackedDeviceIndex) -> ETrackedDeviceClass {
+        unsafe { (self.table.GetTrackedDeviceClass)(device) }
+    }
+
+    /** Whether there is a device connected in this slot. */
+    pub fn is_tracked_device_connected(&self, device: TrackedDeviceIndex) -> bool {
+        unsafe { (self.table.IsTrackedDeviceConnected)(device) }
+    }
+
+    pub fn bool_tracked_device_property(
+        &self,
+        device: TrackedDeviceIndex,
+        prop: ETrackedDeviceProperty,
+    ) -> Result<bool, ETrackedPropertyError> {
+        let mut error = ETrackedPropertyError::TrackedProp_Success;
+        let value = unsafe { (self.table.GetBoolTrackedDeviceProperty)(device, prop, &mut error) };
+        property_result(value, error)
+    }
+
+    pub fn float_tracked_device_property(
+        &self,
+        device: TrackedDeviceIndex,
+        prop: ETrackedDeviceProperty,
+    ) -> Result<f32, ETrackedPropertyError> {
+        let mut error = ETrackedPropertyError::TrackedProp_Success;
+        let value = unsafe { (self.table.GetFloatTrackedDeviceProperty)(device, prop, &mut error) };
+        property_result(value, error)
+    }
+
+    pub fn int32_tracked_device_property(
+        &self,
+        device: TrackedDeviceIndex,
+        prop: ETrackedDeviceProperty,
+    ) -> Result<i32, ETrackedPropertyError> {
+        let mut error = ETrackedPropertyError::TrackedProp_Success;
+        let value = unsafe { (self.table.GetInt32TrackedDeviceProperty)(device, prop, &mut error) };
+        property_result(value, error)
+    }
+
+    pub fn uint64_tracked_device_property(
+        &self,
+        device: TrackedDeviceIndex,
+        prop: ETrackedDeviceProperty,
+    ) -> Result<u64, ETrackedPropertyError> {
+        let mut error = ETrackedPropertyError::TrackedProp_Success;
+        let value = unsafe { (self.table.GetUint64TrackedDeviceProperty)(device, prop, &mut error) };
+        property_result(value, error)
+    }
+
+    pub fn matrix34_tracked_device_property(
+        &self,
+        device: TrackedDeviceIndex,
+        prop: ETrackedDeviceProperty,
+    ) -> Result<HmdMatrix34, ETrackedPropertyError> {
+        let mut error = ETrackedPropertyError::TrackedProp_Success;
+        let value = unsafe { (self.table.GetMatrix34TrackedDeviceProperty)(device, prop, &mut error) };
+        property_result(value, error)
+    }
+
+    /** Reads a string property, growing the buffer to the size the runtime reports. */
+    pub fn string_tracked_device_property(
+        &self,
+        device: TrackedDeviceIndex,
+        prop: ETrackedDeviceProperty,
+    ) -> Result<String, ETrackedPropertyError> {
+        let mut error = ETrackedPropertyError::TrackedProp_Success;
+        let value = read_string(|buffer, size| {
+            error = ETrackedPropertyError::TrackedProp_Success;
+            unsafe { (self.table.GetStringTrackedDeviceProperty)(device, prop, buffer, size, &mut error) }
+        });
+        property_result(value, error)
+    }
+
+    /** The name of a property error enum value. */
+    pub fn prop_error_name_from_enum(&self, error: ETrackedPropertyError) -> String {
+        unsafe { string_from_ptr((self.table.GetPropErrorNameFromEnum)(error)) }
+    }
+
+    // ------------------------------------
+    // Event methods
+    // ------------------------------------
+
+    /** The next event on the queue, if there is one. */
+    pub fn poll_next_event(&self) -> Option<VREvent> {
+        let mut event = MaybeUninit::<VREvent>::uninit();
+        unsafe {
+            if (self.table.PollNextEvent)(event.as_mut_ptr(), size_of::<VREvent>() as u32) {
+                Some(event.assume_init())
+            } else {
+                None
+            }
+        }
+    }
+
+    /** The next event on the queue along with the pose of its tracked device when the event happened. */
+    pub fn poll_next_event_with_pose(&self, origin: ETrackingUniverseOrigin) -> Option<(VREvent, TrackedDevicePose)> {
+        let mut event = MaybeUninit::<VREvent>::uninit();
+        let mut pose = MaybeUninit::<TrackedDevicePose>::uninit();
+        unsafe {
+            if (self.table.PollNextEventWithPose)(
+                origin,
+                event.as_mut_ptr(),
+                size_of::<VREvent>() as u32,
+                pose.as_mut_ptr(),
+            ) {
+                Some((event.assume_init(), pose.assume_init()))
+            } else {
+                None
+            }
+        }
+    }
+
+    /** The name of an EVREventType enum value. */
+    pub fn event_type_name_from_enum(&self, event_type: EVREventType) -> String {
+        unsafe { string_from_ptr((self.table.GetEventTypeNameFromEnum)(event_type)) }
+    }
+
+    // ------------------------------------
+    // Rendering helper methods
+    // ------------------------------------
+
+    /** Vertices of the hidden area mesh for an eye. Standard and inverse meshes are triangle lists,
+    * line loop meshes are one vertex per point. Empty if the HMD has no hidden area mesh. */
+    pub fn hidden_area_mesh(&self, eye: EVREye, mesh_type: EHiddenAreaMeshType) -> &'a [HmdVector2] {
+        let line_loop = mesh_type == EHiddenAreaMeshType::k_eHiddenAreaMesh_LineLoop;
+        let mesh = unsafe { (self.table.GetHiddenAreaMesh)(eye, mesh_type) };
+
+        if mesh.vertex_data.is_null() || mesh.triangle_count == 0 {
+            return &[];
+        }
+
+        let vertex_count = if line_loop { mesh.triangle_count } else { mesh.triangle_count * 3 };
+        unsafe { slice::from_raw_parts(mesh.vertex_data, vertex_count as usize) }
+    }
+
+    // ------------------------------------
+    // Controller methods
+    // ------------------------------------
+
+    /** The current state of a controller, or None if the index is invalid. */
+    pub fn controller_state(&self, device: TrackedDeviceIndex) -> Option<VRControllerState> {
+        let mut state = MaybeUninit::<VRControllerState>::uninit();
+        unsafe {
+            if (self.table.GetControllerState)(device, state.as_mut_ptr(), size_of::<VRControllerState>() as u32) {
+                Some(state.assume_init())
+            } else {
+                None
+            }
+        }
+    }
+
+    /** The current state of a controller and its pose when that state was last updated. */
+    pub fn controller_state_with_pose(
+        &self,
+        origin: ETrackingUniverseOrigin,
+        device: TrackedDeviceIndex,
+    ) -> Option<(VRControllerState, TrackedDevicePose)> {
+        let mut state = MaybeUninit::<VRControllerState>::uninit();
+        let mut pose = MaybeUninit::<TrackedDevicePose>::uninit();
+        unsafe {
+            if (self.table.GetControllerStateWithPose)(
+                origin,
+                device,
+                state.as_mut_ptr(),
+                size_of::<VRControllerState>() as u32,
+                pose.as_mut_ptr(),
+            ) {
+                Some((state.assume_init(), pose.assume_init()))
+            } else {
+                None
+            }
+        }
+    }
+
+    /** Triggers a single haptic pulse on a controller. */
+    pub fn trigger_haptic_pulse(&self, device: TrackedDeviceIndex, axis_id: u32, duration_micro_sec: u16) {
+        unsafe { (self.table.TriggerHapticPulse)(device, axis_id, duration_micro_sec) }
+    }
+
+    /** The name of an EVRButtonId enum value. */
+    pub fn button_id_name_from_enum(&self, button: EVRButtonId) -> String {
+        unsafe { string_from_ptr((self.table.GetButtonIdNameFromEnum)(button)) }
+    }
+
+    /** The name of an EVRControllerAxisType enum value. */
+    pub fn controller_axis_type_name_from_enum(&self, axis_type: EVRControllerAxisType) -> String {
+        unsafe { string_from_ptr((self.table.GetControllerAxisTypeNameFromEnum)(axis_type)) }
+    }
+
+    /** Whether this application is receiving input from the system. */
+    pub fn is_input_available(&self) -> bool {
+        unsafe { (self.table.IsInputAvailable)() }
+    }
+
+    /** Whether SteamVR is drawing controllers on top of the application. */
+    pub fn is_steamvr_drawing_controllers(&self) -> bool {
+        unsafe { (self.table.IsSteamVRDrawingControllers)() }
+    }
+
+    /** Whether the user has put SteamVR into a mode that is distracting them from the application. */
+    pub fn should_application_pause(&self) -> bool {
+        unsafe { (self.table.ShouldApplicationPause)() }
+    }
+
+    /** Whether SteamVR is doing significant rendering work and the application should reduce its own. */
+    pub fn should_application_reduce_rendering_work(&self) -> bool {
+        unsafe { (self.table.ShouldApplicationReduceRenderingWork)() }
+    }
+
+    // ------------------------------------
+    // Firmware methods
+    // ------------------------------------
+
+    /** Performs the actual firmware update if applicable. */
+    pub fn perform_firmware_update(&self, device: TrackedDeviceIndex) -> EVRFirmwareError {
+        unsafe { (self.table.PerformFirmwareUpdate)(device) }
+    }
+
+    // ------------------------------------
+    // Application life cycle methods
+    // ------------------------------------
+
+    /** Acknowledges that VREvent_Quit has been received and the process is exiting. */
+    pub fn acknowledge_quit_exiting(&self) {
+        unsafe { (self.table.AcknowledgeQuit_Exiting)() }
+    }
+
+    // -------------------------------------
+    // App container sandbox methods
+    // -------------------------------------
+
+    /** Paths an application must have read access to when running inside of an app container. */
+    pub fn app_container_file_paths(&self) -> Vec<String> {
+        read_string(|buffer, size| unsafe { (self.table.GetAppContainerFilePaths)(buffer, size) })
+            .split(';')
+            .filter(|path| !path.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    // -------------------------------------
+    // System methods
+    // -------------------------------------
+
+    /** The current version of the SteamVR runtime, for logging or showing to a user. */
+    pub fn runtime_version(&self) -> String {
+        unsafe { string_from_ptr((self.table.GetRuntimeVersion)()) }
+    }
+}
+
+fn property_result<T>(value: T, error: ETrackedPropertyError) -> Result<T, ETrackedPropertyError> {
+    match error {
+        ETrackedPropertyError::TrackedProp_Success => Ok(value),
+        error => Err(error),
+    }
+}
+
@@ -0,0 +1,88 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+use super::vrtypes::*;
+
+/** A Texture together with the submit flags its handle needs, built by the Texture constructors below.
+* It borrows the struct the handle points to for Vulkan and D3D12, so that struct outlives the submit. */
+#[derive(Clone, Copy, Debug)]
+pub struct SubmitTexture<'a> {
+    texture: Texture,
+    flags: EVRSubmitFlags,
+    data: PhantomData<&'a c_void>,
+}
+
+impl<'a> SubmitTexture<'a> {
+    fn new(handle: *mut c_void, texture_type: ETextureType, color_space: EColorSpace, flags: EVRSubmitFlags) -> SubmitTexture<'a> {
+        SubmitTexture { texture: Texture { handle, texture_type, color_space }, flags, data: PhantomData }
+    }
+
+    /** The texture passed to Submit. */
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /** The EVRSubmitFlags the handle requires, ORed into the flags given to Submit. */
+    pub fn flags(&self) -> EVRSubmitFlags {
+        self.flags
+    }
+}
+
+/** A raw Texture submitted as is. Any flags its handle needs have to be passed to submit. */
+impl From<Texture> for SubmitTexture<'static> {
+    fn from(texture: Texture) -> SubmitTexture<'static> {
+        SubmitTexture { texture, flags: EVRSubmitFlags::Submit_Default, data: PhantomData }
+    }
+}
+
+impl Texture {
+    /** An OpenGL texture, by name. */
+    pub fn gl(name: GLUint, color_space: EColorSpace) -> SubmitTexture<'static> {
+        SubmitTexture::new(name as usize as *mut c_void, ETextureType::TextureType_OpenGL, color_space, EVRSubmitFlags::Submit_Default)
+    }
+
+    /** An OpenGL render buffer, for example an MSAA target, by name. */
+    pub fn gl_render_buffer(name: GLUint, color_space: EColorSpace) -> SubmitTexture<'static> {
+        SubmitTexture::new(
+            name as usize as *mut c_void,
+            ETextureType::TextureType_OpenGL,
+            color_space,
+            EVRSubmitFlags::Submit_GlRenderBuffer,
+        )
+    }
+
+    /** An OpenGL array texture, by name. Layer 0 is the left eye and layer 1 the right eye. */
+    pub fn gl_array(name: GLUint, color_space: EColorSpace) -> SubmitTexture<'static> {
+        SubmitTexture::new(
+            name as usize as *mut c_void,
+            ETextureType::TextureType_OpenGL,
+            color_space,
+            EVRSubmitFlags::Submit_GlArrayTexture,
+        )
+    }
+
+    /** A Vulkan image. */
+    pub fn vulkan(data: &VRVulkanTextureData, color_space: EColorSpace) -> SubmitTexture<'_> {
+        SubmitTexture::new(data as *const VRVulkanTextureData as *mut c_void, ETextureType::TextureType_Vulkan, color_space, EVRSubmitFlags::Submit_Default)
+    }
+
+    /** One layer of a Vulkan array image. */
+    pub fn vulkan_array(data: &VRVulkanTextureArrayData, color_space: EColorSpace) -> SubmitTexture<'_> {
+        SubmitTexture::new(
+            data as *const VRVulkanTextureArrayData as *mut c_void,
+            ETextureType::TextureType_Vulkan,
+            color_space,
+            EVRSubmitFlags::Submit_VulkanTextureWithArrayData,
+        )
+    }
+
+    /** A D3D11 texture, from its ID3D11Texture2D pointer. */
+    pub fn directx(texture: *mut c_void, color_space: EColorSpace) -> SubmitTexture<'static> {
+        SubmitTexture::new(texture, ETextureType::TextureType_DirectX, color_space, EVRSubmitFlags::Submit_Default)
+    }
+
+    /** A D3D12 resource and the queue it is used on. */
+    pub fn directx12(data: &D3D12TextureData, color_space: EColorSpace) -> SubmitTexture<'_> {
+        SubmitTexture::new(data as *const D3D12TextureData as *mut c_void, ETextureType::TextureType_DirectX12, color_space, EVRSubmitFlags::Submit_Default)
+    }
+}
@@ -0,0 +1,276 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use super::vrtypes::*;
+
+/** Errors from writing or reading a frame timing log */
+#[derive(Debug)]
+pub enum TimingLogError {
+    Io(io::Error),
+    Csv(csv::Error),
+    /** A JSON Lines record could not be written or parsed. The line number is 1-based, and 0 when writing. */
+    Json(usize, serde_json::Error),
+}
+
+impl fmt::Display for TimingLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimingLogError::Io(error) => write!(f, "frame timing log I/O failed: {}", error),
+            TimingLogError::Csv(error) => write!(f, "invalid frame timing CSV: {}", error),
+            TimingLogError::Json(0, error) => write!(f, "could not write frame timing JSON: {}", error),
+            TimingLogError::Json(line, error) => write!(f, "invalid frame timing JSON on line {}: {}", line, error),
+        }
+    }
+}
+
+impl Error for TimingLogError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TimingLogError::Io(error) => Some(error),
+            TimingLogError::Csv(error) => Some(error),
+            TimingLogError::Json(_, error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for TimingLogError {
+    fn from(error: io::Error) -> TimingLogError {
+        TimingLogError::Io(error)
+    }
+}
+
+impl From<csv::Error> for TimingLogError {
+    fn from(error: csv::Error) -> TimingLogError {
+        TimingLogError::Csv(error)
+    }
+}
+
+/** One CSV row. CSV has no nesting, so the HMD pose is spread over its own columns and the
+* reprojection flags are written as their raw u32. */
+#[derive(Serialize, Deserialize)]
+struct CsvRow {
+    size: u32,
+    frame_index: u32,
+    num_frame_presents: u32,
+    num_mis_presented: u32,
+    num_dropped_frames: u32,
+    reprojection_flags: u32,
+    system_time_in_seconds: f64,
+    pre_submit_gpu_ms: f32,
+    post_submit_gpu_ms: f32,
+    total_render_gpu_ms: f32,
+    compositor_render_gpu_ms: f32,
+    compositor_render_cpu_ms: f32,
+    compositor_idle_cpu_ms: f32,
+    client_frame_interval_ms: f32,
+    present_call_cpu_ms: f32,
+    wait_for_present_cpu_ms: f32,
+    submit_frame_ms: f32,
+    wait_get_poses_called_ms: f32,
+    new_poses_ready_ms: f32,
+    new_frame_ready_ms: f32,
+    compositor_update_start_ms: f32,
+    compositor_update_end_ms: f32,
+    compositor_render_start_ms: f32,
+    hmd_m00: f32,
+    hmd_m01: f32,
+    hmd_m02: f32,
+    hmd_m03: f32,
+    hmd_m10: f32,
+    hmd_m11: f32,
+    hmd_m12: f32,
+    hmd_m13: f32,
+    hmd_m20: f32,
+    hmd_m21: f32,
+    hmd_m22: f32,
+    hmd_m23: f32,
+    hmd_velocity_x: f32,
+    hmd_velocity_y: f32,
+    hmd_velocity_z: f32,
+    hmd_angular_velocity_x: f32,
+    hmd_angular_velocity_y: f32,
+    hmd_angular_velocity_z: f32,
+    hmd_tracking_result: ETrackingResult,
+    hmd_pose_is_valid: bool,
+    hmd_device_is_connected: bool,
+    num_vsyncs_ready_for_use: u32,
+    num_vsyncs_to_first_view: u32,
+}
+
+impl From<&Compositor_FrameTiming> for CsvRow {
+    fn from(timing: &Compositor_FrameTiming) -> CsvRow {
+        let pose = timing.hmd_pose;
+        let [[hmd_m00, hmd_m01, hmd_m02, hmd_m03], [hmd_m10, hmd_m11, hmd_m12, hmd_m13], [hmd_m20, hmd_m21, hmd_m22, hmd_m23]] =
+            pose.device_to_absolute_tracking;
+        let [hmd_velocity_x, hmd_velocity_y, hmd_velocity_z] = pose.velocity;
+        let [hmd_angular_velocity_x, hmd_angular_velocity_y, hmd_angular_velocity_z] = pose.angular_velocity;
+
+        CsvRow {
+            size: timing.size,
+            frame_index: timing.frame_index,
+            num_frame_presents: timing.num_frame_presents,
+            num_mis_presented: timing.num_mis_presented,
+            num_dropped_frames: timing.num_dropped_frames,
+            reprojection_flags: timing.reprojection_flags.bits(),
+            system_time_in_seconds: timing.system_time_in_seconds,
+            pre_submit_gpu_ms: timing.pre_submit_gpu_ms,
+            post_submit_gpu_ms: timing.post_submit_gpu_ms,
+            total_render_gpu_ms: timing.total_render_gpu_ms,
+            compositor_render_gpu_ms: timing.compositor_render_gpu_ms,
+            compositor_render_cpu_ms: timing.compositor_render_cpu_ms,
+            compositor_idle_cpu_ms: timing.compositor_idle_cpu_ms,
+            client_frame_interval_ms: timing.client_frame_interval_ms,
+            present_call_cpu_ms: timing.present_call_cpu_ms,
+            wait_for_present_cpu_ms: timing.wait_for_present_cpu_ms,
+            submit_frame_ms: timing.submit_frame_ms,
+            wait_get_poses_called_ms: timing.wait_get_poses_called_ms,
+            new_poses_ready_ms: timing.new_poses_ready_ms,
+            new_frame_ready_ms: timing.new_frame_ready_ms,
+            compositor_update_start_ms: timing.compositor_update_start_ms,
+            compositor_update_end_ms: timing.compositor_update_end_ms,
+            compositor_render_start_ms: timing.compositor_render_start_ms,
+            hmd_m00,
+            hmd_m01,
+            hmd_m02,
+            hmd_m03,
+            hmd_m10,
+            hmd_m11,
+            hmd_m12,
+            hmd_m13,
+            hmd_m20,
+            hmd_m21,
+            hmd_m22,
+            hmd_m23,
+            hmd_velocity_x,
+            hmd_velocity_y,
+            hmd_velocity_z,
+            hmd_angular_velocity_x,
+            hmd_angular_velocity_y,
+            hmd_angular_velocity_z,
+            hmd_tracking_result: pose.tracking_result,
+            hmd_pose_is_valid: pose.pose_is_valid,
+            hmd_device_is_connected: pose.device_is_connected,
+            num_vsyncs_ready_for_use: timing.num_vsyncs_ready_for_use,
+            num_vsyncs_to_first_view: timing.num_vsyncs_to_first_view,
+        }
+    }
+}
+
+impl From<CsvRow> for Compositor_FrameTiming {
+    fn from(row: CsvRow) -> Compositor_FrameTiming {
+        Compositor_FrameTiming {
+            size: row.size,
+            frame_index: row.frame_index,
+            num_frame_presents: row.num_frame_presents,
+            num_mis_presented: row.num_mis_presented,
+            num_dropped_frames: row.num_dropped_frames,
+            reprojection_flags: row.reprojection_flags.into(),
+            system_time_in_seconds: row.system_time_in_seconds,
+            pre_submit_gpu_ms: row.pre_submit_gpu_ms,
+            post_submit_gpu_ms: row.post_submit_gpu_ms,
+            total_render_gpu_ms: row.total_render_gpu_ms,
+            compositor_render_gpu_ms: row.compositor_render_gpu_ms,
+            compositor_render_cpu_ms: row.compositor_render_cpu_ms,
+            compositor_idle_cpu_ms: row.compositor_idle_cpu_ms,
+            client_frame_interval_ms: row.client_frame_interval_ms,
+            present_call_cpu_ms: row.present_call_cpu_ms,
+            wait_for_present_cpu_ms: row.wait_for_present_cpu_ms,
+            submit_frame_ms: row.submit_frame_ms,
+            wait_get_poses_called_ms: row.wait_get_poses_called_ms,
+            new_poses_ready_ms: row.new_poses_ready_ms,
+            new_frame_ready_ms: row.new_frame_ready_ms,
+            compositor_update_start_ms: row.compositor_update_start_ms,
+            compositor_update_end_ms: row.compositor_update_end_ms,
+            compositor_render_start_ms: row.compositor_render_start_ms,
+            hmd_pose: TrackedDevicePose {
+                device_to_absolute_tracking: [
+                    [row.hmd_m00, row.hmd_m01, row.hmd_m02, row.hmd_m03],
+                    [row.hmd_m10, row.hmd_m11, row.hmd_m12, row.hmd_m13],
+                    [row.hmd_m20, row.hmd_m21, row.hmd_m22, row.hmd_m23],
+                ],
+                velocity: [row.hmd_velocity_x, row.hmd_velocity_y, row.hmd_velocity_z],
+                angular_velocity: [row.hmd_angular_velocity_x, row.hmd_angular_velocity_y, row.hmd_angular_velocity_z],
+                tracking_result: row.hmd_tracking_result,
+                pose_is_valid: row.hmd_pose_is_valid,
+                device_is_connected: row.hmd_device_is_connected,
+            },
+            num_vsyncs_ready_for_use: row.num_vsyncs_ready_for_use,
+            num_vsyncs_to_first_view: row.num_vsyncs_to_first_view,
+        }
+    }
+}
+
+enum Output<W: Write> {
+    Csv(Box<csv::Writer<W>>),
+    JsonLines(W),
+}
+
+/** Streams frame timings to a file as they are recorded, one row or line per frame.
+* Call flush before the process exits, or drop the writer, so buffered rows are not lost. */
+pub struct FrameTimingWriter<W: Write> {
+    output: Output<W>,
+}
+
+impl<W: Write> FrameTimingWriter<W> {
+    /** Writes CSV with a header row. */
+    pub fn csv(writer: W) -> FrameTimingWriter<W> {
+        FrameTimingWriter { output: Output::Csv(Box::new(csv::Writer::from_writer(writer))) }
+    }
+
+    /** Writes one JSON object per line. */
+    pub fn json_lines(writer: W) -> FrameTimingWriter<W> {
+        FrameTimingWriter { output: Output::JsonLines(writer) }
+    }
+
+    pub fn write(&mut self, timing: &Compositor_FrameTiming) -> Result<(), TimingLogError> {
+        match &mut self.output {
+            Output::Csv(writer) => writer.serialize(CsvRow::from(timing))?,
+            Output::JsonLines(writer) => {
+                serde_json::to_writer(&mut *writer, timing).map_err(|error| TimingLogError::Json(0, error))?;
+                writer.write_all(b"\n")?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_all<'t, I>(&mut self, timings: I) -> Result<(), TimingLogError>
+    where
+        I: IntoIterator<Item = &'t Compositor_FrameTiming>,
+    {
+        timings.into_iter().try_for_each(|timing| self.write(timing))
+    }
+
+    pub fn flush(&mut self) -> Result<(), TimingLogError> {
+        match &mut self.output {
+            Output::Csv(writer) => writer.flush()?,
+            Output::JsonLines(writer) => writer.flush()?,
+        }
+        Ok(())
+    }
+}
+
+/** Reads the frame timings a FrameTimingWriter::csv wrote. */
+pub fn read_csv<R: Read>(reader: R) -> Result<Vec<Compositor_FrameTiming>, TimingLogError> {
+    csv::Reader::from_reader(reader)
+        .deserialize::<CsvRow>()
+        .map(|row| Ok(Compositor_FrameTiming::from(row?)))
+        .collect()
+}
+
+/** Reads the frame timings a FrameTimingWriter::json_lines wrote. Blank lines are skipped. */
+pub fn read_json_lines<R: BufRead>(reader: R) -> Result<Vec<Compositor_FrameTiming>, TimingLogError> {
+    let mut timings = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        timings.push(serde_json::from_str(&line).map_err(|error| TimingLogError::Json(index + 1, error))?);
+    }
+
+    Ok(timings)
+}
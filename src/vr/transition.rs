@@ -0,0 +1,84 @@
+use std::marker::PhantomData;
+
+use super::compositor::Compositor;
+use super::ivrcompositor::EVRCompositorError;
+use super::public_vrtypes::HmdColor;
+use super::vrtypes::*;
+
+/** A fade out of the scene, for level loads and other times the app cannot render. Started with
+* Compositor::scene_transition, it can also bring up the grid and a skybox. Finishing or dropping it
+* fades back to the fade color the transition started from, shows or hides the grid as it was then, and
+* puts the default skybox back. Skybox textures are borrowed for 't, so they
+* outlive the transition that shows them. */
+pub struct SceneTransition<'a, 't> {
+    compositor: Compositor<'a>,
+    seconds: f32,
+    previous_color: HmdColor,
+    previous_grid_alpha: f32,
+    grid: bool,
+    skybox: bool,
+    faces: PhantomData<&'t [Texture]>,
+}
+
+impl<'a, 't> SceneTransition<'a, 't> {
+    pub(crate) fn new(compositor: Compositor<'a>, color: HmdColor, seconds: f32) -> SceneTransition<'a, 't> {
+        let previous_color = compositor.current_fade_color(false);
+        let previous_grid_alpha = compositor.current_grid_alpha();
+        compositor.fade_to_color(seconds, color, false);
+        SceneTransition {
+            compositor,
+            seconds,
+            previous_color,
+            previous_grid_alpha,
+            grid: false,
+            skybox: false,
+            faces: PhantomData,
+        }
+    }
+
+    /** Also fades in the compositor grid, over the transition's duration. */
+    pub fn fade_grid(&mut self) {
+        self.compositor.fade_grid(self.seconds, true);
+        self.grid = true;
+    }
+
+    /** Shows a skybox for the rest of the transition; see Compositor::set_skybox_override for the face rules.
+    *
+    * # Safety
+    * The textures must follow the contract of Compositor::set_skybox_override, and the objects they name must
+    * stay alive until the transition ends. */
+    pub unsafe fn set_skybox(&mut self, faces: &'t [Texture]) -> Result<(), EVRCompositorError> {
+        self.compositor.set_skybox_override(faces)?;
+        self.skybox = true;
+        Ok(())
+    }
+
+    /** The fade color as it currently is. */
+    pub fn fade_color(&self) -> HmdColor {
+        self.compositor.current_fade_color(false)
+    }
+
+    /** The grid's current alpha. */
+    pub fn grid_alpha(&self) -> f32 {
+        self.compositor.current_grid_alpha()
+    }
+
+    /** Ends the transition, fading back to the scene over `seconds`. The fade back itself happens on drop. */
+    pub fn finish(mut self, seconds: f32) {
+        self.seconds = seconds;
+    }
+}
+
+/** Fades back over the transition's duration, unless finish gave another one. The grid can only be faded all
+* the way in or out, so it comes back shown if any of it was showing when the transition started. */
+impl Drop for SceneTransition<'_, '_> {
+    fn drop(&mut self) {
+        if self.skybox {
+            self.compositor.clear_skybox_override();
+        }
+        if self.grid {
+            self.compositor.fade_grid(self.seconds, self.previous_grid_alpha > 0.0);
+        }
+        self.compositor.fade_to_color(self.seconds, self.previous_color, false);
+    }
+}
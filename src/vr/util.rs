@@ -0,0 +1,78 @@
+use std::ffi::CStr;
+use std::ptr::null_mut;
+
+/** Copies a runtime-owned, null-terminated string. Null pointers become an empty string. */
+pub(crate) unsafe fn string_from_ptr(ptr: *const i8) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+
+    CStr::from_ptr(ptr).to_string_lossy().into_owned()
+}
+
+/** Reads a string from an entry point that takes (buffer, buffer size) and returns the size it needs,
+* including the trailing null. The buffer is grown and the call repeated until the string fits. */
+pub(crate) fn read_string<F>(mut read: F) -> String
+where
+    F: FnMut(*mut i8, u32) -> u32,
+{
+    let mut buffer: Vec<u8> = Vec::new();
+
+    loop {
+        let ptr = if buffer.is_empty() { null_mut() } else { buffer.as_mut_ptr() as *mut i8 };
+        let required = read(ptr, buffer.len() as u32) as usize;
+
+        if required == 0 {
+            return String::new();
+        }
+
+        if required <= buffer.len() {
+            let len = buffer.iter().position(|&c| c == 0).unwrap_or(required);
+            buffer.truncate(len);
+            return String::from_utf8_lossy(&buffer).into_owned();
+        }
+
+        buffer = vec![0; required];
+    }
+}
+
+/** Implements a checked TryFrom<u32> for a C enum from the list of its variants.
+* Values the list does not name come back as the Err value instead of being transmuted. */
+macro_rules! enum_try_from_u32 {
+    ($enum:ident { $($variant:ident),* $(,)? }) => {
+        impl TryFrom<u32> for $enum {
+            type Error = u32;
+
+            fn try_from(value: u32) -> Result<$enum, u32> {
+                $(
+                    if value == $enum::$variant as u32 {
+                        return Ok($enum::$variant);
+                    }
+                )*
+                Err(value)
+            }
+        }
+    };
+}
+
+pub(crate) use enum_try_from_u32;
+
+/** Implements the conversions between a bitflags type and the raw u32 used at the FFI boundary.
+* Bits the type does not name are kept, so a value passes through unchanged. */
+macro_rules! flags_from_u32 {
+    ($flags:ident) => {
+        impl From<u32> for $flags {
+            fn from(bits: u32) -> $flags {
+                $flags::from_bits_retain(bits)
+            }
+        }
+
+        impl From<$flags> for u32 {
+            fn from(flags: $flags) -> u32 {
+                flags.bits()
+            }
+        }
+    };
+}
+
+pub(crate) use flags_from_u32;
@@ -1,16 +1,44 @@
 use super::public_vrtypes::*;
 use std::ffi::c_void;
+use std::fmt;
 
-pub struct VkDevice;
-pub struct VkPhysicalDevice;
-pub struct VkInstance;
-pub struct VkQueue;
+use bitflags::bitflags;
+
+use super::util::flags_from_u32;
+
+/* Vulkan handles, declared opaque the way openvr.h forward-declares them so no Vulkan binding is needed.
+* Handles from ash or another binding can be cast to these, as they are the same pointers. */
+#[repr(C)]
+pub struct VkDevice_T {
+    _private: [u8; 0],
+}
+#[repr(C)]
+pub struct VkPhysicalDevice_T {
+    _private: [u8; 0],
+}
+#[repr(C)]
+pub struct VkInstance_T {
+    _private: [u8; 0],
+}
+#[repr(C)]
+pub struct VkQueue_T {
+    _private: [u8; 0],
+}
+
+pub type VkDevice = *mut VkDevice_T;
+pub type VkPhysicalDevice = *mut VkPhysicalDevice_T;
+pub type VkInstance = *mut VkInstance_T;
+pub type VkQueue = *mut VkQueue_T;
+
+/* openvr.h packs its structs to 4 bytes on Linux and macOS and to 8 bytes elsewhere. Structs with
+* 8-byte members carry repr(packed(4)) on those targets so their layout matches the runtime's. */
 
 pub type SpatialAnchorHandle = u32;
 pub type GLSharedTextureHandle = *mut c_void;
 pub type GLInt = i32;
 pub type GLUint = u32;
 
+#[repr(C)]
 pub struct DistortionCoordinates {
     pub red: [f32; 2],
     pub green: [f32; 2],
@@ -18,7 +46,7 @@ pub struct DistortionCoordinates {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVREye {
     Eye_Left = 0,
@@ -26,7 +54,7 @@ pub enum EVREye {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum ETextureType {
     TextureType_Invalid = -1,         // Handle has been invalidated
@@ -43,7 +71,7 @@ pub enum ETextureType {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EColorSpace {
     ColorSpace_Auto = 0, // Assumes 'gamma' for 8-bit per component formats, otherwise 'linear'.  This mirrors the DXGI formats which have _SRGB variants.
@@ -51,6 +79,9 @@ pub enum EColorSpace {
     ColorSpace_Linear = 2, // Same as gamma but has been converted to a linear representation using DXGI's sRGB conversion algorithm.
 }
 
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct Texture {
     pub handle: *mut c_void,
     pub texture_type: ETextureType,
@@ -61,7 +92,8 @@ pub type SharedTextureHandle = u64;
 pub const INVALID_SHARED_TEXTURE_HANDLE: SharedTextureHandle = 0;
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub enum ETrackingResult {
     TrackingResult_Uninitialized = 1,
@@ -93,7 +125,7 @@ pub const k_unTrackedDeviceIndexOther: u32 = 0xFFFFFFFE;
 pub const k_unTrackedDeviceIndexInvalid: u32 = 0xFFFFFFFF;
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum ETrackedDeviceClass {
     TrackedDeviceClass_Invalid = 0,           // the ID was not valid.
@@ -107,7 +139,7 @@ pub enum ETrackedDeviceClass {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum ETrackedControllerRole {
     TrackedControllerRole_Invalid = 0, // Invalid value for controller type
@@ -127,6 +159,9 @@ pub fn is_role_allowed_as_hand(role: ETrackedControllerRole) -> bool {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct TrackedDevicePose {
     pub device_to_absolute_tracking: HmdMatrix34,
     pub velocity: HmdVector3,         // velocity in tracker space in m/s
@@ -142,7 +177,7 @@ pub struct TrackedDevicePose {
 /** Identifies which style of tracking origin the application wants to use
 * for the poses it is requesting */
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum ETrackingUniverseOrigin {
     TrackingUniverseSeated = 0, // Poses are provided relative to the seated zero pose
@@ -232,7 +267,7 @@ pub const k_unOpenVRInternalReserved_End: PropertyTypeTag = 10000;
 /** Each entry in this enum represents a property that can be retrieved about a
 * tracked device. Many fields are only valid for one ETrackedDeviceClass. */
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum ETrackedDeviceProperty {
     Prop_Invalid = 0,
@@ -468,7 +503,7 @@ pub enum ETrackedDeviceProperty {
 pub const k_unMaxPropertyStringSize: u32 = 32 * 1024;
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug)]
 #[repr(C)]
 pub enum ETrackedPropertyError {
     TrackedProp_Success = 0,
@@ -511,6 +546,8 @@ pub const k_ulInvalidActionSetHandle: VRActionSetHandle = 0;
 #[allow(non_upper_case_globals)]
 pub const k_ulInvalidInputValueHandle: VRInputValueHandle = 0;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
 pub struct VRTextureBounds {
     pub u_min: f32,
     pub v_min: f32,
@@ -518,6 +555,8 @@ pub struct VRTextureBounds {
     pub v_max: f32,
 }
 
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VRTextureWithPose {
     pub handle: *mut c_void,
     pub texture_type: ETextureType,
@@ -525,12 +564,16 @@ pub struct VRTextureWithPose {
     pub device_to_absolute_tracking: HmdMatrix34,
 }
 
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VRTextureDepthInfo {
     pub handle: *mut c_void, // See ETextureType definition above
     pub projection: HmdMatrix44,
     pub range: HmdVector2, // 0..1
 }
 
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VRTextureWithPoseAndDepth {
     pub handle: *mut c_void,
     pub texture_type: ETextureType,
@@ -539,8 +582,42 @@ pub struct VRTextureWithPoseAndDepth {
     pub depth: VRTextureDepthInfo,
 }
 
-/* TODO: some vulkan features */
-/* TODO: some D3D12 features */
+/** Texture handle for TextureType_Vulkan. Texture::handle points to this struct. */
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
+pub struct VRVulkanTextureData {
+    pub image: u64, // VkImage
+    pub device: VkDevice,
+    pub physical_device: VkPhysicalDevice,
+    pub instance: VkInstance,
+    pub queue: VkQueue,
+    pub queue_family_index: u32,
+    pub width: u32,
+    pub height: u32,
+    pub format: u32, // VkFormat
+    pub sample_count: u32,
+}
+
+/** Texture handle for a layer of a Vulkan array image. Submit with Submit_VulkanTextureWithArrayData. */
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
+pub struct VRVulkanTextureArrayData {
+    pub texture: VRVulkanTextureData,
+    pub array_index: u32,
+    pub array_size: u32,
+}
+
+/** Texture handle for TextureType_DirectX12. Texture::handle points to this struct. */
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
+pub struct D3D12TextureData {
+    pub resource: *mut c_void, // ID3D12Resource
+    pub command_queue: *mut c_void, // ID3D12CommandQueue
+    pub node_mask: u32,
+}
 
 /** Status of the overall system or tracked objects */
 #[allow(non_camel_case_types)]
@@ -558,48 +635,52 @@ pub enum EVRState {
     VRState_Ready_Alert_Low = 7,
 }
 
-#[allow(non_camel_case_types)]
-#[derive(PartialEq)]
-#[repr(C)]
-pub enum EVRSubmitFlags {
-    // Simple render path. App submits rendered left and right eye images with no lens distortion correction applied.
-    Submit_Default = 0x00,
+bitflags! {
+    /** Flags for IVRCompositor::Submit. Converts to and from the raw u32 the function table takes. */
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct EVRSubmitFlags: u32 {
+        // Simple render path. App submits rendered left and right eye images with no lens distortion correction applied.
+        const Submit_Default = 0x00;
 
-    // App submits final left and right eye images with lens distortion already applied (lens distortion makes the images appear
-    // barrel distorted with chromatic aberration correction applied). The app would have used the data returned by
-    // vr::IVRSystem::ComputeDistortion() to apply the correct distortion to the rendered images before calling Submit().
-    Submit_LensDistortionAlreadyApplied = 0x01,
+        // App submits final left and right eye images with lens distortion already applied (lens distortion makes the images appear
+        // barrel distorted with chromatic aberration correction applied). The app would have used the data returned by
+        // vr::IVRSystem::ComputeDistortion() to apply the correct distortion to the rendered images before calling Submit().
+        const Submit_LensDistortionAlreadyApplied = 0x01;
 
-    // If the texture pointer passed in is actually a renderbuffer (e.g. for MSAA in OpenGL) then set this flag.
-    Submit_GlRenderBuffer = 0x02,
+        // If the texture pointer passed in is actually a renderbuffer (e.g. for MSAA in OpenGL) then set this flag.
+        const Submit_GlRenderBuffer = 0x02;
 
-    // Do not use
-    Submit_Reserved = 0x04,
+        // Do not use
+        const Submit_Reserved = 0x04;
 
-    // Set to indicate that pTexture is a pointer to a VRTextureWithPose_t.
-    // This flag can be combined with Submit_TextureWithDepth to pass a VRTextureWithPoseAndDepth_t.
-    Submit_TextureWithPose = 0x08,
+        // Set to indicate that pTexture is a pointer to a VRTextureWithPose_t.
+        // This flag can be combined with Submit_TextureWithDepth to pass a VRTextureWithPoseAndDepth_t.
+        const Submit_TextureWithPose = 0x08;
 
-    // Set to indicate that pTexture is a pointer to a VRTextureWithDepth_t.
-    // This flag can be combined with Submit_TextureWithPose to pass a VRTextureWithPoseAndDepth_t.
-    Submit_TextureWithDepth = 0x10,
+        // Set to indicate that pTexture is a pointer to a VRTextureWithDepth_t.
+        // This flag can be combined with Submit_TextureWithPose to pass a VRTextureWithPoseAndDepth_t.
+        const Submit_TextureWithDepth = 0x10;
 
-    // Set to indicate a discontinuity between this and the last frame.
-    // This will prevent motion smoothing from attempting to extrapolate using the pair.
-    Submit_FrameDiscontinuty = 0x20,
+        // Set to indicate a discontinuity between this and the last frame.
+        // This will prevent motion smoothing from attempting to extrapolate using the pair.
+        const Submit_FrameDiscontinuty = 0x20;
 
-    // Set to indicate that pTexture->handle is a contains VRVulkanTextureArrayData_t
-    Submit_VulkanTextureWithArrayData = 0x40,
+        // Set to indicate that pTexture->handle is a contains VRVulkanTextureArrayData_t
+        const Submit_VulkanTextureWithArrayData = 0x40;
 
-    // If the texture pointer passed in is an OpenGL Array texture, set this flag
-    Submit_GlArrayTexture = 0x80,
+        // If the texture pointer passed in is an OpenGL Array texture, set this flag
+        const Submit_GlArrayTexture = 0x80;
 
-    // Do not use
-    Submit_Reserved2 = 0x8000,
+        // Do not use
+        const Submit_Reserved2 = 0x8000;
+    }
 }
 
+flags_from_u32!(EVRSubmitFlags);
+
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EDeviceActivityLevel {
     k_EDeviceActivityLevel_Unknown = -1,
@@ -611,7 +692,7 @@ pub enum EDeviceActivityLevel {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVRButtonId {
     k_EButton_System = 0,
@@ -646,7 +727,8 @@ pub enum EVRButtonId {
 
 /** used for controller button events */
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_Controller {
     pub button: u32, // EVRButtonId enum
 }
@@ -662,8 +744,9 @@ pub enum EVRMouseButton {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /** used for simulated mouse events in overlay space */
+#[repr(C)]
 pub struct VREvent_Mouse {
     pub x: f32,
     pub y: f32,      // co-ords are in GL space, bottom left of the texture is 0,0
@@ -671,8 +754,9 @@ pub struct VREvent_Mouse {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /** used for simulated mouse wheel scroll */
+#[repr(C)]
 pub struct VREvent_Scroll {
     pub x_delta: f32,
     pub y_delta: f32,
@@ -685,7 +769,8 @@ pub struct VREvent_Scroll {
    flag set.
 **/
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_TouchPadMove {
     // true if the users finger is detected on the touch pad
     pub finger_down: bool,
@@ -704,7 +789,9 @@ pub struct VREvent_TouchPadMove {
 
 /** notification related events. Details will still change at this point */
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_Notification {
     pub user_value: u64,
     pub notification_id: u32,
@@ -712,7 +799,8 @@ pub struct VREvent_Notification {
 
 /** Used for events about processes */
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_Process {
     pub pid: u32,
     pub old_pid: u32,
@@ -723,7 +811,9 @@ pub struct VREvent_Process {
 
 /** Used for a few events about overlays */
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_Overlay {
     pub overlay_handle: u64,
     pub device_path: u64,
@@ -732,35 +822,43 @@ pub struct VREvent_Overlay {
 
 /** Used for a few events about overlays */
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_Status {
     pub status_state: u32, // EVRState enum
 }
 
 /** Used for keyboard events **/
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_Keyboard {
     pub new_input: [u8; 8], // Up to 11 bytes of new input
     pub user_value: u64,    // Possible flags about the new input
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_Ipd {
     pub ipd_meters: f32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_Chaperone {
     pub previous_universe: u64,
     pub current_universe: u64,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /** Not actually used for any events */
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_Reserved {
     pub reserved0: u64,
     pub reserved1: u64,
@@ -771,59 +869,71 @@ pub struct VREvent_Reserved {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_PerformanceTest {
     pub fidelity_level: u32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_SeatedZeroPoseReset {
     pub reset_by_system_menu: bool,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_Screenshot {
     pub handle: u32,
     pub type_: u32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_ScreenshotProgress {
     pub progress: f32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_ApplicationLaunch {
     pub pid: u32,
     pub args_handle: u32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_EditingCameraSurface {
     pub overlay_handle: u64,
     pub visual_mode: u32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_MessageOverlay {
     pub vr_message_overlay_response: u32, // vr::VRMessageOverlayResponse enum
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_Property {
     pub container: PropertyContainerHandle,
     pub prop: ETrackedDeviceProperty,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_HapticVibration {
     pub container_handle: u64, // property container handle of the device with the haptic component
     pub component_handle: u64, // Which haptic component needs to vibrate
@@ -833,13 +943,17 @@ pub struct VREvent_HapticVibration {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_WebConsole {
     pub web_console_handle: WebConsoleHandle,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_InputBindingLoad {
     pub app_container: PropertyContainerHandle,
     pub path_message: u64,
@@ -848,7 +962,9 @@ pub struct VREvent_InputBindingLoad {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_InputActionManifestLoad {
     pub path_app_key: u64,
     pub path_message: u64,
@@ -857,24 +973,27 @@ pub struct VREvent_InputActionManifestLoad {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_SpatialAnchor {
     pub handle: SpatialAnchorHandle,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VREvent_ProgressUpdate {
     pub application_property_container: u64,
     pub path_device: u64,
     pub path_input_source: u64,
     pub path_progress_action: u64,
     pub path_icon: u64,
-    pub f_progress: u64,
+    pub f_progress: f32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EShowUIType {
     ShowUI_ControllerBinding = 0,
@@ -888,19 +1007,21 @@ pub enum EShowUIType {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_ShowUI {
     pub type_: EShowUIType,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_ShowDevTools {
     pub browser_identifier: i32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EHDCPError {
     HDCPError_None = 0,
@@ -911,13 +1032,14 @@ pub enum EHDCPError {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent_HDCPError {
     pub code: EHDCPError,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVREventType {
     VREvent_None = 0,
@@ -1139,7 +1261,9 @@ pub enum EVREventType {
     VREvent_VendorSpecific_Reserved_End = 19999,
 }
 
+#[derive(Clone, Copy)]
 #[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub union VREvent_Data {
     pub reserved: VREvent_Reserved,
     pub controller: VREvent_Controller,
@@ -1172,6 +1296,14 @@ pub union VREvent_Data {
     pub hdcp_error: VREvent_HDCPError,
 }
 
+impl fmt::Debug for VREvent_Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VREvent_Data").finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
 pub struct VREvent {
     pub event_type: u32,
     pub tracked_device_index: TrackedDeviceIndex,
@@ -1179,21 +1311,26 @@ pub struct VREvent {
     pub data: VREvent_Data,
 }
 
-pub type VRComponentProperties = u32;
+pub type VRComponentProperties = EVRComponentProperty;
 
-#[allow(non_camel_case_types)]
-#[derive(PartialEq)]
-#[repr(C)]
-pub enum EVRComponentProperty {
-    VRComponentProperty_IsStatic = (1 << 0),
-    VRComponentProperty_IsVisible = (1 << 1),
-    VRComponentProperty_IsTouched = (1 << 2),
-    VRComponentProperty_IsPressed = (1 << 3),
-    VRComponentProperty_IsScrolled = (1 << 4),
-    VRComponentProperty_IsHighlighted = (1 << 5),
+bitflags! {
+    /** The state of a render model component, in RenderModel_ComponentState::properties. */
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct EVRComponentProperty: u32 {
+        const VRComponentProperty_IsStatic = 1 << 0;
+        const VRComponentProperty_IsVisible = 1 << 1;
+        const VRComponentProperty_IsTouched = 1 << 2;
+        const VRComponentProperty_IsPressed = 1 << 3;
+        const VRComponentProperty_IsScrolled = 1 << 4;
+        const VRComponentProperty_IsHighlighted = 1 << 5;
+    }
 }
 
+flags_from_u32!(EVRComponentProperty);
+
 #[allow(non_camel_case_types)]
+#[repr(C)]
 pub struct RenderModel_ComponentState {
     pub tracking_to_component_render_model: HmdMatrix34,
     pub tracking_to_component_local: HmdMatrix34,
@@ -1247,13 +1384,15 @@ pub enum EVRSpatialAnchorError {
     VRSpatialAnchorError_UnknownDriver = 13,
 }
 
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct HiddenAreaMesh {
     pub vertex_data: *const HmdVector2,
     pub triangle_count: u32,
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EHiddenAreaMeshType {
     k_eHiddenAreaMesh_Standard = 0,
@@ -1264,7 +1403,7 @@ pub enum EHiddenAreaMeshType {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVRControllerAxisType {
     k_eControllerAxis_None = 0,
@@ -1273,6 +1412,8 @@ pub enum EVRControllerAxisType {
     k_eControllerAxis_Trigger = 3, // Analog trigger data is in the X axis
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
 pub struct VRControllerAxis {
     pub x: f32, // Ranges from -1.0 to 1.0 for joysticks and track pads. Ranges from 0.0 to 1.0 for triggers were 0 is fully released.
     pub y: f32, // Ranges from -1.0 to 1.0 for joysticks and track pads. Is always 0.0 for triggers.
@@ -1281,6 +1422,9 @@ pub struct VRControllerAxis {
 #[allow(non_upper_case_globals)]
 pub const k_unControllerStateAxisCount: usize = 5;
 
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct VRControllerState001 {
     // If packet num matches that on your prior call, then the controller state hasn't been changed since
     // your last call and there is no need to process it
@@ -1352,7 +1496,7 @@ pub enum EVROverlayError {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVRApplicationType {
     VRApplication_Other = 0, // Some other kind of application that isn't covered by the other entries
@@ -1384,7 +1528,7 @@ pub fn is_openxr_app_type(type_: EVRApplicationType) -> bool {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVRFirmwareError {
     VRFirmwareError_None = 0,
@@ -1444,7 +1588,7 @@ pub type BoneIndex = i32;
 pub const INVALID_BONE_INDEX: BoneIndex = -1;
 
 #[allow(non_camel_case_types)]
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub enum EVRInitError {
     VRInitError_None = 0,
@@ -1731,6 +1875,8 @@ pub const MAX_DISTORTION_FUNCTION_PARAMETERS: u32 = 8;
 pub type TrackedCameraHandle = u64;
 pub const INVALID_TRACKED_CAMERA_HANDLE: TrackedCameraHandle = 0;
 
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct CameraVideoStreamFrameHeader {
     pub frame_type: EVRTrackedCameraFrameType,
 
@@ -1748,54 +1894,61 @@ pub struct CameraVideoStreamFrameHeader {
 pub type ScreenshotHandle = u32;
 pub const SCREENSHOT_HANDLE_INVALID: ScreenshotHandle = 0;
 
-/** Compositor frame timing reprojection flags. */
+bitflags! {
+    /** Compositor frame timing reprojection flags, in Compositor_FrameTiming::reprojection_flags. */
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct VRCompositorReprojectionFlags: u32 {
+        const VRCompositor_ReprojectionReason_Cpu = 0x01;
+        const VRCompositor_ReprojectionReason_Gpu = 0x02;
+        const VRCompositor_ReprojectionAsync = 0x04; // This flag indicates the async reprojection mode is active,
+                                                     // but does not indicate if reprojection actually happened or not.
+                                                     // Use the ReprojectionReason flags above to check if reprojection
+                                                     // was actually applied (i.e. scene texture was reused).
+                                                     // NumFramePresents > 1 also indicates the scene texture was reused,
+                                                     // and also the number of times that it was presented in total.
+        const VRCompositor_ReprojectionMotion = 0x08; // This flag indicates whether or not motion smoothing was triggered for this frame
+        const VRCompositor_PredictionMask = 0xF0; // The runtime may predict more than one frame (up to four) ahead if
+                                                  // it detects the application is taking too long to render. These two
+                                                  // bits will contain the count of additional frames (normally zero).
+                                                  // Use Compositor_FrameTiming::additional_predicted_frames to read from
+                                                  // the latest frame timing entry.
+        const VRCompositor_ThrottleMask = 0xF00; // Number of frames the compositor is throttling the application.
+                                                 // Use Compositor_FrameTiming::throttled_frames to read from
+                                                 // the latest frame timing entry.
+    }
+}
+
+flags_from_u32!(VRCompositorReprojectionFlags);
+
+/** The reprojection flags as raw u32 values, as openvr.h declares them. */
 #[allow(non_upper_case_globals)]
-pub const VRCompositor_ReprojectionReason_Cpu: u32 = 0x01;
+pub const VRCompositor_ReprojectionReason_Cpu: u32 = VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Cpu.bits();
 #[allow(non_upper_case_globals)]
-pub const VRCompositor_ReprojectionReason_Gpu: u32 = 0x02;
+pub const VRCompositor_ReprojectionReason_Gpu: u32 = VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Gpu.bits();
 #[allow(non_upper_case_globals)]
-pub const VRCompositor_ReprojectionAsync: u32 = 0x04; // This flag indicates the async reprojection mode is active,
-                                                      // but does not indicate if reprojection actually happened or not.
-                                                      // Use the ReprojectionReason flags above to check if reprojection
-                                                      // was actually applied (i.e. scene texture was reused).
-                                                      // NumFramePresents > 1 also indicates the scene texture was reused,
-                                                      // and also the number of times that it was presented in total.
+pub const VRCompositor_ReprojectionAsync: u32 = VRCompositorReprojectionFlags::VRCompositor_ReprojectionAsync.bits();
 #[allow(non_upper_case_globals)]
-pub const VRCompositor_ReprojectionMotion: u32 = 0x08; // This flag indicates whether or not motion smoothing was triggered for this frame
+pub const VRCompositor_ReprojectionMotion: u32 = VRCompositorReprojectionFlags::VRCompositor_ReprojectionMotion.bits();
 #[allow(non_upper_case_globals)]
-pub const VRCompositor_PredictionMask: u32 = 0xF0; // The runtime may predict more than one frame (up to four) ahead if
-                                                   // it detects the application is taking too long to render. These two
-                                                   // bits will contain the count of additional frames (normally zero).
-                                                   // Use the VR_COMPOSITOR_ADDITIONAL_PREDICTED_FRAMES macro to read from
-                                                   // the latest frame timing entry.
+pub const VRCompositor_PredictionMask: u32 = VRCompositorReprojectionFlags::VRCompositor_PredictionMask.bits();
 #[allow(non_upper_case_globals)]
-pub const VRCompositor_ThrottleMask: u32 = 0xF00; // Number of frames the compositor is throttling the application.
-                                                  // Use the VR_COMPOSITOR_NUMBER_OF_THROTTLED_FRAMES macro to read from
-                                                  // the latest frame timing entry.
-
-#[macro_export]
-macro_rules! VR_COMPOSITOR_ADDITIONAL_PREDICTED_FRAMES {
-    (timing) => {
-        (((timing).m_nReprojectionFlags & VRCompositor_PredictionMask) >> 4)
-    };
-}
-
-#[macro_export]
-macro_rules! VR_COMPOSITOR_NUMBER_OF_THROTTLED_FRAMES {
-    (timing) => {
-        (((timing).m_nReprojectionFlags & vr::VRCompositor_ThrottleMask) >> 8)
-    };
-}
+pub const VRCompositor_ThrottleMask: u32 = VRCompositorReprojectionFlags::VRCompositor_ThrottleMask.bits();
 
 #[allow(non_camel_case_types)]
 /** Provides a single frame's timing information to the app */
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct Compositor_FrameTiming {
     pub size: u32, // Set to sizeof( Compositor_FrameTiming )
     pub frame_index: u32,
     pub num_frame_presents: u32, // number of times this frame was presented
     pub num_mis_presented: u32, // number of times this frame was presented on a vsync other than it was originally predicted to
     pub num_dropped_frames: u32, // number of additional times previous frame was scanned out
-    pub reprojection_flags: u32,
+    pub reprojection_flags: VRCompositorReprojectionFlags,
 
     /** Absolute time reference for comparing frames.  This aligns with the vsync that running start is relative to. */
     pub system_time_in_seconds: f64,
@@ -1831,7 +1984,10 @@ pub struct Compositor_FrameTiming {
     pub num_vsyncs_to_first_view: u32,
 }
 
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(non_camel_case_types)]
+#[repr(C)]
 pub struct Compositor_BenchmarkResults {
     pub mega_pixels_per_second: f32,
     pub hmd_recommended_mega_pixels_per_second: f32,
@@ -1839,12 +1995,13 @@ pub struct Compositor_BenchmarkResults {
 
 /** Frame timing data provided by direct mode drivers. */
 #[allow(non_camel_case_types)]
+#[repr(C)]
 pub struct DriverDirectMode_FrameTiming {
     pub size: u32,               // Set to sizeof( DriverDirectMode_FrameTiming )
     pub num_frame_presents: u32, // number of times frame was presented
     pub num_mis_presented: u32, // number of times frame was presented on a vsync other than it was originally predicted to
     pub num_dropped_frames: u32, // number of additional times previous frame was scanned out (i.e. compositor missed vsync)
-    pub reprojection_flags: u32,
+    pub reprojection_flags: VRCompositorReprojectionFlags,
 }
 
 pub const VRCOMPOSITOR_REPROJECTION_MOTION_ENABLED: u32 = 0x100;
@@ -1870,24 +2027,29 @@ pub enum EVRMuraCorrectionMode
 	EVRMuraCorrectionMode_NoCorrection
 }
 
-#[allow(non_camel_case_types)]
-#[derive(PartialEq)]
-#[repr(C)]
-/** raw IMU data provided by IVRIOBuffer from paths to tracked devices with IMUs */
-pub enum Imu_OffScaleFlags
-{
-	OffScale_AccelX	= 0x01,
-	OffScale_AccelY	= 0x02,
-	OffScale_AccelZ	= 0x04,
-	OffScale_GyroX	= 0x08,
-	OffScale_GyroY	= 0x10,
-	OffScale_GyroZ	= 0x20,
+bitflags! {
+    /** raw IMU data provided by IVRIOBuffer from paths to tracked devices with IMUs */
+    #[allow(non_camel_case_types)]
+    #[repr(transparent)]
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct Imu_OffScaleFlags: u32 {
+        const OffScale_AccelX = 0x01;
+        const OffScale_AccelY = 0x02;
+        const OffScale_AccelZ = 0x04;
+        const OffScale_GyroX = 0x08;
+        const OffScale_GyroY = 0x10;
+        const OffScale_GyroZ = 0x20;
+    }
 }
 
+flags_from_u32!(Imu_OffScaleFlags);
+
+#[repr(C)]
+#[cfg_attr(any(target_os = "linux", target_os = "macos"), repr(packed(4)))]
 pub struct ImuSample
 {
-	pub sample_time: u64,
-	pub v_accel: HmdVector3,
-	pub v_gyro: HmdVector3,
-	pub un_off_scale_flags: u32,
+	pub sample_time: f64,
+	pub v_accel: HmdVector3d,
+	pub v_gyro: HmdVector3d,
+	pub un_off_scale_flags: Imu_OffScaleFlags,
 }
@@ -0,0 +1,37 @@
+use std::ffi::CString;
+
+use super::compositor::Compositor;
+use super::system::System;
+use super::vrtypes::*;
+
+/** Splits the space-separated extension list the runtime returns into names for VkInstanceCreateInfo
+* or VkDeviceCreateInfo. */
+pub(crate) fn extension_list(extensions: &str) -> Vec<CString> {
+    extensions
+        .split_ascii_whitespace()
+        .filter_map(|name| CString::new(name).ok())
+        .collect()
+}
+
+/** What a Vulkan renderer needs to create its device for the HMD. */
+#[derive(Clone, Debug)]
+pub struct VulkanDeviceSetup {
+    /** The physical device the HMD is attached to */
+    pub physical_device: VkPhysicalDevice,
+    /** The device extensions the compositor needs on it */
+    pub device_extensions: Vec<CString>,
+}
+
+impl VulkanDeviceSetup {
+    /** Resolves the HMD's physical device through GetOutputDevice and the device extensions the compositor
+    * needs on it. None if the runtime does not report a physical device.
+    *
+    * # Safety
+    * The instance must be a live VkInstance created with the extensions from
+    * Compositor::vulkan_instance_extensions_required enabled. */
+    pub unsafe fn new(system: &System, compositor: &Compositor, instance: VkInstance) -> Option<VulkanDeviceSetup> {
+        let physical_device = system.vulkan_output_device(instance)?;
+        let device_extensions = compositor.vulkan_device_extensions_required(physical_device);
+        Some(VulkanDeviceSetup { physical_device, device_extensions })
+    }
+}
@@ -1,3 +1,6 @@
+// get_ivrsystem only checks that the interface can be fetched, and never reads the table it gets.
+#![allow(unused_variables, unused_assignments)]
+
 extern crate openvr_api;
 
 #[cfg(test)]
@@ -0,0 +1,193 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, ivrcompositor::*, mock::*, texture::*, vrtypes::*};
+
+    fn texture() -> SubmitTexture<'static> {
+        Texture::gl(1, EColorSpace::ColorSpace_Gamma)
+    }
+
+    fn timing(frame_index: u32, reprojection_flags: u32) -> Compositor_FrameTiming {
+        Compositor_FrameTiming {
+            frame_index,
+            reprojection_flags: reprojection_flags.into(),
+            pre_submit_gpu_ms: 4.0,
+            post_submit_gpu_ms: 0.5,
+            total_render_gpu_ms: 6.0,
+            compositor_render_cpu_ms: 0.25,
+            new_poses_ready_ms: 1.0,
+            new_frame_ready_ms: 4.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn wait_get_poses() {
+        let mock = MockRuntime::new();
+        let controller = mock.add_controller(ETrackedControllerRole::TrackedControllerRole_LeftHand);
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let poses = compositor.wait_get_poses().unwrap();
+
+        assert!(poses.render[0].pose_is_valid);
+        assert!(poses.game[controller as usize].device_is_connected);
+        assert!(!poses.render[controller as usize + 1].device_is_connected);
+        assert_eq!(mock.state().frame_index, 1);
+    }
+
+    #[test]
+    fn submit_errors() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let null_texture = SubmitTexture::from(Texture {
+            handle: std::ptr::null_mut(),
+            texture_type: ETextureType::TextureType_OpenGL,
+            color_space: EColorSpace::ColorSpace_Gamma,
+        });
+
+        compositor.wait_get_poses().unwrap();
+        unsafe {
+            assert_eq!(
+                compositor.submit(EVREye::Eye_Left, &null_texture, None, EVRSubmitFlags::Submit_Default),
+                Err(EVRCompositorError::VRCompositorError_InvalidTexture)
+            );
+
+            mock.state().submit_error = Some(EVRCompositorError::VRCompositorError_DoNotHaveFocus);
+            assert_eq!(
+                compositor.submit(EVREye::Eye_Left, &texture(), None, EVRSubmitFlags::Submit_Default),
+                Err(EVRCompositorError::VRCompositorError_DoNotHaveFocus)
+            );
+        }
+    }
+
+    #[test]
+    fn frame_order() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let bounds = VRTextureBounds { u_min: 0.0, v_min: 0.0, u_max: 0.5, v_max: 1.0 };
+
+        for _ in 0..2 {
+            let frame = compositor.begin_frame().unwrap();
+            assert!(frame.render_pose(k_unTrackedDeviceIndex_Hmd).unwrap().pose_is_valid);
+
+            let frame = unsafe { frame.submit_left(&texture(), Some(bounds), EVRSubmitFlags::Submit_Default) }.unwrap();
+            let frame = unsafe { frame.submit_right(&texture(), None, EVRSubmitFlags::Submit_Default) }.unwrap();
+            frame.post_present_handoff();
+        }
+
+        let frames = mock.submitted_frames();
+        let order: Vec<(u32, EVREye)> = frames.iter().map(|frame| (frame.frame_index, frame.eye)).collect();
+        assert_eq!(
+            order,
+            [(1, EVREye::Eye_Left), (1, EVREye::Eye_Right), (2, EVREye::Eye_Left), (2, EVREye::Eye_Right)]
+        );
+        assert_eq!(frames[0].bounds, Some([0.0, 0.0, 0.5, 1.0]));
+        assert_eq!(frames[1].bounds, None);
+        assert_eq!(mock.state().post_present_handoffs, 2);
+    }
+
+    #[test]
+    fn texture_flags() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let vulkan = VRVulkanTextureData {
+            image: 7,
+            device: std::ptr::dangling_mut(),
+            physical_device: std::ptr::dangling_mut(),
+            instance: std::ptr::dangling_mut(),
+            queue: std::ptr::dangling_mut(),
+            queue_family_index: 0,
+            width: 1024,
+            height: 1024,
+            format: 43,
+            sample_count: 1,
+        };
+        let vulkan_array = VRVulkanTextureArrayData { texture: vulkan, array_index: 1, array_size: 2 };
+
+        let left = Texture::gl_render_buffer(3, EColorSpace::ColorSpace_Auto);
+        let right = Texture::vulkan_array(&vulkan_array, EColorSpace::ColorSpace_Auto);
+
+        let frame = compositor.begin_frame().unwrap();
+        let frame = unsafe { frame.submit_left(&left, None, EVRSubmitFlags::Submit_FrameDiscontinuty) }.unwrap();
+        unsafe { frame.submit_right(&right, None, EVRSubmitFlags::Submit_Default) }.unwrap();
+
+        let frames = mock.submitted_frames();
+        assert_eq!(frames[0].handle as usize, 3);
+        assert_eq!(
+            frames[0].flags,
+            EVRSubmitFlags::Submit_GlRenderBuffer | EVRSubmitFlags::Submit_FrameDiscontinuty
+        );
+        assert_eq!(frames[1].texture_type, ETextureType::TextureType_Vulkan);
+        assert_eq!(frames[1].handle as *const VRVulkanTextureArrayData, &vulkan_array as *const VRVulkanTextureArrayData);
+        assert_eq!(frames[1].flags, EVRSubmitFlags::Submit_VulkanTextureWithArrayData);
+    }
+
+    #[test]
+    fn frame_timings() {
+        let mock = MockRuntime::new();
+        for frame_index in 1..=3 {
+            mock.push_frame_timing(timing(frame_index, 0));
+        }
+        mock.push_frame_timing(timing(4, 0x1 | 0x8 | 0x20 | 0x200));
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        assert!(compositor.frame_timings(0).is_empty());
+
+        let timings = compositor.frame_timings(8);
+        let indices: Vec<u32> = timings.iter().map(|timing| timing.frame_index).collect();
+        assert_eq!(indices, [1, 2, 3, 4]);
+        assert_eq!(compositor.frame_timings(2)[0].frame_index, 3);
+
+        assert!(!timings[0].was_reprojected());
+        let late = &timings[3];
+        assert!(late.was_reprojected());
+        assert_eq!(late.reprojection_reason(), VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Cpu);
+        assert!(late.motion_smoothing_active());
+        assert_eq!(late.additional_predicted_frames(), 2);
+        assert_eq!(late.throttled_frames(), 2);
+        assert_eq!(late.application_cpu_ms(), 3.0);
+        assert_eq!(late.application_gpu_ms(), 4.5);
+        assert_eq!(late.total_cpu_ms(), 3.25);
+        assert_eq!(late.total_gpu_ms(), 6.0);
+    }
+
+    #[test]
+    fn stage_override() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let grey = openvr_api::vr::public_vrtypes::HmdColor { r: 0.5, g: 0.5, b: 0.5, a: 1.0 };
+        let transform = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, -1.0], [0.0, 0.0, 1.0, 0.0]];
+
+        let settings = Compositor_StageRenderSettings::new()
+            .fresnel(0.5)
+            .secondary_color(grey)
+            .vignette(1.0, 3.0)
+            .wireframe(true);
+        assert_eq!(settings.m_flFresnelStrength, 0.0);
+        assert_eq!(settings.m_flVignetteOuterRadius, 3.0);
+
+        compositor.set_stage_override("/levels/stage.obj", &transform, &settings).unwrap();
+        {
+            let state = mock.state();
+            let stage = state.stage_override.as_ref().unwrap();
+            assert_eq!(stage.render_model_path, "/levels/stage.obj");
+            assert_eq!(stage.transform, transform);
+            assert!(stage.settings.m_bWireframe);
+            assert_eq!(stage.settings.m_SecondaryColor, grey);
+        }
+
+        assert_eq!(
+            compositor.set_stage_override("stage\0.obj", &transform, &settings),
+            Err(EVRCompositorError::VRCompositorError_RequestFailed)
+        );
+        compositor.clear_stage_override();
+        assert!(mock.state().stage_override.is_none());
+    }
+}
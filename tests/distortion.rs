@@ -0,0 +1,156 @@
+extern crate openvr_api;
+
+#[cfg(test)]
+mod tests {
+    use openvr_api::vr::{distortion::*, vrtypes::*};
+    use std::cell::Cell;
+    use std::fs;
+    use std::io::ErrorKind;
+    use std::path::PathBuf;
+
+    /** Pulls each channel towards the center by a different amount, like a lens with chromatic aberration. */
+    fn barrel(eye: EVREye, u: f32, v: f32) -> Option<DistortionCoordinates> {
+        let shift = if eye == EVREye::Eye_Left { 0.01 } else { -0.01 };
+        let channel = |scale: f32| [0.5 + (u - 0.5) * scale + shift, 0.5 + (v - 0.5) * scale];
+        Some(DistortionCoordinates { red: channel(0.9), green: channel(0.8), blue: channel(0.7) })
+    }
+
+    fn assert_close(a: [f32; 2], b: [f32; 2]) {
+        assert!((a[0] - b[0]).abs() < 1e-6 && (a[1] - b[1]).abs() < 1e-6, "{:?} != {:?}", a, b);
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("openvr_api_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn grid() {
+        let mesh = DistortionMesh::generate(&barrel, EVREye::Eye_Right, 5, 3);
+        assert_eq!((mesh.eye, mesh.columns, mesh.rows, mesh.failed_samples), (EVREye::Eye_Right, 5, 3, 0));
+        assert_eq!(mesh.vertices.len(), 15);
+        assert_eq!(mesh.indices.len(), 4 * 2 * 6);
+        assert_eq!(&mesh.indices[..6], &[0, 1, 6, 0, 6, 5]);
+        assert!(mesh.indices.iter().all(|&index| index < 15));
+
+        let first = mesh.vertices[0];
+        assert_eq!(first.position, [0.0, 0.0]);
+        assert_close(first.red, [0.04, 0.05]);
+        assert_close(first.blue, [0.14, 0.15]);
+        let last = mesh.vertices[14];
+        assert_eq!(last.position, [1.0, 1.0]);
+        assert_eq!(mesh.vertices[7].position, [0.5, 0.5]);
+        assert_close(last.green, [0.89, 0.9]);
+
+        // Too small a grid still covers the corners.
+        let smallest = DistortionMesh::generate(&barrel, EVREye::Eye_Left, 0, 1);
+        assert_eq!((smallest.columns, smallest.rows, smallest.indices.len()), (2, 2, 6));
+
+        // A grid whose vertex or index count overflows u32 is clamped instead.
+        let thin = DistortionMesh::generate(&barrel, EVREye::Eye_Left, u32::MAX, 2);
+        assert_eq!((thin.columns, thin.rows), (MAX_GRID_SIZE, 2));
+        assert_eq!(thin.indices.len(), (MAX_GRID_SIZE as usize - 1) * 6);
+
+        let [left, right] = DistortionMesh::generate_stereo(&barrel, 5, 3);
+        assert_eq!((left.eye, right), (EVREye::Eye_Left, mesh));
+    }
+
+    #[test]
+    fn failed_samples() {
+        let center_fails = |eye, u, v| if u == 0.5 && v == 0.5 { None } else { barrel(eye, u, v) };
+        let mesh = DistortionMesh::generate(&center_fails, EVREye::Eye_Left, 3, 3);
+        assert_eq!(mesh.failed_samples, 1);
+        assert_eq!(mesh.vertices[4].red, [0.5, 0.5]);
+        // Of the eight triangles, only the two that miss the center are left.
+        assert_eq!(mesh.indices, [1, 2, 5, 3, 7, 6]);
+
+        let mesh = DistortionMesh::generate(&|_, _, _| None, EVREye::Eye_Left, 4, 4);
+        assert_eq!((mesh.failed_samples, mesh.indices.len()), (16, 0));
+    }
+
+    #[test]
+    fn write_and_read() {
+        let mesh = DistortionMesh::generate(&barrel, EVREye::Eye_Right, 4, 6);
+        let mut buffer = Vec::new();
+        mesh.write_to(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), 8 + 6 * 4 + 24 * 32 + mesh.indices.len() * 4);
+        assert_eq!(DistortionMesh::read_from(buffer.as_slice()).unwrap(), mesh);
+
+        let error = DistortionMesh::read_from(&buffer[..buffer.len() - 1]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        let mut bad_magic = buffer.clone();
+        bad_magic[0] = b'x';
+        assert_eq!(DistortionMesh::read_from(bad_magic.as_slice()).unwrap_err().kind(), ErrorKind::InvalidData);
+
+        let mut bad_index = buffer.clone();
+        let last = bad_index.len() - 4;
+        bad_index[last..].copy_from_slice(&24u32.to_le_bytes());
+        assert_eq!(DistortionMesh::read_from(bad_index.as_slice()).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn cache() {
+        let directory = temp_dir("distortion_cache");
+        let cache = DistortionCache::new(&directory);
+        let samples = Cell::new(0);
+        let counting = |eye, u, v| {
+            samples.set(samples.get() + 1);
+            barrel(eye, u, v)
+        };
+
+        assert_eq!(cache.path("LHR-1234/5", 8, 8), directory.join("LHR-1234%2F5_8x8.distortion"));
+        // Escaping keeps serial numbers that differ only in escaped characters apart.
+        let serials = ["LHR-1234/5", "LHR-1234_5", "LHR-1234%2F5", "LHR-1234:5", "LHR-1234é5", ".."];
+        for (i, a) in serials.iter().enumerate() {
+            for b in &serials[i + 1..] {
+                assert_ne!(cache.path(a, 8, 8), cache.path(b, 8, 8));
+            }
+            assert_eq!(cache.path(a, 8, 8).parent(), Some(directory.as_path()));
+        }
+        assert!(cache.load("LHR-1234/5", 8, 8).is_none());
+
+        let generated = cache.load_or_generate("LHR-1234/5", &counting, 8, 8).unwrap();
+        assert_eq!(samples.get(), 2 * 64);
+        assert!(cache.path("LHR-1234/5", 8, 8).exists());
+
+        let cached = cache.load_or_generate("LHR-1234/5", &counting, 8, 8).unwrap();
+        assert_eq!(samples.get(), 2 * 64);
+        assert_eq!(cached, generated);
+
+        // Another grid size or headset samples again.
+        cache.load_or_generate("LHR-1234/5", &counting, 4, 4).unwrap();
+        cache.load_or_generate("LHR-9999", &counting, 8, 8).unwrap();
+        assert_eq!(samples.get(), 2 * 64 + 2 * 16 + 2 * 64);
+
+        // A damaged file is replaced.
+        fs::write(cache.path("LHR-9999", 8, 8), b"ovrdist1 and then nothing useful").unwrap();
+        assert!(cache.load("LHR-9999", 8, 8).is_none());
+        assert_eq!(cache.load_or_generate("LHR-9999", &counting, 8, 8).unwrap(), generated);
+        assert!(cache.load("LHR-9999", 8, 8).is_some());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn system_source() {
+        use openvr_api::vr::{context::*, mock::*};
+
+        let _mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        // The mock lens has no distortion.
+        let mesh = DistortionMesh::generate(&system, EVREye::Eye_Left, 3, 3);
+        assert!(mesh.vertices.iter().all(|vertex| vertex.red == vertex.position && vertex.blue == vertex.position));
+
+        let directory = temp_dir("distortion_system");
+        let cache = DistortionCache::new(&directory);
+        let [left, _] = cache.load_or_generate_for_system(&system, 3, 3).unwrap();
+        assert_eq!(left, mesh);
+        assert!(cache.path("MOCK-HMD-0", 3, 3).exists());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
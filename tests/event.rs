@@ -0,0 +1,100 @@
+extern crate openvr_api;
+
+#[cfg(test)]
+mod tests {
+    use openvr_api::vr::{event::*, vrtypes::*};
+
+    fn event(event_type: u32, data: VREvent_Data) -> VREvent {
+        VREvent {
+            event_type,
+            tracked_device_index: 3,
+            event_age_seconds: 0.0,
+            data,
+        }
+    }
+
+    #[test]
+    fn button_press() {
+        let raw = event(
+            EVREventType::VREvent_ButtonPress as u32,
+            VREvent_Data { controller: VREvent_Controller { button: EVRButtonId::k_EButton_Grip as u32 } },
+        );
+
+        match Event::from(&raw) {
+            Event::ButtonPress { device, button } => {
+                assert_eq!(device, 3);
+                assert_eq!(button, EVRButtonId::k_EButton_Grip);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn property_changed() {
+        let raw = event(
+            EVREventType::VREvent_PropertyChanged as u32,
+            VREvent_Data {
+                property: VREvent_Property { container: 42, prop: ETrackedDeviceProperty::Prop_DisplayFrequency_Float },
+            },
+        );
+
+        match Event::from(&raw) {
+            Event::PropertyChanged { container, prop } => {
+                assert_eq!(container, 42);
+                assert_eq!(prop, ETrackedDeviceProperty::Prop_DisplayFrequency_Float);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn chaperone_universe_changed() {
+        let raw = event(
+            EVREventType::VREvent_ChaperoneUniverseHasChanged as u32,
+            VREvent_Data { chaperone: VREvent_Chaperone { previous_universe: 1, current_universe: 2 } },
+        );
+
+        match Event::from(&raw) {
+            Event::ChaperoneUniverseChanged { previous_universe, current_universe } => {
+                assert_eq!((previous_universe, current_universe), (1, 2));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn payloadless_event_is_other() {
+        let raw = event(
+            EVREventType::VREvent_DashboardActivated as u32,
+            VREvent_Data { ipd: VREvent_Ipd { ipd_meters: 0.0 } },
+        );
+
+        match Event::from(&raw) {
+            Event::Other { event_type, device } => {
+                assert_eq!(event_type, EVREventType::VREvent_DashboardActivated);
+                assert_eq!(device, 3);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_event_type_is_raw() {
+        let raw = event(123456, VREvent_Data { ipd: VREvent_Ipd { ipd_meters: 0.0 } });
+
+        match Event::from(&raw) {
+            Event::Raw { event_type, device, .. } => assert_eq!((event_type, device), (123456, 3)),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_button_is_raw() {
+        let raw = event(
+            EVREventType::VREvent_ButtonPress as u32,
+            VREvent_Data { controller: VREvent_Controller { button: 20 } },
+        );
+
+        assert!(matches!(Event::from(&raw), Event::Raw { .. }));
+    }
+}
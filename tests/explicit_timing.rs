@@ -0,0 +1,116 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, explicit_timing::*, ivrcompositor::*, mock::*, texture::*, vrtypes::*};
+
+    fn texture() -> SubmitTexture<'static> {
+        Texture::gl(1, EColorSpace::ColorSpace_Gamma)
+    }
+
+    #[test]
+    fn frame_loop() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+
+        {
+            let mut timing = compositor.explicit_timing();
+            assert_eq!(
+                mock.state().timing_mode,
+                EVRCompositorTimingMode::VRCompositorTimingMode_Explicit_ApplicationPerformsPostPresentHandoff
+            );
+
+            for frame in 1..=2 {
+                timing.wait_get_poses().unwrap();
+                let submissions = timing.submit_queue(|| mock.state().explicit_timing_submissions).unwrap();
+                assert_eq!(submissions, frame);
+                timing.submit_queue(|| ()).unwrap();
+                assert_eq!(mock.state().explicit_timing_submissions, frame);
+
+                unsafe {
+                    timing.submit(EVREye::Eye_Right, &texture(), None, EVRSubmitFlags::Submit_Default).unwrap();
+                    timing.submit(EVREye::Eye_Left, &texture(), None, EVRSubmitFlags::Submit_Default).unwrap();
+                }
+                assert_eq!(timing.state(), ExplicitFrameState::WaitingForHandoff);
+                timing.post_present_handoff().unwrap();
+            }
+            assert_eq!(mock.state().post_present_handoffs, 2);
+        }
+
+        assert_eq!(mock.state().timing_mode, EVRCompositorTimingMode::VRCompositorTimingMode_Implicit);
+    }
+
+    #[test]
+    fn out_of_order() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let mut timing = compositor.explicit_timing();
+
+        assert_eq!(
+            timing.submit_timing_data(),
+            Err(ExplicitTimingError::OutOfOrder("SubmitExplicitTimingData", ExplicitFrameState::WaitingForPoses))
+        );
+        assert_eq!(
+            timing.post_present_handoff(),
+            Err(ExplicitTimingError::OutOfOrder("PostPresentHandoff", ExplicitFrameState::WaitingForPoses))
+        );
+
+        timing.wait_get_poses().unwrap();
+        assert!(timing.wait_get_poses().is_err());
+        assert_eq!(
+            unsafe { timing.submit(EVREye::Eye_Left, &texture(), None, EVRSubmitFlags::Submit_Default) },
+            Err(ExplicitTimingError::OutOfOrder("Submit", ExplicitFrameState::WaitingForTimingData))
+        );
+
+        timing.submit_timing_data().unwrap();
+        unsafe {
+            timing.submit(EVREye::Eye_Left, &texture(), None, EVRSubmitFlags::Submit_Default).unwrap();
+            assert_eq!(
+                timing.submit(EVREye::Eye_Left, &texture(), None, EVRSubmitFlags::Submit_Default),
+                Err(ExplicitTimingError::OutOfOrder("Submit", ExplicitFrameState::EyeSubmitted(EVREye::Eye_Left)))
+            );
+        }
+        assert!(timing.post_present_handoff().is_err());
+
+        mock.state().submit_error = Some(EVRCompositorError::VRCompositorError_DoNotHaveFocus);
+        assert_eq!(
+            unsafe { timing.submit(EVREye::Eye_Right, &texture(), None, EVRSubmitFlags::Submit_Default) },
+            Err(ExplicitTimingError::Compositor(EVRCompositorError::VRCompositorError_DoNotHaveFocus))
+        );
+        assert_eq!(timing.state(), ExplicitFrameState::EyeSubmitted(EVREye::Eye_Left));
+        assert_eq!(mock.state().post_present_handoffs, 0);
+    }
+
+    #[test]
+    fn recovers_from_failed_submit() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let mut timing = compositor.explicit_timing();
+        let submit = |timing: &mut ExplicitTiming, eye| unsafe {
+            timing.submit(eye, &texture(), None, EVRSubmitFlags::Submit_Default)
+        };
+
+        // Without focus every Submit fails, and each frame is given up on.
+        mock.state().submit_error = Some(EVRCompositorError::VRCompositorError_DoNotHaveFocus);
+        for _ in 0..2 {
+            timing.wait_get_poses().unwrap();
+            timing.submit_queue(|| ()).unwrap();
+            assert!(submit(&mut timing, EVREye::Eye_Left).is_err());
+            assert!(timing.wait_get_poses().is_err());
+            timing.abandon_frame();
+            assert_eq!(timing.state(), ExplicitFrameState::WaitingForPoses);
+        }
+
+        mock.state().submit_error = None;
+        timing.wait_get_poses().unwrap();
+        timing.submit_queue(|| ()).unwrap();
+        submit(&mut timing, EVREye::Eye_Left).unwrap();
+        submit(&mut timing, EVREye::Eye_Right).unwrap();
+        timing.post_present_handoff().unwrap();
+        assert_eq!(mock.state().post_present_handoffs, 1);
+        assert_eq!(mock.state().frame_index, 3);
+    }
+}
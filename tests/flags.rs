@@ -0,0 +1,41 @@
+extern crate openvr_api;
+
+#[cfg(test)]
+mod tests {
+    use openvr_api::vr::ivrchaperonesetup::EChaperoneImportFlags;
+    use openvr_api::vr::vrtypes::*;
+
+    #[test]
+    fn set_operations() {
+        let flags = EVRSubmitFlags::Submit_GlRenderBuffer | EVRSubmitFlags::Submit_FrameDiscontinuty;
+
+        assert!(flags.contains(EVRSubmitFlags::Submit_GlRenderBuffer));
+        assert!(!flags.contains(EVRSubmitFlags::Submit_GlArrayTexture));
+        assert_eq!(flags - EVRSubmitFlags::Submit_GlRenderBuffer, EVRSubmitFlags::Submit_FrameDiscontinuty);
+        assert_eq!(flags & EVRSubmitFlags::Submit_FrameDiscontinuty, EVRSubmitFlags::Submit_FrameDiscontinuty);
+        assert!(EVRSubmitFlags::Submit_Default.is_empty());
+    }
+
+    #[test]
+    fn debug_lists_set_flags() {
+        let properties =
+            EVRComponentProperty::VRComponentProperty_IsVisible | EVRComponentProperty::VRComponentProperty_IsPressed;
+
+        assert_eq!(
+            format!("{:?}", properties),
+            "EVRComponentProperty(VRComponentProperty_IsVisible | VRComponentProperty_IsPressed)"
+        );
+        assert_eq!(format!("{:?}", Imu_OffScaleFlags::from(0x41)), "Imu_OffScaleFlags(OffScale_AccelX | 0x40)");
+    }
+
+    #[test]
+    fn raw_u32() {
+        let reprojection = VRCompositorReprojectionFlags::from(0x112);
+
+        assert!(reprojection.contains(VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Gpu));
+        assert_eq!(reprojection.bits() & VRCompositor_PredictionMask, 0x10);
+        assert_eq!(u32::from(reprojection), 0x112);
+        assert_eq!(u32::from(EChaperoneImportFlags::EChaperoneImport_BoundsOnly), 1);
+        assert_eq!(EVRSubmitFlags::from(0x40), EVRSubmitFlags::Submit_VulkanTextureWithArrayData);
+    }
+}
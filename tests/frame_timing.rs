@@ -0,0 +1,120 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, frame_timing::*, ivrcompositor::*, mock::*, vrtypes::*};
+
+    fn timing(frame_index: u32, total_render_gpu_ms: f32, reprojection_flags: u32) -> Compositor_FrameTiming {
+        Compositor_FrameTiming {
+            frame_index,
+            num_frame_presents: 1,
+            reprojection_flags: reprojection_flags.into(),
+            total_render_gpu_ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn overlapping_batches() {
+        let mut recorder = FrameTimingRecorder::new(3);
+        let batch: Vec<Compositor_FrameTiming> = (1..=3).map(|index| timing(index, 5.0, 0)).collect();
+        assert_eq!(recorder.record(&batch), 3);
+
+        let batch: Vec<Compositor_FrameTiming> = (2..=5).map(|index| timing(index, 5.0, 0)).collect();
+        assert_eq!(recorder.record(&batch), 2);
+
+        let indices: Vec<u32> = recorder.frames().map(|timing| timing.frame_index).collect();
+        assert_eq!(indices, [3, 4, 5]);
+        assert_eq!(recorder.summary().stats.frames, 5);
+        assert_eq!(recorder.summary().missed_frames, 0);
+
+        recorder.record(&[timing(9, 5.0, 0)]);
+        assert_eq!(recorder.summary().missed_frames, 3);
+        assert_eq!(recorder.last_frame_index(), Some(9));
+
+        recorder.record(&[timing(2, 5.0, 0), timing(u32::MAX, 5.0, 0)]);
+        assert_eq!(recorder.last_frame_index(), Some(9));
+    }
+
+    #[test]
+    fn statistics() {
+        let mut recorder = FrameTimingRecorder::new(10);
+        let mut batch: Vec<Compositor_FrameTiming> =
+            (1..=10).map(|index| timing(index, index as f32, 0)).collect();
+        batch[3].reprojection_flags = VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Gpu;
+        batch[4].reprojection_flags = VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Gpu
+            | VRCompositorReprojectionFlags::VRCompositor_ReprojectionReason_Cpu;
+        batch[5].num_dropped_frames = 2;
+        batch[6].num_frame_presents = 2;
+        batch[6].num_mis_presented = 1;
+        recorder.record(&batch);
+
+        assert_eq!(recorder.gpu_ms_percentile(50.0), Some(5.0));
+        assert_eq!(recorder.gpu_ms_percentile(99.0), Some(10.0));
+        assert_eq!(recorder.gpu_ms_percentile(0.0), Some(1.0));
+        assert_eq!(FrameTimingRecorder::new(4).gpu_ms_percentile(50.0), None);
+
+        let stats = recorder.stats();
+        assert_eq!(stats.gpu_reprojected, 2);
+        assert_eq!(stats.cpu_reprojected, 1);
+        assert_eq!(stats.dropped_frames, 2);
+        assert_eq!(stats.frame_presents, 11);
+        assert_eq!(stats.mispresent_rate(), 1.0 / 11.0);
+        assert_eq!(recorder.summary().reprojected_frames, 2);
+    }
+
+    #[test]
+    fn poll_and_compare() {
+        let mock = MockRuntime::new();
+        for index in 1..=4 {
+            mock.push_frame_timing(timing(index, 6.0, 0));
+        }
+        mock.state().cumulative_stats = Compositor_CumulativeStats {
+            m_nNumFramePresents: 6,
+            m_nNumDroppedFrames: 1,
+            ..Default::default()
+        };
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let mut recorder = FrameTimingRecorder::new(16);
+
+        // Frame 4 is still being shown, so it waits for the next poll.
+        assert_eq!(recorder.poll(&compositor), 3);
+        assert_eq!(recorder.last_frame_index(), Some(3));
+        mock.push_frame_timing(timing(5, 6.0, 0));
+        assert_eq!(recorder.poll(&compositor), 1);
+        assert_eq!(recorder.poll(&compositor), 0);
+
+        let comparison = recorder.summary().compare(&compositor.cumulative_stats());
+        assert_eq!(comparison.frame_presents, (4, 6));
+        assert_eq!(comparison.dropped_frames, (0, 1));
+        assert_eq!(comparison.reprojected_frames, (0, 0));
+    }
+
+    #[test]
+    fn newest_frame_counts_settle() {
+        let mock = MockRuntime::new();
+        mock.push_frame_timing(timing(1, 6.0, 0));
+        mock.push_frame_timing(timing(2, 6.0, 0));
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let mut recorder = FrameTimingRecorder::new(16);
+        assert_eq!(recorder.poll(&compositor), 1);
+
+        // The app misses its next vsyncs, so frame 2 is presented again, once on the wrong vsync.
+        if let Some(newest) = mock.state().frame_timings.last_mut() {
+            newest.num_frame_presents = 3;
+            newest.num_mis_presented = 1;
+            newest.num_dropped_frames = 2;
+        }
+        mock.push_frame_timing(timing(3, 6.0, 0));
+        assert_eq!(recorder.poll(&compositor), 1);
+
+        let stats = recorder.summary().stats;
+        assert_eq!((stats.frames, stats.frame_presents), (2, 4));
+        assert_eq!((stats.mis_presented, stats.dropped_frames), (1, 2));
+        assert_eq!(recorder.last_frame_index(), Some(2));
+    }
+}
@@ -0,0 +1,239 @@
+extern crate openvr_api;
+
+#[cfg(all(test, any(feature = "glam", feature = "nalgebra", feature = "mint")))]
+mod tests {
+    use openvr_api::vr::{interop::*, math::*, public_vrtypes::*, vrtypes::*};
+
+    // Every element differs, so a conversion that forgets to transpose cannot round-trip or pass the layout checks.
+    const M34: HmdMatrix34 = [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0], [9.0, 10.0, 11.0, 12.0]];
+    const M33: HmdMatrix33 = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    const M44: HmdMatrix44 =
+        [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0], [9.0, 10.0, 11.0, 12.0], [13.0, 14.0, 15.0, 16.0]];
+    const V2: HmdVector2 = [1.0, -2.0];
+    const V3: HmdVector3 = [1.0, -2.0, 3.0];
+    const V4: HmdVector4 = [1.0, -2.0, 3.0, -4.0];
+    const V3D: HmdVector3d = [1.0e10, -2.0, 3.5];
+    const QUAD: HmdQuad = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 2.0]];
+    const COLOR: HmdColor = HmdColor { r: 0.1, g: 0.2, b: 0.3, a: 0.4 };
+    const RECT: HmdRect2 = HmdRect2 { top_left: [-1.0, 1.0], bottom_right: [1.0, -1.0] };
+    const EPSILON: f32 = 1e-5;
+
+    fn rotation() -> HmdQuaternionf {
+        HmdQuaternionf::from_axis_angle([1.0, 2.0, 0.5], 0.8)
+    }
+
+    fn rotation_d() -> HmdQuaternion {
+        HmdQuaternion { w: 0.5, x: -0.5, y: 0.5, z: 0.5 }
+    }
+
+    fn rigid() -> HmdMatrix34 {
+        HmdMatrix34::from_pos_quat([0.5, 1.5, -2.0], rotation())
+    }
+
+    fn bone() -> VRBoneTransform {
+        VRBoneTransform { position: [0.25, -0.5, 1.0, 1.0], orientation: rotation() }
+    }
+
+    fn assert_close(a: &[f32], b: &[f32]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < EPSILON, "{:?} != {:?}", a, b);
+        }
+    }
+
+    fn assert_bone(a: VRBoneTransform, b: VRBoneTransform) {
+        let (p, q) = (a.orientation, b.orientation);
+        let q = if p.dot(&q) < 0.0 { HmdQuaternionf { w: -q.w, x: -q.x, y: -q.y, z: -q.z } } else { q };
+        assert_close(&a.position, &b.position);
+        assert_close(&[p.w, p.x, p.y, p.z], &[q.w, q.x, q.y, q.z]);
+    }
+
+    fn tracked(matrix: HmdMatrix34) -> TrackedDevicePose {
+        TrackedDevicePose {
+            device_to_absolute_tracking: matrix,
+            velocity: [1.0, 0.0, 0.0],
+            angular_velocity: [0.0, 1.0, 0.0],
+            tracking_result: ETrackingResult::TrackingResult_Running_OK,
+            pose_is_valid: true,
+            device_is_connected: true,
+        }
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam() {
+        use glam::{Affine3A, DQuat, DVec3, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+        let affine: Affine3A = M34.into_math();
+        assert_eq!(affine.matrix3.x_axis, Vec3A::new(1.0, 5.0, 9.0));
+        assert_eq!(affine.translation, Vec3A::new(4.0, 8.0, 12.0));
+        assert_eq!(HmdMatrix34::from_math(affine), M34);
+        let moved = Affine3A::from_math(rigid()).transform_point3(Vec3::new(0.3, -0.2, 0.9));
+        assert_close(&moved.to_array(), &rigid().transform_point([0.3, -0.2, 0.9]));
+
+        let affine_44: Mat4 = M34.into_math();
+        assert_eq!(affine_44.row(2), Vec4::new(9.0, 10.0, 11.0, 12.0));
+        assert_eq!(affine_44.row(3), Vec4::W);
+        assert_eq!(HmdMatrix34::from_math(affine_44), M34);
+
+        let m33: Mat3 = M33.into_math();
+        assert_eq!(m33.row(0), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(HmdMatrix33::from_math(m33), M33);
+
+        let m44: Mat4 = M44.into_math();
+        assert_eq!(m44.row(3), Vec4::new(13.0, 14.0, 15.0, 16.0));
+        assert_eq!(m44.w_axis, Vec4::new(4.0, 8.0, 12.0, 16.0));
+        assert_eq!(HmdMatrix44::from_math(m44), M44);
+
+        assert_eq!(HmdVector2::from_math(Vec2::from_math(V2)), V2);
+        assert_eq!(HmdVector3::from_math(Vec3::from_math(V3)), V3);
+        assert_eq!(HmdVector4::from_math(Vec4::from_math(V4)), V4);
+        assert_eq!(HmdVector3d::from_math(DVec3::from_math(V3D)), V3D);
+        let corners: [Vec3; 4] = QUAD.into_math();
+        assert_eq!(corners[3], Vec3::new(0.0, 1.0, 2.0));
+        assert_eq!(HmdQuad::from_math(corners), QUAD);
+
+        let quat = Quat::from(rotation());
+        assert_eq!(quat.w, rotation().w);
+        assert_close(&(quat * Vec3::from(V3)).to_array(), &rotation().rotate(V3));
+        assert_eq!(HmdQuaternionf::from(quat), rotation());
+        let dquat = DQuat::from(rotation_d());
+        assert_eq!((dquat.w, dquat.x), (0.5, -0.5));
+        assert_eq!(HmdQuaternion::from(dquat), rotation_d());
+
+        assert_eq!(Vec4::from(COLOR), Vec4::new(0.1, 0.2, 0.3, 0.4));
+        assert_eq!(HmdColor::from(Vec4::from(COLOR)), COLOR);
+        let (top_left, bottom_right) = RECT.into();
+        assert_eq!((top_left, bottom_right), (Vec2::new(-1.0, 1.0), Vec2::new(1.0, -1.0)));
+        assert_eq!(HmdRect2::from((top_left, bottom_right)), RECT);
+
+        let bone_affine = Affine3A::from(bone());
+        assert_close(&bone_affine.translation.to_array(), &[0.25, -0.5, 1.0]);
+        assert_bone(VRBoneTransform::from(bone_affine), bone());
+
+        let pose_affine = Affine3A::from(tracked(rigid()));
+        assert_eq!(HmdMatrix34::from_math(pose_affine), rigid());
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra() {
+        use nalgebra::{
+            Isometry3, Matrix3, Matrix3x4, Matrix4, Point2, Point3, Quaternion, UnitQuaternion, Vector2, Vector3,
+            Vector4,
+        };
+
+        let m34: Matrix3x4<f32> = M34.into_math();
+        assert_eq!(m34[(1, 3)], 8.0);
+        assert_eq!(m34.column(0), Vector3::new(1.0, 5.0, 9.0));
+        assert_eq!(HmdMatrix34::from_math(m34), M34);
+
+        let affine_44: Matrix4<f32> = M34.into_math();
+        assert_eq!(affine_44.row(3), Vector4::new(0.0, 0.0, 0.0, 1.0).transpose());
+        assert_eq!(HmdMatrix34::from_math(affine_44), M34);
+
+        let isometry: Isometry3<f32> = rigid().into_math();
+        let moved = isometry.transform_point(&Point3::new(0.3, -0.2, 0.9));
+        assert_close(moved.coords.as_slice(), &rigid().transform_point([0.3, -0.2, 0.9]));
+        assert_close(HmdMatrix34::from_math(isometry).as_flattened(), rigid().as_flattened());
+
+        let m33: Matrix3<f32> = M33.into_math();
+        assert_eq!(m33[(0, 2)], 3.0);
+        assert_eq!(HmdMatrix33::from_math(m33), M33);
+
+        let m44: Matrix4<f32> = M44.into_math();
+        assert_eq!(m44[(3, 0)], 13.0);
+        assert_eq!(HmdMatrix44::from_math(m44), M44);
+
+        assert_eq!(HmdVector2::from_math(Vector2::from_math(V2)), V2);
+        assert_eq!(HmdVector3::from_math(Vector3::from_math(V3)), V3);
+        assert_eq!(HmdVector4::from_math(Vector4::from_math(V4)), V4);
+        assert_eq!(HmdVector3d::from_math(Vector3::<f64>::from_math(V3D)), V3D);
+        let corners: [Vector3<f32>; 4] = QUAD.into_math();
+        assert_eq!(corners[3], Vector3::new(0.0, 1.0, 2.0));
+        assert_eq!(HmdQuad::from_math(corners), QUAD);
+
+        let quat = Quaternion::from(rotation());
+        assert_eq!((quat.w, quat.i), (rotation().w, rotation().x));
+        assert_eq!(HmdQuaternionf::from(quat), rotation());
+        let unit = UnitQuaternion::from(rotation());
+        assert_close(unit.transform_vector(&Vector3::from(V3)).as_slice(), &rotation().rotate(V3));
+        let back = HmdQuaternionf::from(unit);
+        assert_close(&[back.w, back.x, back.y, back.z], &[rotation().w, rotation().x, rotation().y, rotation().z]);
+        assert_eq!(HmdQuaternion::from(Quaternion::from(rotation_d())), rotation_d());
+        assert_eq!(HmdQuaternion::from(UnitQuaternion::from(rotation_d())), rotation_d());
+
+        assert_eq!(Vector4::from(COLOR), Vector4::new(0.1, 0.2, 0.3, 0.4));
+        assert_eq!(HmdColor::from(Vector4::from(COLOR)), COLOR);
+        let (top_left, bottom_right) = RECT.into();
+        assert_eq!((top_left, bottom_right), (Point2::new(-1.0, 1.0), Point2::new(1.0, -1.0)));
+        assert_eq!(HmdRect2::from((top_left, bottom_right)), RECT);
+
+        let bone_isometry = Isometry3::from(bone());
+        assert_eq!(bone_isometry.translation.vector, Vector3::new(0.25, -0.5, 1.0));
+        assert_bone(VRBoneTransform::from(bone_isometry), bone());
+
+        let pose_isometry = Isometry3::from(tracked(rigid()));
+        assert_close(pose_isometry.translation.vector.as_slice(), &[0.5, 1.5, -2.0]);
+        assert_close(HmdMatrix34::from_math(pose_isometry).as_flattened(), rigid().as_flattened());
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint() {
+        use mint::{
+            ColumnMatrix3, ColumnMatrix3x4, ColumnMatrix4, Point2, Quaternion, RowMatrix3, RowMatrix3x4, RowMatrix4,
+            Vector2, Vector3, Vector4,
+        };
+
+        let rows: RowMatrix3x4<f32> = M34.into_math();
+        assert_eq!(rows.y, Vector4 { x: 5.0, y: 6.0, z: 7.0, w: 8.0 });
+        assert_eq!(HmdMatrix34::from_math(rows), M34);
+        let columns: ColumnMatrix3x4<f32> = M34.into_math();
+        assert_eq!(columns.w, Vector3 { x: 4.0, y: 8.0, z: 12.0 });
+        assert_eq!(HmdMatrix34::from_math(columns), M34);
+
+        let rows: RowMatrix3<f32> = M33.into_math();
+        assert_eq!(rows.x, Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+        assert_eq!(HmdMatrix33::from_math(rows), M33);
+        let columns: ColumnMatrix3<f32> = M33.into_math();
+        assert_eq!(columns.x, Vector3 { x: 1.0, y: 4.0, z: 7.0 });
+        assert_eq!(HmdMatrix33::from_math(columns), M33);
+
+        let rows: RowMatrix4<f32> = M44.into_math();
+        assert_eq!(rows.w, Vector4 { x: 13.0, y: 14.0, z: 15.0, w: 16.0 });
+        assert_eq!(HmdMatrix44::from_math(rows), M44);
+        let columns: ColumnMatrix4<f32> = M44.into_math();
+        assert_eq!(columns.x, Vector4 { x: 1.0, y: 5.0, z: 9.0, w: 13.0 });
+        assert_eq!(HmdMatrix44::from_math(columns), M44);
+
+        assert_eq!(HmdVector2::from_math(Vector2::from_math(V2)), V2);
+        assert_eq!(HmdVector3::from_math(Vector3::from_math(V3)), V3);
+        assert_eq!(HmdVector4::from_math(Vector4::from_math(V4)), V4);
+        assert_eq!(HmdVector3d::from_math(Vector3::<f64>::from_math(V3D)), V3D);
+        let corners: [Vector3<f32>; 4] = QUAD.into_math();
+        assert_eq!(corners[3], Vector3 { x: 0.0, y: 1.0, z: 2.0 });
+        assert_eq!(HmdQuad::from_math(corners), QUAD);
+
+        let quat = Quaternion::from(rotation());
+        assert_eq!((quat.s, quat.v.x), (rotation().w, rotation().x));
+        assert_eq!(HmdQuaternionf::from(quat), rotation());
+        let dquat = Quaternion::from(rotation_d());
+        assert_eq!((dquat.s, dquat.v.x), (0.5, -0.5));
+        assert_eq!(HmdQuaternion::from(dquat), rotation_d());
+
+        assert_eq!(Vector4::from(COLOR), Vector4 { x: 0.1, y: 0.2, z: 0.3, w: 0.4 });
+        assert_eq!(HmdColor::from(Vector4::from(COLOR)), COLOR);
+        let (top_left, bottom_right): (Point2<f32>, Point2<f32>) = RECT.into();
+        assert_eq!((top_left.x, bottom_right.y), (-1.0, -1.0));
+        assert_eq!(HmdRect2::from((top_left, bottom_right)), RECT);
+
+        let (position, orientation): (Vector4<f32>, Quaternion<f32>) = bone().into();
+        assert_eq!((position.w, orientation.s), (1.0, rotation().w));
+        assert_bone(VRBoneTransform::from((position, orientation)), bone());
+
+        let pose_columns = ColumnMatrix3x4::from(tracked(rigid()));
+        assert_eq!(pose_columns.w, Vector3 { x: 0.5, y: 1.5, z: -2.0 });
+        assert_eq!(HmdMatrix34::from_math(pose_columns), rigid());
+    }
+}
@@ -0,0 +1,192 @@
+extern crate openvr_api;
+
+/* Sizes, alignments and field offsets of openvr.h as compiled for 64-bit Linux, where the header packs
+* its structs to 4 bytes. The checks are consts, so a mismatch fails to build rather than to run. */
+#[cfg(all(test, target_os = "linux", target_pointer_width = "64"))]
+mod tests {
+    use openvr_api::vr::{
+        ivrapplications::*, ivrchaperone::*, ivrchaperonesetup::*, ivrcompositor::*, ivrdebug::*,
+        ivrsettings::*, ivrspatialanchors::*, ivrsystem::*, public_vrtypes::*, vrtypes::*,
+    };
+    use std::mem::{align_of, offset_of, size_of};
+
+    macro_rules! assert_layout {
+        ($type:ty, $size:expr, $align:expr $(, $field:ident @ $offset:expr)* $(,)?) => {
+            const _: () = {
+                assert!(size_of::<$type>() == $size, concat!("size of ", stringify!($type)));
+                assert!(align_of::<$type>() == $align, concat!("alignment of ", stringify!($type)));
+                $(
+                    assert!(
+                        offset_of!($type, $field) == $offset,
+                        concat!("offset of ", stringify!($type), "::", stringify!($field))
+                    );
+                )*
+            };
+        };
+    }
+
+    /* Function tables are arrays of pointers in the order the header declares them. */
+    macro_rules! assert_fn_table {
+        ($type:ty, $count:expr) => {
+            assert_layout!($type, $count * size_of::<usize>(), align_of::<usize>());
+        };
+    }
+
+    #[test]
+    fn public_vrtypes() {
+        assert_layout!(HmdQuaternion, 32, 4, w @ 0, x @ 8, y @ 16, z @ 24);
+        assert_layout!(HmdQuaternionf, 16, 4, w @ 0, z @ 12);
+        assert_layout!(HmdColor, 16, 4, r @ 0, a @ 12);
+        assert_layout!(HmdRect2, 16, 4, top_left @ 0, bottom_right @ 8);
+        assert_layout!(VRBoneTransform, 32, 4, position @ 0, orientation @ 16);
+    }
+
+    #[test]
+    fn textures_and_poses() {
+        assert_layout!(DistortionCoordinates, 24, 4, red @ 0, green @ 8, blue @ 16);
+        assert_layout!(Texture, 16, 4, handle @ 0, texture_type @ 8, color_space @ 12);
+        assert_layout!(
+            TrackedDevicePose,
+            80,
+            4,
+            device_to_absolute_tracking @ 0,
+            velocity @ 48,
+            angular_velocity @ 60,
+            tracking_result @ 72,
+            pose_is_valid @ 76,
+            device_is_connected @ 77,
+        );
+        assert_layout!(VRTextureBounds, 16, 4, u_min @ 0, v_max @ 12);
+        assert_layout!(VRTextureWithPose, 64, 4, color_space @ 12, device_to_absolute_tracking @ 16);
+        assert_layout!(VRTextureDepthInfo, 80, 4, handle @ 0, projection @ 8, range @ 72);
+        assert_layout!(VRTextureWithPoseAndDepth, 144, 4, device_to_absolute_tracking @ 16, depth @ 64);
+        assert_layout!(HiddenAreaMesh, 12, 4, vertex_data @ 0, triangle_count @ 8);
+        assert_layout!(
+            VRVulkanTextureData,
+            60,
+            4,
+            image @ 0,
+            device @ 8,
+            queue @ 32,
+            queue_family_index @ 40,
+            sample_count @ 56,
+        );
+        assert_layout!(VRVulkanTextureArrayData, 68, 4, texture @ 0, array_index @ 60, array_size @ 64);
+        assert_layout!(D3D12TextureData, 20, 4, resource @ 0, command_queue @ 8, node_mask @ 16);
+        assert_layout!(RenderModel_ComponentState, 100, 4, tracking_to_component_local @ 48, properties @ 96);
+    }
+
+    #[test]
+    fn controller_state() {
+        assert_layout!(VRControllerAxis, 8, 4, x @ 0, y @ 4);
+        assert_layout!(
+            VRControllerState001,
+            60,
+            4,
+            packet_num @ 0,
+            button_pressed @ 4,
+            button_touched @ 12,
+            axis @ 20,
+        );
+    }
+
+    #[test]
+    fn events() {
+        assert_layout!(VREvent_Controller, 4, 4);
+        assert_layout!(VREvent_Mouse, 12, 4, button @ 8);
+        assert_layout!(VREvent_Scroll, 16, 4, viewport_scale @ 12);
+        assert_layout!(VREvent_TouchPadMove, 24, 4, seconds_finger_down @ 4, value_y_raw @ 20);
+        assert_layout!(VREvent_Notification, 12, 4, notification_id @ 8);
+        assert_layout!(VREvent_Process, 12, 4, forced @ 8, connection_lost @ 9);
+        assert_layout!(VREvent_Overlay, 24, 4, memory_block_id @ 16);
+        assert_layout!(VREvent_Status, 4, 4);
+        assert_layout!(VREvent_Keyboard, 16, 4, user_value @ 8);
+        assert_layout!(VREvent_Ipd, 4, 4);
+        assert_layout!(VREvent_Chaperone, 16, 4, current_universe @ 8);
+        assert_layout!(VREvent_Reserved, 48, 4, reserved5 @ 40);
+        assert_layout!(VREvent_PerformanceTest, 4, 4);
+        assert_layout!(VREvent_SeatedZeroPoseReset, 1, 1);
+        assert_layout!(VREvent_Screenshot, 8, 4, type_ @ 4);
+        assert_layout!(VREvent_ScreenshotProgress, 4, 4);
+        assert_layout!(VREvent_ApplicationLaunch, 8, 4, args_handle @ 4);
+        assert_layout!(VREvent_EditingCameraSurface, 12, 4, visual_mode @ 8);
+        assert_layout!(VREvent_MessageOverlay, 4, 4);
+        assert_layout!(VREvent_Property, 12, 4, prop @ 8);
+        assert_layout!(
+            openvr_api::vr::event::RawPropertyData,
+            size_of::<VREvent_Property>(),
+            align_of::<VREvent_Property>(),
+            container @ offset_of!(VREvent_Property, container),
+            prop @ offset_of!(VREvent_Property, prop),
+        );
+        assert_layout!(VREvent_HapticVibration, 28, 4, duration_seconds @ 16, amplitude @ 24);
+        assert_layout!(VREvent_WebConsole, 8, 4);
+        assert_layout!(VREvent_InputBindingLoad, 32, 4, path_controller_type @ 24);
+        assert_layout!(VREvent_InputActionManifestLoad, 32, 4, path_manifest_path @ 24);
+        assert_layout!(VREvent_SpatialAnchor, 4, 4);
+        assert_layout!(VREvent_ProgressUpdate, 44, 4, f_progress @ 40);
+        assert_layout!(VREvent_ShowUI, 4, 4);
+        assert_layout!(VREvent_ShowDevTools, 4, 4);
+        assert_layout!(VREvent_HDCPError, 4, 4);
+        assert_layout!(VREvent_Data, 48, 4);
+        assert_layout!(
+            VREvent,
+            60,
+            4,
+            event_type @ 0,
+            tracked_device_index @ 4,
+            event_age_seconds @ 8,
+            data @ 12,
+        );
+    }
+
+    #[test]
+    fn compositor_and_misc() {
+        assert_layout!(
+            Compositor_FrameTiming,
+            184,
+            4,
+            reprojection_flags @ 20,
+            system_time_in_seconds @ 24,
+            pre_submit_gpu_ms @ 32,
+            compositor_render_start_ms @ 92,
+            hmd_pose @ 96,
+            num_vsyncs_ready_for_use @ 176,
+            num_vsyncs_to_first_view @ 180,
+        );
+        assert_layout!(Compositor_BenchmarkResults, 8, 4);
+        assert_layout!(Compositor_CumulativeStats, 60, 4, m_nNumReprojectedFramesTimedOut @ 56);
+        assert_layout!(
+            Compositor_StageRenderSettings,
+            48,
+            4,
+            m_SecondaryColor @ 16,
+            m_flVignetteInnerRadius @ 32,
+            m_bBackfaceCulling @ 44,
+        );
+        assert_layout!(DriverDirectMode_FrameTiming, 20, 4, reprojection_flags @ 16);
+        assert_layout!(
+            CameraVideoStreamFrameHeader,
+            108,
+            4,
+            frame_sequence @ 16,
+            tracked_device_pose @ 20,
+            frame_exposure_time @ 100,
+        );
+        assert_layout!(ImuSample, 60, 4, sample_time @ 0, v_accel @ 8, v_gyro @ 32, un_off_scale_flags @ 56);
+        assert_layout!(SpatialAnchorPose, 48, 4);
+        assert_layout!(AppOverrideKeys, 16, 4, pchKey @ 0, pchValue @ 8);
+    }
+
+    #[test]
+    fn fn_tables() {
+        assert_fn_table!(VR_IVRSystem_FnTable, 46);
+        assert_fn_table!(VR_IVRApplications_FnTable, 30);
+        assert_fn_table!(VR_IVRSettings_FnTable, 11);
+        assert_fn_table!(VR_IVRChaperone_FnTable, 9);
+        assert_fn_table!(VR_IVRCHaperoneSetup_FnTable, 20);
+        assert_fn_table!(VR_IVRCompositor_FnTable, 51);
+        assert_fn_table!(VR_IVRDebug_FnTable, 3);
+        assert_fn_table!(VR_IVRSpatialAnchors_FnTable, 4);
+    }
+}
@@ -0,0 +1,47 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "dynamic"))]
+mod tests {
+    use openvr_api::vr::{loader::*, vrtypes::*};
+    use std::path::Path;
+    use std::ptr::{null, null_mut};
+
+    #[test]
+    fn missing_library() {
+        let path = "/nonexistent/libopenvr_api.so";
+
+        match load(path) {
+            Err(LoadError::Library(error_path, _)) => assert_eq!(error_path, Path::new(path)),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        assert!(!is_loaded());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn missing_symbol() {
+        match load("libc.so.6") {
+            Err(LoadError::MissingSymbol(name)) => assert_eq!(name, "VR_IsHmdPresent"),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        assert!(!is_loaded());
+    }
+
+    #[test]
+    fn no_implicit_load() {
+        // Nothing is loaded behind the app's back, and the fallbacks accept null error pointers.
+        unsafe {
+            assert!(!VR_IsRuntimeInstalled());
+            assert!(VR_GetGenericInterface(c"FnTable:IVRSystem_022".as_ptr(), null_mut()).is_null());
+            assert_eq!(VR_InitInternal2(null_mut(), EVRApplicationType::VRApplication_Scene, null()), 0);
+
+            let mut error = EVRInitError::VRInitError_None;
+            VR_InitInternal2(&mut error, EVRApplicationType::VRApplication_Scene, null());
+            assert_eq!(error, EVRInitError::VRInitError_Init_InstallationNotFound);
+        }
+
+        assert!(!is_loaded());
+    }
+}
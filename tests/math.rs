@@ -0,0 +1,100 @@
+extern crate openvr_api;
+
+#[cfg(test)]
+mod tests {
+    use openvr_api::vr::{math::*, public_vrtypes::*};
+    use std::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI};
+
+    const EPSILON: f32 = 1e-5;
+
+    fn assert_close(a: &[f32], b: &[f32]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < EPSILON, "{:?} != {:?}", a, b);
+        }
+    }
+
+    fn assert_matrix(a: &HmdMatrix34, b: &HmdMatrix34) {
+        assert_close(a.as_flattened(), b.as_flattened());
+    }
+
+    fn assert_rotation(a: HmdQuaternionf, b: HmdQuaternionf) {
+        // q and -q are the same rotation.
+        let b = if a.dot(&b) < 0.0 { HmdQuaternionf { w: -b.w, x: -b.x, y: -b.y, z: -b.z } } else { b };
+        assert_close(&[a.w, a.x, a.y, a.z], &[b.w, b.x, b.y, b.z]);
+    }
+
+    /** 90 degrees about +Y: x goes to -z and z to x. */
+    fn yaw_90() -> HmdMatrix34 {
+        [[0.0, 0.0, 1.0, 1.0], [0.0, 1.0, 0.0, 2.0], [-1.0, 0.0, 0.0, 3.0]]
+    }
+
+    #[test]
+    fn position_and_rotation() {
+        let matrix = yaw_90();
+        assert_eq!(matrix.position(), [1.0, 2.0, 3.0]);
+        assert_rotation(matrix.rotation_quat(), HmdQuaternionf { w: FRAC_1_SQRT_2, x: 0.0, y: FRAC_1_SQRT_2, z: 0.0 });
+        assert_rotation(HmdMatrix34::identity().rotation_quat(), HmdQuaternionf::IDENTITY);
+
+        let half_turn = [[1.0, 0.0, 0.0, 0.0], [0.0, -1.0, 0.0, 0.0], [0.0, 0.0, -1.0, 0.0]];
+        assert_rotation(half_turn.rotation_quat(), HmdQuaternionf { w: 0.0, x: 1.0, y: 0.0, z: 0.0 });
+    }
+
+    #[test]
+    fn pos_quat_round_trip() {
+        assert_matrix(&HmdMatrix34::from_pos_quat([1.0, 2.0, 3.0], yaw_90().rotation_quat()), &yaw_90());
+
+        let rotations = [([1.0, 0.0, 0.0], 0.3), ([0.0, 1.0, 1.0], 2.0), ([1.0, -2.0, 0.5], 3.1), ([0.0, 0.0, 1.0], PI)];
+        for (axis, angle) in rotations {
+            let rotation = HmdQuaternionf::from_axis_angle(axis, angle);
+            let matrix = HmdMatrix34::from_pos_quat([-0.5, 1.5, 0.0], rotation);
+            assert_rotation(matrix.rotation_quat(), rotation);
+            assert_close(&matrix.transform_vector([0.3, -0.2, 0.9]), &rotation.rotate([0.3, -0.2, 0.9]));
+        }
+    }
+
+    #[test]
+    fn transform_and_compose() {
+        let matrix = yaw_90();
+        assert_close(&matrix.transform_point([1.0, 0.0, 0.0]), &[1.0, 2.0, 2.0]);
+        assert_close(&matrix.transform_vector([1.0, 0.0, 0.0]), &[0.0, 0.0, -1.0]);
+
+        let twice = matrix.mul(&matrix);
+        assert_close(&twice.transform_point([0.0, 0.0, 0.0]), &matrix.transform_point([1.0, 2.0, 3.0]));
+        assert_rotation(twice.rotation_quat(), HmdQuaternionf::from_axis_angle([0.0, 1.0, 0.0], PI));
+
+        assert_matrix(&matrix.mul(&HmdMatrix34::identity()), &matrix);
+        assert_matrix(&matrix.mul(&matrix.inverse_rigid()), &HmdMatrix34::identity());
+        assert_matrix(&matrix.inverse_rigid().mul(&matrix), &HmdMatrix34::identity());
+        assert_close(&matrix.inverse_rigid().transform_point([1.0, 2.0, 2.0]), &[1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn matrix_44() {
+        let matrix = yaw_90().to_44();
+        assert_eq!(matrix[3], [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(HmdMatrix34::from_44(&matrix), yaw_90());
+    }
+
+    #[test]
+    fn quaternions() {
+        let zero = HmdQuaternionf { w: 0.0, x: 0.0, y: 0.0, z: 0.0 };
+        assert_eq!(zero.normalize(), HmdQuaternionf::IDENTITY);
+        let scaled = HmdQuaternionf { w: 2.0, x: 0.0, y: 0.0, z: 2.0 };
+        assert_close(&[scaled.normalize().length()], &[1.0]);
+
+        let a = HmdQuaternionf::IDENTITY;
+        let b = HmdQuaternionf::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2);
+        assert_rotation(a.slerp(&b, 0.0), a);
+        assert_rotation(a.slerp(&b, 1.0), b);
+        assert_rotation(a.slerp(&b, 0.5), HmdQuaternionf::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2 / 2.0));
+
+        // -b is the same rotation as b, so the shorter arc is still a quarter turn.
+        let negated = HmdQuaternionf { w: -b.w, x: -b.x, y: -b.y, z: -b.z };
+        assert_rotation(a.slerp(&negated, 0.5), HmdQuaternionf::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2 / 2.0));
+        assert_rotation(b.slerp(&b, 0.5), b);
+
+        let double: HmdQuaternion = b.into();
+        assert_rotation(HmdQuaternionf::from(double), b);
+    }
+}
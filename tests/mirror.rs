@@ -0,0 +1,51 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, mock::*, vrtypes::*};
+
+    #[test]
+    fn gl_lock_and_release() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+
+        {
+            let mut left = unsafe { compositor.mirror_texture_gl(EVREye::Eye_Left) }.unwrap();
+            let right = unsafe { compositor.mirror_texture_gl(EVREye::Eye_Right) }.unwrap();
+            assert_eq!(right.eye(), EVREye::Eye_Right);
+            assert_ne!(left.texture_id(), right.texture_id());
+            assert_eq!(mock.state().mirror_textures, [left.shared_handle(), right.shared_handle()]);
+
+            let left_id = left.texture_id();
+            {
+                let lock = left.lock();
+                assert_eq!(lock.texture_id(), left_id);
+                assert!(mock.state().gl_shared_texture_locked);
+            }
+            assert!(!mock.state().gl_shared_texture_locked);
+
+            drop(right);
+            assert_eq!(mock.state().mirror_textures, [left.shared_handle()]);
+        }
+
+        assert!(mock.state().mirror_textures.is_empty());
+    }
+
+    #[test]
+    fn d3d11_release() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+
+        assert_eq!(
+            unsafe { compositor.mirror_texture_d3d11(EVREye::Eye_Left, std::ptr::null_mut()) }.err(),
+            Some(openvr_api::vr::ivrcompositor::EVRCompositorError::VRCompositorError_InvalidTexture)
+        );
+
+        let texture = unsafe { compositor.mirror_texture_d3d11(EVREye::Eye_Left, std::ptr::dangling_mut()) }.unwrap();
+        assert_eq!(mock.state().mirror_textures, [texture.shader_resource_view()]);
+        drop(texture);
+        assert!(mock.state().mirror_textures.is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, event::*, ivrcompositor::*, ivrsettings::*, mock::*, vrtypes::*};
+    use openvr_api::vr::vrtypes::ETrackedDeviceProperty::*;
+    use std::ffi::CString;
+    use std::mem::zeroed;
+    use std::ptr::null;
+
+    #[test]
+    fn init_error() {
+        let mock = MockRuntime::new();
+        mock.state().init_error = Some(EVRInitError::VRInitError_Init_HmdNotFound);
+
+        assert!(matches!(
+            Context::new(EVRApplicationType::VRApplication_Scene).err(),
+            Some(ContextError::Init(EVRInitError::VRInitError_Init_HmdNotFound))
+        ));
+        assert!(!mock.state().initialized);
+    }
+
+    #[test]
+    fn devices_and_properties() {
+        let mock = MockRuntime::new();
+        let right = mock.add_controller(ETrackedControllerRole::TrackedControllerRole_RightHand);
+        mock.set_property(right, Prop_DeviceIsWireless_Bool, true);
+        mock.set_property(0, Prop_DisplayAvailableFrameRates_Float_Array, MockProperty::array(&[90.0f32, 120.0]));
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        assert_eq!(system.recommended_render_target_size(), (1024, 1024));
+        assert_eq!(system.tracked_device_class(right), ETrackedDeviceClass::TrackedDeviceClass_Controller);
+        assert_eq!(
+            system.tracked_device_index_for_controller_role(ETrackedControllerRole::TrackedControllerRole_RightHand),
+            Some(right)
+        );
+        assert_eq!(system.string_tracked_device_property(0, Prop_ModelNumber_String), Ok("Mock HMD".to_string()));
+        assert_eq!(system.property::<bool>(right, Prop_DeviceIsWireless_Bool), Ok(true));
+        assert_eq!(system.property::<Vec<f32>>(0, Prop_DisplayAvailableFrameRates_Float_Array), Ok(vec![90.0, 120.0]));
+        assert_eq!(
+            system.float_tracked_device_property(right, Prop_DisplayFrequency_Float),
+            Err(ETrackedPropertyError::TrackedProp_UnknownProperty)
+        );
+        assert_eq!(
+            system.int32_tracked_device_property(0, Prop_DisplayFrequency_Float),
+            Err(ETrackedPropertyError::TrackedProp_WrongDataType)
+        );
+
+        mock.set_connected(right, false);
+        assert!(!system.is_tracked_device_connected(right));
+    }
+
+    #[test]
+    fn events() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        mock.push_event(EVREventType::VREvent_TrackedDeviceActivated, 0, unsafe { zeroed() });
+        let events: Vec<Event> = system.poll_events().collect();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Event::TrackedDeviceActivated { device: 0 }));
+        assert!(system.poll_next_event().is_none());
+    }
+
+    #[test]
+    fn settings() {
+        let mock = MockRuntime::new();
+        mock.set_setting(STEAMVR_SECTION, STEAMVR_IPD_FLOAT, SettingValue::Float(0.063));
+
+        let context = Context::new(EVRApplicationType::VRApplication_Utility).unwrap();
+        let settings = context.interface::<VR_IVRSettings_FnTable>().unwrap();
+        let section = CString::new(STEAMVR_SECTION).unwrap();
+        let ipd = CString::new(STEAMVR_IPD_FLOAT).unwrap();
+        let mut error = EVRSettingsError::VRSettingsError_None;
+
+        assert_eq!(unsafe { (settings.GetFloat)(section.as_ptr(), ipd.as_ptr(), &mut error) }, 0.063);
+        assert_eq!(error, EVRSettingsError::VRSettingsError_None);
+
+        unsafe { (settings.GetBool)(section.as_ptr(), ipd.as_ptr(), &mut error) };
+        assert_eq!(error, EVRSettingsError::VRSettingsError_ReadFailed);
+
+        unsafe { (settings.SetBool)(section.as_ptr(), ipd.as_ptr(), true, &mut error) };
+        assert_eq!(mock.setting(STEAMVR_SECTION, STEAMVR_IPD_FLOAT), Some(SettingValue::Bool(true)));
+    }
+
+    #[test]
+    fn submit() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.interface::<VR_IVRCompositor_FnTable>().unwrap();
+        let mut poses: [TrackedDevicePose; 1] = [identity_pose()];
+        let texture = Texture {
+            handle: std::ptr::dangling_mut(),
+            texture_type: ETextureType::TextureType_OpenGL,
+            color_space: EColorSpace::ColorSpace_Auto,
+        };
+
+        unsafe {
+            (compositor.WaitGetPoses)(poses.as_mut_ptr(), 1, poses.as_mut_ptr(), 1);
+            assert_eq!(
+                (compositor.Submit)(EVREye::Eye_Left, &texture, null(), EVRSubmitFlags::Submit_Default),
+                EVRCompositorError::VRCompositorError_None
+            );
+            assert_eq!(
+                (compositor.Submit)(EVREye::Eye_Left, &texture, null(), EVRSubmitFlags::Submit_Default),
+                EVRCompositorError::VRCompositorError_AlreadySubmitted
+            );
+        }
+
+        let frames = mock.submitted_frames();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].frame_index, 1);
+        assert_eq!(frames[0].eye, EVREye::Eye_Left);
+        assert_eq!(frames[0].texture_type, ETextureType::TextureType_OpenGL);
+    }
+}
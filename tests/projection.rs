@@ -0,0 +1,175 @@
+extern crate openvr_api;
+
+#[cfg(test)]
+mod tests {
+    use openvr_api::vr::{math::*, projection::*, public_vrtypes::*, system::Fov};
+
+    const EPSILON: f32 = 1e-5;
+    const FOV: Fov = Fov { left: -1.2, right: 0.9, top: -1.1, bottom: 1.3 };
+
+    /** Clip space x, y and z over w for a point in eye space. */
+    fn project(matrix: &HmdMatrix44, point: HmdVector3) -> HmdVector3 {
+        let row = |r: usize| matrix[r][0] * point[0] + matrix[r][1] * point[1] + matrix[r][2] * point[2] + matrix[r][3];
+        let w = row(3);
+        [row(0) / w, row(1) / w, row(2) / w]
+    }
+
+    fn depth(projection: Projection, distance: f32) -> f32 {
+        project(&projection.matrix(), [0.0, 0.0, -distance])[2]
+    }
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < EPSILON, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn matches_get_projection_matrix() {
+        // ComposeProjection from the OpenVR samples, which GetProjectionMatrix matches.
+        let (near, far) = (0.1, 100.0);
+        let idx = 1.0 / (FOV.right - FOV.left);
+        let idy = 1.0 / (FOV.bottom - FOV.top);
+        let idz = 1.0 / (far - near);
+        let expected = [
+            [2.0 * idx, 0.0, (FOV.right + FOV.left) * idx, 0.0],
+            [0.0, 2.0 * idy, (FOV.bottom + FOV.top) * idy, 0.0],
+            [0.0, 0.0, -far * idz, -far * near * idz],
+            [0.0, 0.0, -1.0, 0.0],
+        ];
+        let matrix = Projection::new(FOV, near, far).matrix();
+        for (row, expected) in matrix.iter().zip(expected) {
+            for (value, expected) in row.iter().zip(expected) {
+                assert_close(*value, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn depth_ranges() {
+        let d3d = Projection::new(FOV, 0.1, 100.0);
+        let gl = d3d.depth_range(DepthRange::NegativeOneToOne);
+        let cases = [
+            (d3d, 0.0, 1.0),
+            (d3d.reverse_z(), 1.0, 0.0),
+            (gl, -1.0, 1.0),
+            (gl.reverse_z(), 1.0, -1.0),
+        ];
+        for (projection, near, far) in cases {
+            assert_close(depth(projection, 0.1), near);
+            assert_close(depth(projection, 100.0), far);
+
+            let infinite = projection.infinite_far();
+            assert_eq!(infinite.far_z(), None);
+            assert_close(depth(infinite, 0.1), near);
+            assert!((depth(infinite, 1.0e6) - far).abs() < 1e-3);
+            // Depth keeps moving towards the far value past the old far plane.
+            assert!((depth(infinite, 1000.0) - far).abs() < (depth(infinite, 100.0) - far).abs());
+        }
+
+        // Reverse-Z keeps precision far away: 1 m apart at 500 m is still many float steps apart.
+        let reverse = d3d.reverse_z().infinite_far();
+        let (a, b) = (depth(reverse, 500.0), depth(reverse, 501.0));
+        assert!(a > b && (a - b) / f32::EPSILON / a > 100.0);
+    }
+
+    #[test]
+    fn tangents_and_flip_y() {
+        let projection = Projection::new(FOV, 0.1, 100.0);
+        let corner = project(&projection.matrix(), [FOV.left * 2.0, FOV.bottom * 2.0, -2.0]);
+        assert_close(corner[0], -1.0);
+        assert_close(corner[1], 1.0);
+        let corner = project(&projection.matrix(), [FOV.right * 2.0, FOV.top * 2.0, -2.0]);
+        assert_close(corner[0], 1.0);
+        assert_close(corner[1], -1.0);
+
+        let flipped = project(&projection.flip_y().matrix(), [FOV.left * 2.0, FOV.bottom * 2.0, -2.0]);
+        assert_close(flipped[0], -1.0);
+        assert_close(flipped[1], -1.0);
+    }
+
+    /** Points spread over an eye's frustum, in head space. */
+    fn eye_points(fov: Fov, eye_to_head: &HmdMatrix34, depths: &[f32]) -> Vec<HmdVector3> {
+        let mut points = Vec::new();
+        for x in [fov.left, 0.5 * (fov.left + fov.right), fov.right] {
+            for y in [fov.top, 0.0, fov.bottom] {
+                for &d in depths {
+                    points.push(eye_to_head.transform_point([x * d, y * d, -d]));
+                }
+            }
+        }
+        points
+    }
+
+    fn assert_contains(frustum: &StereoFrustum, points: &[HmdVector3]) {
+        for point in points {
+            for plane in frustum.planes() {
+                let distance = plane[0] * point[0] + plane[1] * point[1] + plane[2] * point[2] + plane[3];
+                assert!(distance > -1e-4, "{:?} is outside {:?}", point, plane);
+            }
+            assert!(frustum.intersects_sphere(*point, 1e-4));
+        }
+    }
+
+    #[test]
+    fn stereo_frustum() {
+        let fov = Fov { left: -1.0, right: 1.0, top: -1.0, bottom: 1.0 };
+        let left_eye = HmdMatrix34::from_pos_quat([-0.032, 0.0, 0.0], HmdQuaternionf::IDENTITY);
+        let right_eye = HmdMatrix34::from_pos_quat([0.032, 0.0, 0.0], HmdQuaternionf::IDENTITY);
+        let projection = Projection::new(fov, 0.1, 50.0);
+        let frustum = StereoFrustum::new(&projection, &left_eye, &projection, &right_eye);
+
+        // The outer sides meet 3.2 cm behind the eyes, and then line up with the combined sides.
+        assert_close(frustum.apex[0], 0.0);
+        assert_close(frustum.apex[2], 0.032);
+        assert_close(frustum.fov.left, -1.0);
+        assert_close(frustum.fov.right, 1.0);
+        assert_close(frustum.near_z, 0.132);
+        assert_close(frustum.far_z.unwrap(), 50.032);
+        assert_contains(&frustum, &eye_points(fov, &left_eye, &[0.1, 1.0, 50.0]));
+        assert_contains(&frustum, &eye_points(fov, &right_eye, &[0.1, 1.0, 50.0]));
+
+        // Outside the sides, behind the near plane and past the far plane.
+        assert!(!frustum.intersects_sphere([-3.0, 0.0, -1.0], 0.5));
+        assert!(frustum.intersects_sphere([-3.0, 0.0, -1.0], 1.5));
+        assert!(!frustum.intersects_sphere([0.0, 0.0, 0.5], 0.1));
+        assert!(!frustum.intersects_sphere([0.0, 0.0, -60.0], 1.0));
+
+        // The frustum's projection puts the eye frustums' outer edges on the clip space sides.
+        let matrix = frustum.projection().matrix();
+        let edge = frustum.head_to_frustum().transform_point(left_eye.transform_point([-10.0, 0.0, -10.0]));
+        assert_close(project(&matrix, edge)[0], -1.0);
+
+        let infinite = StereoFrustum::new(&projection.infinite_far(), &left_eye, &projection, &right_eye);
+        assert_eq!(infinite.far_z, None);
+        assert_eq!(infinite.planes()[5], [0.0, 0.0, 0.0, 1.0]);
+        assert_close(infinite.fov.top, -1.0);
+        assert!(infinite.intersects_sphere([0.0, 0.0, -1.0e4], 0.0));
+    }
+
+    #[test]
+    fn canted_stereo_frustum() {
+        let cant = HmdQuaternionf::from_axis_angle([0.0, 1.0, 0.0], 0.17);
+        let left_eye = HmdMatrix34::from_pos_quat([-0.032, 0.0, 0.0], cant);
+        let right_eye = HmdMatrix34::from_pos_quat([0.032, 0.0, 0.0], cant.conjugate());
+        let left = Projection::new(FOV, 0.05, 20.0);
+        let right = Projection::new(Fov { left: -FOV.right, right: -FOV.left, ..FOV }, 0.05, 20.0);
+        let frustum = StereoFrustum::new(&left, &left_eye, &right, &right_eye);
+
+        assert_contains(&frustum, &eye_points(left.fov(), &left_eye, &[0.05, 0.5, 20.0]));
+        assert_contains(&frustum, &eye_points(right.fov(), &right_eye, &[0.05, 0.5, 20.0]));
+        assert!(frustum.fov.left < -1.2 && frustum.fov.right > 1.2);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn system_projection() {
+        use openvr_api::vr::{context::*, mock::*, vrtypes::*};
+
+        let mock = MockRuntime::new();
+        mock.state().projection_raw[1] = FOV;
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+        for eye in [EVREye::Eye_Left, EVREye::Eye_Right] {
+            assert_eq!(system.projection(eye, 0.1, 30.0).matrix(), system.projection_matrix(eye, 0.1, 30.0));
+        }
+    }
+}
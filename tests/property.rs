@@ -0,0 +1,117 @@
+extern crate openvr_api;
+
+#[cfg(test)]
+mod tests {
+    use openvr_api::vr::{property::*, public_vrtypes::*};
+    use openvr_api::vr::vrtypes::ETrackedDeviceProperty::*;
+
+    #[test]
+    fn property_type_from_suffix() {
+        assert_eq!(Prop_ModelNumber_String.property_type(), Some(<String as TrackedPropertyValue>::PROPERTY_TYPE));
+        assert_eq!(Prop_DeviceIsWireless_Bool.property_type(), Some(<bool as TrackedPropertyValue>::PROPERTY_TYPE));
+        assert_eq!(Prop_DisplayFrequency_Float.property_type(), Some(<f32 as TrackedPropertyValue>::PROPERTY_TYPE));
+        assert_eq!(Prop_DeviceClass_Int32.property_type(), Some(<i32 as TrackedPropertyValue>::PROPERTY_TYPE));
+        assert_eq!(Prop_CurrentUniverseId_Uint64.property_type(), Some(<u64 as TrackedPropertyValue>::PROPERTY_TYPE));
+        assert_eq!(Prop_StatusDisplayTransform_Matrix34.property_type(), Some(<HmdMatrix34 as TrackedPropertyValue>::PROPERTY_TYPE));
+    }
+
+    #[test]
+    fn array_property_types() {
+        assert_eq!(
+            Prop_DisplayAvailableFrameRates_Float_Array.property_type(),
+            Some(<Vec<f32> as TrackedPropertyValue>::PROPERTY_TYPE)
+        );
+        assert_eq!(
+            Prop_CameraToHeadTransforms_Matrix34_Array.property_type(),
+            Some(<Vec<HmdMatrix34> as TrackedPropertyValue>::PROPERTY_TYPE)
+        );
+        assert_ne!(
+            Prop_DisplayAvailableFrameRates_Float_Array.property_type(),
+            Some(<f32 as TrackedPropertyValue>::PROPERTY_TYPE)
+        );
+    }
+
+    #[test]
+    fn untyped_properties() {
+        assert_eq!(Prop_Invalid.property_type(), None);
+        assert_eq!(Prop_DisplayMCImageData_Binary.property_type(), None);
+        assert_eq!(Prop_VendorSpecific_Reserved_Start.property_type(), None);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn string_buffer_regrowth() {
+        use openvr_api::vr::{context::*, mock::*, vrtypes::*};
+
+        let mock = MockRuntime::new();
+        // Far longer than any first guess, with multi-byte characters so the byte count differs from the char count.
+        let long = "Ünïcode path/".repeat(200);
+        mock.set_property(0, Prop_InstallPath_String, long.as_str());
+        mock.set_property(0, Prop_ModeLabel_String, "");
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        assert_eq!(system.property::<String>(0, Prop_InstallPath_String), Ok(long.clone()));
+        assert_eq!(system.string_tracked_device_property(0, Prop_InstallPath_String), Ok(long));
+        assert_eq!(system.property::<String>(0, Prop_ModeLabel_String), Ok(String::new()));
+        assert_eq!(
+            system.property::<String>(0, Prop_UserConfigPath_String),
+            Err(ETrackedPropertyError::TrackedProp_UnknownProperty)
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn array_reads() {
+        use openvr_api::vr::{context::*, mock::*, vrtypes::*};
+
+        let mock = MockRuntime::new();
+        let transforms: Vec<HmdMatrix34> = (0..3)
+            .map(|i| [[1.0, 0.0, 0.0, i as f32], [0.0, 1.0, 0.0, 0.5], [0.0, 0.0, 1.0, -0.25]])
+            .collect();
+        mock.set_property(0, Prop_CameraToHeadTransforms_Matrix34_Array, MockProperty::array(&transforms));
+        mock.set_property(0, Prop_CameraWhiteBalance_Vector4_Array, MockProperty::array(&[[0.9f32, 1.0, 1.1, 1.0]]));
+        mock.set_property(0, Prop_CameraDistortionFunction_Int32_Array, MockProperty::array::<i32>(&[]));
+        mock.set_property(0, Prop_ImuFactoryGyroBias_Vector3, MockProperty::array(&[[0.1f32, -0.2, 0.3]]));
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        assert_eq!(system.property::<Vec<HmdMatrix34>>(0, Prop_CameraToHeadTransforms_Matrix34_Array), Ok(transforms));
+        assert_eq!(
+            system.property::<Vec<HmdVector4>>(0, Prop_CameraWhiteBalance_Vector4_Array),
+            Ok(vec![[0.9, 1.0, 1.1, 1.0]])
+        );
+        assert_eq!(system.property::<Vec<i32>>(0, Prop_CameraDistortionFunction_Int32_Array), Ok(vec![]));
+        assert_eq!(system.property::<HmdVector3>(0, Prop_ImuFactoryGyroBias_Vector3), Ok([0.1, -0.2, 0.3]));
+        assert_eq!(
+            system.property::<Vec<f32>>(0, Prop_CameraDistortionCoefficients_Float_Array),
+            Err(ETrackedPropertyError::TrackedProp_UnknownProperty)
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn wrong_data_type() {
+        use openvr_api::vr::{context::*, mock::*, vrtypes::*};
+
+        let mock = MockRuntime::new();
+        // Stored as the wrong type, so only the suffix check can reject the typed reads below.
+        mock.set_property(0, Prop_DeviceClass_Int32, 1.5f32);
+        mock.set_property(0, Prop_DisplayAvailableFrameRates_Float_Array, MockProperty::array(&[1i32, 2]));
+        // No suffix to check, so the runtime rejects the element type.
+        mock.set_property(0, Prop_DisplayMCImageData_Binary, MockProperty::array(&[1.0f32]));
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        let wrong = Some(ETrackedPropertyError::TrackedProp_WrongDataType);
+        assert_eq!(system.property::<f32>(0, Prop_DeviceClass_Int32).err(), wrong);
+        assert_eq!(system.property::<i32>(0, Prop_DisplayFrequency_Float).err(), wrong);
+        assert_eq!(system.property::<String>(0, Prop_DisplayFrequency_Float).err(), wrong);
+        assert_eq!(system.property::<f32>(0, Prop_DisplayAvailableFrameRates_Float_Array).err(), wrong);
+        assert_eq!(system.property::<Vec<i32>>(0, Prop_DisplayAvailableFrameRates_Float_Array).err(), wrong);
+        assert_eq!(system.property::<Vec<f32>>(0, Prop_SerialNumber_String).err(), wrong);
+        assert_eq!(system.property::<Vec<i32>>(0, Prop_DisplayMCImageData_Binary).err(), wrong);
+        assert_eq!(system.property::<Vec<f32>>(0, Prop_DisplayMCImageData_Binary), Ok(vec![1.0]));
+        assert_eq!(system.property::<f32>(0, Prop_DisplayFrequency_Float), Ok(90.0));
+    }
+}
@@ -0,0 +1,165 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, mock::*, public_vrtypes::*, system::*, vrtypes::*};
+    use openvr_api::vr::vrtypes::ETrackedDeviceProperty::*;
+
+    const IDENTITY: HmdMatrix34 = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]];
+
+    #[test]
+    fn display() {
+        let mock = MockRuntime::new();
+        mock.state().projection_raw[1] = Fov { left: -1.2, right: 0.9, top: -1.1, bottom: 1.0 };
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        assert_eq!(system.recommended_render_target_size(), (1024, 1024));
+        assert_eq!(system.projection_raw(EVREye::Eye_Left), Fov { left: -1.0, right: 1.0, top: -1.0, bottom: 1.0 });
+        assert_eq!(system.projection_raw(EVREye::Eye_Right), Fov { left: -1.2, right: 0.9, top: -1.1, bottom: 1.0 });
+        assert_eq!(system.eye_to_head_transform(EVREye::Eye_Left)[0][3], -0.032);
+        assert_eq!(system.eye_to_head_transform(EVREye::Eye_Right)[0][3], 0.032);
+        assert_eq!(system.seated_zero_pose_to_standing_absolute_tracking_pose(), IDENTITY);
+        assert_eq!(system.raw_zero_pose_to_standing_absolute_tracking_pose(), IDENTITY);
+        assert!(system.hidden_area_mesh(EVREye::Eye_Left, EHiddenAreaMeshType::k_eHiddenAreaMesh_Standard).is_empty());
+    }
+
+    #[test]
+    fn devices() {
+        let mock = MockRuntime::new();
+        let left = mock.add_controller(ETrackedControllerRole::TrackedControllerRole_LeftHand);
+        let right = mock.add_controller(ETrackedControllerRole::TrackedControllerRole_RightHand);
+        mock.state().devices[right as usize].activity_level = EDeviceActivityLevel::k_EDeviceActivityLevel_Idle;
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        assert_eq!(
+            system.sorted_tracked_device_indices_of_class(
+                ETrackedDeviceClass::TrackedDeviceClass_Controller,
+                k_unTrackedDeviceIndexInvalid
+            ),
+            vec![left, right]
+        );
+        assert_eq!(
+            system.tracked_device_index_for_controller_role(ETrackedControllerRole::TrackedControllerRole_LeftHand),
+            Some(left)
+        );
+        assert_eq!(
+            system.tracked_device_index_for_controller_role(ETrackedControllerRole::TrackedControllerRole_Treadmill),
+            None
+        );
+        assert_eq!(
+            system.controller_role_for_tracked_device_index(right),
+            ETrackedControllerRole::TrackedControllerRole_RightHand
+        );
+        assert_eq!(system.tracked_device_class(0), ETrackedDeviceClass::TrackedDeviceClass_HMD);
+        assert_eq!(system.tracked_device_class(right + 1), ETrackedDeviceClass::TrackedDeviceClass_Invalid);
+        assert_eq!(system.tracked_device_activity_level(right), EDeviceActivityLevel::k_EDeviceActivityLevel_Idle);
+
+        assert!(system.is_tracked_device_connected(left));
+        mock.set_connected(left, false);
+        assert!(!system.is_tracked_device_connected(left));
+        assert!(!system.is_tracked_device_connected(right + 1));
+    }
+
+    #[test]
+    fn properties() {
+        let mock = MockRuntime::new();
+        mock.set_property(0, Prop_DisplayMCImageWidth_Int32, 2160);
+        mock.set_property(0, Prop_CurrentUniverseId_Uint64, 7u64);
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        assert_eq!(system.string_tracked_device_property(0, Prop_SerialNumber_String), Ok("MOCK-HMD-0".to_string()));
+        assert_eq!(system.float_tracked_device_property(0, Prop_DisplayFrequency_Float), Ok(90.0));
+        assert_eq!(system.int32_tracked_device_property(0, Prop_DisplayMCImageWidth_Int32), Ok(2160));
+        assert_eq!(system.uint64_tracked_device_property(0, Prop_CurrentUniverseId_Uint64), Ok(7));
+        assert_eq!(
+            system.bool_tracked_device_property(0, Prop_DisplayFrequency_Float).err(),
+            Some(ETrackedPropertyError::TrackedProp_WrongDataType)
+        );
+        assert_eq!(
+            system.float_tracked_device_property(1, Prop_DisplayFrequency_Float).err(),
+            Some(ETrackedPropertyError::TrackedProp_InvalidDevice)
+        );
+        assert_eq!(
+            system.prop_error_name_from_enum(ETrackedPropertyError::TrackedProp_UnknownProperty),
+            "TrackedProp_UnknownProperty"
+        );
+    }
+
+    #[test]
+    fn apply_transform() {
+        let _mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        let pose = TrackedDevicePose { velocity: [1.0, 0.0, 0.0], ..identity_pose() };
+        let mut transform = IDENTITY;
+        transform[0][3] = 2.0;
+        let moved = system.apply_transform(&pose, &transform);
+
+        assert_eq!(moved.device_to_absolute_tracking[0][3], 2.0);
+        assert_eq!(moved.velocity, [1.0, 0.0, 0.0]);
+        assert!(moved.pose_is_valid);
+    }
+
+    #[test]
+    fn controllers() {
+        let mock = MockRuntime::new();
+        let right = mock.add_controller(ETrackedControllerRole::TrackedControllerRole_RightHand);
+        let mut state = mock.state().devices[right as usize].controller_state;
+        state.packet_num = 3;
+        state.button_pressed = 1 << EVRButtonId::k_EButton_Axis1 as u64;
+        mock.set_controller_state(right, state);
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        let read = system.controller_state(right).unwrap();
+        assert_eq!((read.packet_num, read.button_pressed), (3, state.button_pressed));
+        let (_, pose) = system
+            .controller_state_with_pose(ETrackingUniverseOrigin::TrackingUniverseStanding, right)
+            .unwrap();
+        assert!(pose.pose_is_valid);
+
+        system.trigger_haptic_pulse(right, 0, 500);
+        assert_eq!(mock.state().haptic_pulses, vec![(right, 0, 500)]);
+
+        mock.set_connected(right, false);
+        assert!(system.controller_state(right).is_none());
+        assert!(system.controller_state(right + 1).is_none());
+
+        assert_eq!(system.button_id_name_from_enum(EVRButtonId::k_EButton_Axis1), "k_EButton_Axis1");
+        assert_eq!(
+            system.controller_axis_type_name_from_enum(EVRControllerAxisType::k_eControllerAxis_Joystick),
+            "k_eControllerAxis_Joystick"
+        );
+    }
+
+    #[test]
+    fn application_state() {
+        let mock = MockRuntime::new();
+        {
+            let mut state = mock.state();
+            state.should_pause = true;
+            state.input_available = false;
+            state.app_container_file_paths = vec!["C:\\game".to_string(), "C:\\saves".to_string()];
+        }
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+
+        assert!(system.should_application_pause());
+        assert!(!system.is_input_available());
+        assert_eq!(system.app_container_file_paths(), vec!["C:\\game".to_string(), "C:\\saves".to_string()]);
+        assert_eq!(system.runtime_version(), "mock");
+        assert_eq!(system.perform_firmware_update(0), EVRFirmwareError::VRFirmwareError_None);
+
+        system.acknowledge_quit_exiting();
+        assert!(mock.state().quit_acknowledged);
+    }
+}
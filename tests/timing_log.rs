@@ -0,0 +1,80 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use openvr_api::vr::{ivrcompositor::*, timing_log::*, vrtypes::*};
+
+    fn timing(frame_index: u32) -> Compositor_FrameTiming {
+        Compositor_FrameTiming {
+            frame_index,
+            num_frame_presents: 2,
+            num_mis_presented: 1,
+            reprojection_flags: (VRCompositor_ReprojectionReason_Gpu | VRCompositor_ReprojectionMotion | 0x10).into(),
+            system_time_in_seconds: 1234.5 + frame_index as f64 / 90.0,
+            pre_submit_gpu_ms: 4.25,
+            total_render_gpu_ms: 6.125,
+            hmd_pose: TrackedDevicePose {
+                device_to_absolute_tracking: [[1.0, 0.0, 0.0, 0.1], [0.0, 1.0, 0.0, 1.7], [0.0, 0.0, 1.0, -0.3]],
+                velocity: [0.0, 0.5, 0.0],
+                angular_velocity: [0.25, 0.0, 0.0],
+                tracking_result: ETrackingResult::TrackingResult_Running_OK,
+                pose_is_valid: true,
+                device_is_connected: true,
+            },
+            num_vsyncs_to_first_view: 2,
+            ..Default::default()
+        }
+    }
+
+    fn assert_same(read: &[Compositor_FrameTiming], written: &[Compositor_FrameTiming]) {
+        assert_eq!(read.len(), written.len());
+        for (read, written) in read.iter().zip(written) {
+            assert_eq!(format!("{:?}", read), format!("{:?}", written));
+        }
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let timings = [timing(1), timing(2), timing(3)];
+        let mut buffer = Vec::new();
+        let mut writer = FrameTimingWriter::csv(&mut buffer);
+        writer.write_all(&timings[..2]).unwrap();
+        writer.write(&timings[2]).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert!(text.starts_with("size,frame_index,"));
+        assert_eq!(text.lines().count(), 4);
+        assert_same(&read_csv(buffer.as_slice()).unwrap(), &timings);
+    }
+
+    #[test]
+    fn json_lines_round_trip() {
+        let timings = [timing(7), timing(8)];
+        let mut buffer = Vec::new();
+        let mut writer = FrameTimingWriter::json_lines(&mut buffer);
+        writer.write_all(&timings).unwrap();
+        drop(writer);
+        buffer.extend_from_slice(b"\n");
+
+        assert_same(&read_json_lines(buffer.as_slice()).unwrap(), &timings);
+
+        match read_json_lines(&b"{}\n"[..]) {
+            Err(TimingLogError::Json(line, _)) => assert_eq!(line, 1),
+            other => panic!("expected a JSON error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn stats_serialize() {
+        let stats = Compositor_CumulativeStats { m_nPid: 1000, m_nNumFramePresents: 900, ..Default::default() };
+        let json = serde_json::to_string(&stats).unwrap();
+        let read: Compositor_CumulativeStats = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.m_nNumFramePresents, 900);
+
+        let results = Compositor_BenchmarkResults { mega_pixels_per_second: 500.0, hmd_recommended_mega_pixels_per_second: 300.0 };
+        let read: Compositor_BenchmarkResults = serde_json::from_str(&serde_json::to_string(&results).unwrap()).unwrap();
+        assert_eq!(read.mega_pixels_per_second, 500.0);
+    }
+}
@@ -0,0 +1,95 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, ivrcompositor::*, mock::*, public_vrtypes::*, vrtypes::*};
+
+    const BLACK: HmdColor = HmdColor { r: 0.0, g: 0.0, b: 0.0, a: 1.0 };
+
+    fn face(name: usize) -> Texture {
+        Texture {
+            handle: name as *mut std::ffi::c_void,
+            texture_type: ETextureType::TextureType_OpenGL,
+            color_space: EColorSpace::ColorSpace_Auto,
+        }
+    }
+
+    #[test]
+    fn restores_on_drop() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        let faces: Vec<Texture> = (1..=6).map(face).collect();
+
+        {
+            let mut transition = compositor.scene_transition(BLACK, 0.5);
+            transition.fade_grid();
+            unsafe { transition.set_skybox(&faces) }.unwrap();
+
+            assert_eq!(transition.fade_color(), BLACK);
+            assert_eq!(transition.grid_alpha(), 1.0);
+            assert_eq!(mock.state().skybox_override.as_ref().map(Vec::len), Some(6));
+        }
+
+        assert_eq!(compositor.current_fade_color(false).a, 0.0);
+        assert_eq!(compositor.current_grid_alpha(), 0.0);
+        assert!(mock.state().skybox_override.is_none());
+
+        // What was there before the transition comes back, not a clear fade and a hidden grid.
+        let tint = HmdColor { r: 0.2, g: 0.0, b: 0.0, a: 0.3 };
+        compositor.fade_to_color(0.0, tint, false);
+        compositor.fade_grid(0.0, true);
+        {
+            let mut transition = compositor.scene_transition(BLACK, 0.5);
+            transition.fade_grid();
+            assert_eq!(transition.fade_color(), BLACK);
+        }
+        assert_eq!(compositor.current_fade_color(false), tint);
+        assert_eq!(compositor.current_grid_alpha(), 1.0);
+    }
+
+    #[test]
+    fn finish() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+
+        let mut transition = compositor.scene_transition(BLACK, 1.0);
+        transition.fade_grid();
+        {
+            let state = mock.state();
+            assert_eq!((state.fade_color, state.fade_seconds, state.grid_fade_seconds), (BLACK, 1.0, 1.0));
+        }
+        transition.finish(0.25);
+
+        // The fade back takes finish's duration, not the one the transition started with.
+        let state = mock.state();
+        assert_eq!((state.fade_color.a, state.fade_seconds), (0.0, 0.25));
+        assert_eq!((state.grid_alpha, state.grid_fade_seconds), (0.0, 0.25));
+    }
+
+    #[test]
+    fn skybox_rules() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+
+        // The faces are borrowed for as long as the transition lives, so they are declared before it.
+        let (three, null, pair) = ([face(1), face(2), face(3)], [face(0)], [face(1), face(2)]);
+        let mut mixed = pair;
+        mixed[1].texture_type = ETextureType::TextureType_Vulkan;
+        let invalid = Err(EVRCompositorError::VRCompositorError_InvalidTexture);
+        let mut transition = compositor.scene_transition(BLACK, 0.0);
+
+        unsafe {
+            assert_eq!(transition.set_skybox(&[]), invalid);
+            assert_eq!(transition.set_skybox(&three), invalid);
+            assert_eq!(transition.set_skybox(&null), invalid);
+            assert_eq!(transition.set_skybox(&mixed), invalid);
+            assert!(mock.state().skybox_override.is_none());
+
+            transition.set_skybox(&pair).unwrap();
+        }
+        assert_eq!(mock.state().skybox_override.as_ref().map(Vec::len), Some(2));
+    }
+}
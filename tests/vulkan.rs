@@ -0,0 +1,43 @@
+extern crate openvr_api;
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use openvr_api::vr::{context::*, mock::*, vrtypes::*, vulkan::*};
+    use std::ffi::CString;
+
+    fn names(extensions: &[CString]) -> Vec<&str> {
+        extensions.iter().map(|name| name.to_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn instance_extensions() {
+        let mock = MockRuntime::new();
+        mock.state().vulkan_instance_extensions = " VK_KHR_surface  VK_KHR_external_memory_capabilities ".to_string();
+
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let compositor = context.compositor().unwrap();
+        assert_eq!(
+            names(&compositor.vulkan_instance_extensions_required()),
+            ["VK_KHR_surface", "VK_KHR_external_memory_capabilities"]
+        );
+
+        mock.state().vulkan_instance_extensions.clear();
+        assert!(compositor.vulkan_instance_extensions_required().is_empty());
+    }
+
+    #[test]
+    fn device_setup() {
+        let mock = MockRuntime::new();
+        let context = Context::new(EVRApplicationType::VRApplication_Scene).unwrap();
+        let system = context.system().unwrap();
+        let compositor = context.compositor().unwrap();
+        let instance: VkInstance = std::ptr::dangling_mut();
+
+        assert!(unsafe { VulkanDeviceSetup::new(&system, &compositor, instance) }.is_none());
+
+        mock.state().output_device = 0x1000;
+        let setup = unsafe { VulkanDeviceSetup::new(&system, &compositor, instance) }.unwrap();
+        assert_eq!(setup.physical_device as usize, 0x1000);
+        assert_eq!(names(&setup.device_extensions), ["VK_KHR_external_memory", "VK_KHR_external_memory_fd"]);
+    }
+}